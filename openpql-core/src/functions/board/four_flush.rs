@@ -0,0 +1,35 @@
+use crate::{PQLBoard, PQLCardSet};
+
+/// Returns `true` when the most frequent board suit has exactly four cards.
+pub fn four_flush(board: PQLBoard) -> bool {
+    PQLCardSet::from(board).max_same_suit_count() == 4
+}
+
+#[cfg(test)]
+mod tests {
+    use openpql_prelude::cards;
+    use quickcheck_macros::quickcheck;
+
+    use super::*;
+    use crate::PQLSuit;
+
+    fn f(s: &str) -> bool {
+        four_flush(PQLBoard::from_slice(&cards!(s)))
+    }
+
+    #[test]
+    fn test_four_flush_cases() {
+        assert!(f("2s 3s 4s 5s"));
+        assert!(f("2s 3s 4h 5s 6s"));
+        assert!(!f("2s 3s 4s"));
+        assert!(!f("2s 3s 4s 5s 6s"));
+    }
+
+    #[quickcheck]
+    fn test_four_flush(board: PQLBoard) {
+        let cs = PQLCardSet::from(board);
+        let expected = PQLSuit::ARR_ALL.iter().any(|&s| cs.count_by_suit(s) == 4);
+
+        assert_eq!(four_flush(board), expected);
+    }
+}
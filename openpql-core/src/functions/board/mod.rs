@@ -6,10 +6,12 @@ mod board_ranks;
 mod board_suit_count;
 mod duplicated_board_ranks;
 mod flushing_board;
+mod four_flush;
 mod monotone_board;
 mod paired_board;
 mod rainbow_board;
 mod straight_board;
+mod three_flush;
 mod twotone_board;
 
 pub use board_allows_made_lo::*;
@@ -20,8 +22,10 @@ pub use board_ranks::*;
 pub use board_suit_count::*;
 pub use duplicated_board_ranks::*;
 pub use flushing_board::*;
+pub use four_flush::*;
 pub use monotone_board::*;
 pub use paired_board::*;
 pub use rainbow_board::*;
 pub use straight_board::*;
+pub use three_flush::*;
 pub use twotone_board::*;
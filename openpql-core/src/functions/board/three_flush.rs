@@ -0,0 +1,35 @@
+use crate::{PQLBoard, PQLCardSet};
+
+/// Returns `true` when the most frequent board suit has exactly three cards.
+pub fn three_flush(board: PQLBoard) -> bool {
+    PQLCardSet::from(board).max_same_suit_count() == 3
+}
+
+#[cfg(test)]
+mod tests {
+    use openpql_prelude::cards;
+    use quickcheck_macros::quickcheck;
+
+    use super::*;
+    use crate::PQLSuit;
+
+    fn f(s: &str) -> bool {
+        three_flush(PQLBoard::from_slice(&cards!(s)))
+    }
+
+    #[test]
+    fn test_three_flush_cases() {
+        assert!(f("2s 3s 4s"));
+        assert!(f("2s 3s 4h 5s 6d"));
+        assert!(!f("2s 3s 4h"));
+        assert!(!f("2s 3s 4s 5s"));
+    }
+
+    #[quickcheck]
+    fn test_three_flush(board: PQLBoard) {
+        let cs = PQLCardSet::from(board);
+        let expected = PQLSuit::ARR_ALL.iter().any(|&s| cs.count_by_suit(s) == 3);
+
+        assert_eq!(three_flush(board), expected);
+    }
+}
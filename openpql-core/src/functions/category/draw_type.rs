@@ -0,0 +1,30 @@
+use crate::{PQLBoard, PQLCard, PQLCardSet, PQLDrawType, PQLGame};
+
+/// Classifies a hand's strongest draw on the flop or turn.
+pub fn draw_type(game: PQLGame, hand: &[PQLCard], board: PQLBoard) -> PQLDrawType {
+    game.eval_draws(PQLCardSet::from(hand), PQLCardSet::from(board))
+        .best()
+}
+
+#[cfg(test)]
+mod tests {
+    use openpql_prelude::{board, cards};
+
+    use super::*;
+
+    #[test]
+    fn test_draw_type_cases() {
+        assert_eq!(
+            draw_type(PQLGame::Holdem, &cards!("9h 8h"), board!("7h 6s 2h")),
+            PQLDrawType::ComboDraw,
+        );
+        assert_eq!(
+            draw_type(PQLGame::Omaha, &cards!("9c 8d 2s 2h"), board!("7h 6s Kc")),
+            PQLDrawType::OpenEnded,
+        );
+        assert_eq!(
+            draw_type(PQLGame::Holdem, &cards!("9h 8h"), board!("7h 6s 2h Kd As")),
+            PQLDrawType::NoDraw,
+        );
+    }
+}
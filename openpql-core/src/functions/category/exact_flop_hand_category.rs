@@ -19,7 +19,7 @@ mod tests {
     use super::*;
 
     #[quickcheck]
-    fn test_exact_flop_hand_category(game: PQLGame, cards: CardN<10>) -> TestResult {
+    fn test_exact_flop_hand_category(game: PQLGame, cards: CardN<11>) -> TestResult {
         let n = game.player_cards_len() as usize;
         let hand = &cards.as_ref()[..n];
         let board = PQLBoard::from(&cards.as_ref()[n..n + 5]);
@@ -0,0 +1,48 @@
+use crate::{PQLBoard, PQLCard, PQLCardSet, PQLDrawType, PQLGame};
+
+/// Returns `true` when the hand holds `draw`, alone or as part of a
+/// stronger draw: a nut flush draw is also a flush draw.
+pub fn has_draw(game: PQLGame, hand: &[PQLCard], board: PQLBoard, draw: PQLDrawType) -> bool {
+    game.eval_draws(PQLCardSet::from(hand), PQLCardSet::from(board))
+        .contains(draw)
+}
+
+#[cfg(test)]
+mod tests {
+    use openpql_prelude::{CardN, board, cards};
+    use quickcheck::TestResult;
+    use quickcheck_macros::quickcheck;
+
+    use super::*;
+    use crate::functions::draw_type;
+
+    #[test]
+    fn test_has_draw_cases() {
+        let f = |draw| has_draw(PQLGame::Holdem, &cards!("Ah 5h"), board!("4h 3h Kc"), draw);
+
+        assert!(f(PQLDrawType::ComboDraw));
+        assert!(f(PQLDrawType::NutFlushDraw));
+        assert!(f(PQLDrawType::FlushDraw));
+        assert!(f(PQLDrawType::Gutshot));
+        assert!(!f(PQLDrawType::OpenEnded));
+        assert!(!f(PQLDrawType::NoDraw));
+    }
+
+    #[quickcheck]
+    fn test_has_draw(game: PQLGame, cards: CardN<10>, is_turn: bool) -> TestResult {
+        let n = game.player_cards_len() as usize;
+        let hand = &cards.as_ref()[..n];
+        let board = PQLBoard::from(&cards.as_ref()[n..n + 3 + usize::from(is_turn)]);
+
+        let best = draw_type(game, hand, board);
+
+        for draw in PQLDrawType::ARR_ALL {
+            if draw > best {
+                assert!(!has_draw(game, hand, board, draw));
+            }
+        }
+        assert!(has_draw(game, hand, board, best));
+
+        TestResult::passed()
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{PQLBoard, PQLCard, PQLCardSet, PQLGame, PQLLoRating};
+
+/// Returns the 8-or-better low rating of the hand given the board.
+pub fn lo_rating(game: PQLGame, hand: &[PQLCard], board: PQLBoard) -> PQLLoRating {
+    game.eval_lo_rating(PQLCardSet::from(hand), PQLCardSet::from(board))
+}
+
+#[cfg(test)]
+mod tests {
+    use openpql_prelude::{board, cards};
+
+    use super::*;
+
+    #[test]
+    fn test_lo_rating_cases() {
+        let b = board!("Ad 7c 2d Jh 3s");
+        let r1 = lo_rating(PQLGame::Holdem, &cards!("4s 5h"), b);
+        let r2 = lo_rating(PQLGame::Holdem, &cards!("4s 8h"), b);
+        let r3 = lo_rating(PQLGame::Holdem, &cards!("Ks Kh"), b);
+
+        assert!(r1 < r2);
+        assert!(r2 < r3);
+        assert_eq!(r3, PQLLoRating::NONE);
+    }
+}
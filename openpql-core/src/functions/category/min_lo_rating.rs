@@ -0,0 +1,32 @@
+use crate::{PQLBoard, PQLCard, PQLGame, PQLLoRating, functions::lo_rating};
+
+/// Returns `true` when the hand makes a low at least as good as `rating`.
+pub fn min_lo_rating(
+    game: PQLGame,
+    hand: &[PQLCard],
+    board: PQLBoard,
+    rating: PQLLoRating,
+) -> bool {
+    let player_rating = lo_rating(game, hand, board);
+
+    player_rating.is_made() && player_rating <= rating
+}
+
+#[cfg(test)]
+mod tests {
+    use openpql_prelude::{board, cards};
+
+    use super::*;
+
+    #[test]
+    fn test_min_lo_rating() {
+        let game = PQLGame::Holdem;
+        let b = board!("Ad 7c 2d Jh 3s");
+        let rating = lo_rating(game, &cards!("4s 8h"), b);
+
+        assert!(min_lo_rating(game, &cards!("4s 5h"), b, rating));
+        assert!(min_lo_rating(game, &cards!("4s 8d"), b, rating));
+        assert!(!min_lo_rating(game, &cards!("6s 8d"), b, rating));
+        assert!(!min_lo_rating(game, &cards!("Ks Kh"), b, PQLLoRating::NONE));
+    }
+}
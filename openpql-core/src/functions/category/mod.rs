@@ -1,17 +1,27 @@
+mod draw_type;
 mod exact_flop_hand_category;
 mod exact_hand_type;
 mod flop_hand_category;
 mod hand_type;
+mod has_draw;
 mod hi_rating;
+mod lo_rating;
 mod min_flop_hand_category;
 mod min_hand_type;
 mod min_hi_rating;
+mod min_lo_rating;
+mod nut_lo;
 
+pub use draw_type::*;
 pub use exact_flop_hand_category::*;
 pub use exact_hand_type::*;
 pub use flop_hand_category::*;
 pub use hand_type::*;
+pub use has_draw::*;
 pub use hi_rating::*;
+pub use lo_rating::*;
 pub use min_flop_hand_category::*;
 pub use min_hand_type::*;
 pub use min_hi_rating::*;
+pub use min_lo_rating::*;
+pub use nut_lo::*;
@@ -0,0 +1,75 @@
+use crate::{PQLBoard, PQLCard, PQLCardSet, PQLGame, util::is_nut_lo};
+
+/// Returns `true` when the hand makes a low that no unseen holding can beat.
+pub fn nut_lo(game: PQLGame, hand: &[PQLCard], board: PQLBoard) -> bool {
+    let p = PQLCardSet::from(hand);
+    let b = PQLCardSet::from(board);
+
+    is_nut_lo(game, p, b, p | b)
+}
+
+#[cfg(test)]
+mod tests {
+    use openpql_prelude::{board, cards};
+
+    use super::*;
+
+    #[test]
+    fn test_nut_lo_holdem() {
+        assert!(nut_lo(
+            PQLGame::Holdem,
+            &cards!("As 2h"),
+            board!("3d 4c 5s")
+        ));
+        assert!(nut_lo(
+            PQLGame::Holdem,
+            &cards!("As 2h"),
+            board!("3d 4c 8s")
+        ));
+        assert!(nut_lo(
+            PQLGame::Holdem,
+            &cards!("As 3h"),
+            board!("2d 4c 8s")
+        ));
+        assert!(!nut_lo(
+            PQLGame::Holdem,
+            &cards!("As 6h"),
+            board!("2d 4c 8s")
+        ));
+        assert!(!nut_lo(
+            PQLGame::Holdem,
+            &cards!("Ks Kh"),
+            board!("2d 4c 8s")
+        ));
+    }
+
+    #[test]
+    fn test_nut_lo_omaha() {
+        assert!(nut_lo(
+            PQLGame::Omaha,
+            &cards!("As 2h Kd Kc"),
+            board!("3d 7c 8s Qs")
+        ));
+        assert!(!nut_lo(
+            PQLGame::Omaha,
+            &cards!("As 4h Kd Kc"),
+            board!("2d 7c 8s Qs")
+        ));
+        // counterfeited: the board ace leaves 2-3 as the nuts
+        assert!(!nut_lo(
+            PQLGame::Omaha,
+            &cards!("As 2h Kd Kc"),
+            board!("Ad 7c 8s Qs")
+        ));
+    }
+
+    #[test]
+    fn test_nut_lo_blocked() {
+        // every ace and deuce is visible, so 3-4 cannot be beaten
+        assert!(nut_lo(
+            PQLGame::Omaha,
+            &cards!("3s 4h As Ah"),
+            board!("Ad Ac 2s 2h 8c")
+        ));
+    }
+}
@@ -1,11 +1,15 @@
 use openpql_prelude::PerPlayer;
 
-use crate::{PQLBoard, PQLCard, PQLCardSet, PQLFraction, PQLGame, PQLPlayerCount};
+use crate::{
+    PQLBoard, PQLCard, PQLCardSet, PQLFraction, PQLGame, PQLHiRating, PQLLoRating, PQLPlayerCount,
+};
 
 /// Returns the hero's exact share of the pot on the river.
 ///
 /// Returns zero if the hero does not hold the best hand; otherwise splits the
-/// pot evenly across all tied winners.
+/// pot evenly across all tied winners. In Hi-Lo games the pot is halved
+/// between the best high and the best qualifying low, so quartered pots are
+/// reported exactly; without a qualifying low the high hand takes it all.
 ///
 /// # Panics
 /// Panics if `player_cards.len()` is not a multiple of `game.player_cards_len()`,
@@ -38,19 +42,61 @@ pub fn fractional_river_equity(
 
     let b = PQLCardSet::from(board);
 
-    let ratings: PerPlayer<_> = player_cards
-        .chunks_exact(n_cards)
-        .map(|h| game.eval_rating(PQLCardSet::from(h), b))
-        .collect();
+    pot_share_by(
+        game,
+        player_cards.chunks_exact(n_cards),
+        hero_idx,
+        |h| game.eval_rating(h.into(), b),
+        |h| game.eval_lo_rating(h.into(), b),
+    )
+}
+
+/// Hero's share of the pot between `hands`, split as in [`fractional_river_equity`].
+///
+/// `eval_hi` and, in Hi-Lo games, `eval_lo` rate the hands, so callers can
+/// rate them their own way, through a cache for instance.
+///
+/// # Panics
+/// Panics if `hands` is empty or `hero_idx` is not one of them.
+pub fn pot_share_by<'a, I, H, L>(
+    game: PQLGame,
+    hands: I,
+    hero_idx: PQLPlayerCount,
+    eval_hi: H,
+    eval_lo: L,
+) -> PQLFraction
+where
+    I: Iterator<Item = &'a [PQLCard]> + Clone,
+    H: Fn(&[PQLCard]) -> PQLHiRating,
+    L: Fn(&[PQLCard]) -> PQLLoRating,
+{
+    let hi: PerPlayer<_> = hands.clone().map(eval_hi).collect();
+    let best_hi = *hi.iter().max().unwrap();
+    let hi_share = share_of_best(&hi, hero_idx, |&r| r == best_hi);
+
+    if !game.is_hilo() {
+        return hi_share;
+    }
 
-    let max = *ratings.iter().max().unwrap();
-    let hero = ratings[hero_idx as usize];
+    let lo: PerPlayer<_> = hands.map(eval_lo).collect();
+    let best_lo = *lo.iter().min().unwrap();
 
-    if hero < max {
-        PQLFraction::zero()
+    if best_lo.is_made() {
+        hi_share.half() + share_of_best(&lo, hero_idx, |&r| r == best_lo).half()
     } else {
-        let n_winners = ratings.iter().filter(|&&r| r == max).count();
-        PQLFraction::pot_share(n_winners)
+        hi_share
+    }
+}
+
+fn share_of_best<T>(
+    ratings: &[T],
+    hero_idx: PQLPlayerCount,
+    is_best: impl Fn(&T) -> bool,
+) -> PQLFraction {
+    if is_best(&ratings[hero_idx as usize]) {
+        PQLFraction::pot_share(ratings.iter().filter(|r| is_best(r)).count())
+    } else {
+        PQLFraction::zero()
     }
 }
 
@@ -83,6 +129,49 @@ mod tests {
         assert_eq!(f, PQLFraction::pot_share(2));
     }
 
+    #[test]
+    fn test_fractional_river_equity_hilo() {
+        let game = PQLGame::Omaha8;
+        let b = board!("2c 4d 7h Kd Qs");
+
+        // hero: nut low, villain: hi with trip kings
+        let hands = cards!("As 3h 9c Ts Kh Ks 8d 8c");
+        assert_eq!(
+            fractional_river_equity(game, b, &hands, 0),
+            PQLFraction::pot_share(2)
+        );
+        assert_eq!(
+            fractional_river_equity(game, b, &hands, 1),
+            PQLFraction::pot_share(2)
+        );
+
+        // quartered: hero and villain share the nut low, villain wins hi
+        let hands = cards!("As 3h 9c Ts Ad 3d Kh Ks");
+        assert_eq!(
+            fractional_river_equity(game, b, &hands, 0),
+            PQLFraction::new(1, 4)
+        );
+        assert_eq!(
+            fractional_river_equity(game, b, &hands, 1),
+            PQLFraction::new(3, 4)
+        );
+
+        // no qualifying low: hi takes everything
+        let no_lo = board!("2c 9d Th Kd Qs");
+        let hands = cards!("As 3h 9c Ts Kh Ks 8d 8c");
+        assert_eq!(
+            fractional_river_equity(game, no_lo, &hands, 1),
+            PQLFraction::pot_share(1)
+        );
+
+        let game = PQLGame::Omaha5HiLo;
+        let hands = cards!("As Jc 9c Ts Qh Kh Ks 8d 8c 5h");
+        assert_eq!(
+            fractional_river_equity(game, b, &hands, 1),
+            PQLFraction::pot_share(1)
+        );
+    }
+
     #[test]
     #[should_panic(expected = "board must have 5 cards")]
     fn test_invalid_board() {
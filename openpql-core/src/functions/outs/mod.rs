@@ -1,7 +1,9 @@
 mod min_outs_to_hand_type;
 mod nut_hi_outs;
+mod nut_lo_outs;
 mod outs_to_hand_type;
 
 pub use min_outs_to_hand_type::*;
 pub use nut_hi_outs::*;
+pub use nut_lo_outs::*;
 pub use outs_to_hand_type::*;
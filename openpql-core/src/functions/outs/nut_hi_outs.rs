@@ -38,8 +38,13 @@ fn is_nut_hi(game: PQLGame, p: PQLCardSet, b: PQLCardSet, known: PQLCardSet) ->
     match game {
         PQLGame::Holdem => HandN::<2>::iter_all::<false>().all(|h| check(h.into())),
         PQLGame::ShortDeck => HandN::<2>::iter_all::<true>().all(|h| check(h.into())),
-        PQLGame::Omaha => HandN::<4>::iter_all::<false>().all(|h| check(h.into())),
-        PQLGame::Omaha5 => HandN::<5>::iter_all::<false>().all(|h| check(h.into())),
+        PQLGame::Omaha | PQLGame::Omaha8 => {
+            HandN::<4>::iter_all::<false>().all(|h| check(h.into()))
+        }
+        PQLGame::Omaha5 | PQLGame::Omaha5HiLo => {
+            HandN::<5>::iter_all::<false>().all(|h| check(h.into()))
+        }
+        PQLGame::Omaha6 => HandN::<6>::iter_all::<false>().all(|h| check(h.into())),
     }
 }
 
@@ -0,0 +1,61 @@
+use crate::{PQLBoard, PQLCard, PQLCardCount, PQLCardSet, PQLGame, util::is_nut_lo};
+
+/// Counts the unseen cards that would give the hand an unbeatable low.
+#[expect(clippy::cast_possible_truncation, reason = "num of cards < u8::MAX")]
+pub fn nut_lo_outs(game: PQLGame, hand: &[PQLCard], board: PQLBoard) -> PQLCardCount {
+    let p = PQLCardSet::from(hand);
+    let b = PQLCardSet::from(board);
+    let known = p | b;
+
+    let all = if game.is_shortdeck() {
+        PQLCardSet::all::<true>()
+    } else {
+        PQLCardSet::all::<false>()
+    };
+
+    (all & !known)
+        .iter()
+        .filter(|&c| {
+            let c64 = PQLCardSet::from(c);
+            is_nut_lo(game, p, b | c64, known | c64)
+        })
+        .count() as PQLCardCount
+}
+
+#[cfg(test)]
+mod tests {
+    use openpql_prelude::{board, cards};
+
+    use super::*;
+
+    #[test]
+    fn test_nut_lo_outs_holdem() {
+        // A2 + 34K: any 5, 6, 7 or 8 makes a low no two-card holding can beat
+        assert_eq!(
+            nut_lo_outs(PQLGame::Holdem, &cards!("As 2h"), board!("3d 4c Kd")),
+            16,
+        );
+        // A6 + 34K: every low card leaves a better low for A2, A5 or 25
+        assert_eq!(
+            nut_lo_outs(PQLGame::Holdem, &cards!("As 6h"), board!("3d 4c Kd")),
+            0,
+        );
+    }
+
+    #[test]
+    fn test_nut_lo_outs_omaha() {
+        // A2 + 3 7 K: any 4, 5, 6 or 8 completes the nut low (4 ranks x 4 suits)
+        assert_eq!(
+            nut_lo_outs(PQLGame::Omaha, &cards!("As 2h Kd Kc"), board!("3d 7c Ks")),
+            16,
+        );
+    }
+
+    #[test]
+    fn test_nut_lo_outs_shortdeck() {
+        assert_eq!(
+            nut_lo_outs(PQLGame::ShortDeck, &cards!("As 6h"), board!("7d 8c Ks")),
+            0,
+        );
+    }
+}
@@ -25,11 +25,13 @@ pub use pql_player::*;
 pub use pql_street::*;
 
 // Category Types
+mod pql_draw_type;
 mod pql_flop_hand_category;
 mod pql_handtype;
 mod pql_hi_rating;
 mod pql_lo_rating;
 
+pub use pql_draw_type::*;
 pub use pql_flop_hand_category::*;
 pub use pql_handtype::*;
 pub use pql_hi_rating::*;
@@ -0,0 +1,2 @@
+/// Drawing hand on the flop or turn (e.g. gutshot, flush draw, combo draw).
+pub type PQLDrawType = openpql_prelude::DrawType;
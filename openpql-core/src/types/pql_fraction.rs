@@ -1,3 +1,5 @@
+use std::ops;
+
 use crate::PQLDouble;
 
 type FractionInner = i32;
@@ -37,6 +39,34 @@ impl PQLFraction {
 
         Self::new(1, den)
     }
+
+    /// Returns half of the fraction, as awarded by each side of a split pot.
+    #[must_use]
+    pub const fn half(self) -> Self {
+        Self::new(self.num, self.den * 2).reduced()
+    }
+
+    const fn reduced(self) -> Self {
+        let (mut a, mut b) = (self.num.abs(), self.den.abs());
+
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+
+        if a <= 1 {
+            self
+        } else {
+            Self::new(self.num / a, self.den / a)
+        }
+    }
+}
+
+impl ops::Add for PQLFraction {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.num * rhs.den + rhs.num * self.den, self.den * rhs.den).reduced()
+    }
 }
 
 #[cfg(test)]
@@ -56,4 +86,24 @@ pub mod tests {
         assert_eq!(f.num, 1);
         assert_eq!(f.den, 10);
     }
+
+    #[test]
+    fn test_half() {
+        assert_eq!(PQLFraction::pot_share(1).half(), PQLFraction::new(1, 2));
+        assert_eq!(PQLFraction::pot_share(3).half(), PQLFraction::new(1, 6));
+        assert_eq!(PQLFraction::new(2, 4).half(), PQLFraction::new(1, 4));
+        assert_eq!(PQLFraction::zero().half(), PQLFraction::zero());
+    }
+
+    #[test]
+    fn test_add() {
+        let quarter = PQLFraction::pot_share(2).half();
+
+        assert_eq!(quarter + quarter, PQLFraction::pot_share(2));
+        assert_eq!(
+            PQLFraction::pot_share(1).half() + quarter,
+            PQLFraction::new(3, 4)
+        );
+        assert_eq!(PQLFraction::zero() + quarter, quarter);
+    }
 }
@@ -1,2 +1,2 @@
-/// Low-hand rating; lower is better.
-pub type PQLLoRating = u8;
+/// Low-hand rating produced by the 8-or-better evaluator; lower is better.
+pub type PQLLoRating = openpql_prelude::LoRating;
@@ -1,6 +1,6 @@
 use std::mem;
 
-use crate::{PQLCardSet, PQLRankSet};
+use crate::{PQLCard, PQLCardSet, PQLGame, PQLRankSet};
 
 // duplicated from prelude
 #[inline]
@@ -18,3 +18,32 @@ pub const fn rank_cardinality(c: PQLCardSet) -> [PQLRankSet; 4] {
         mem::transmute([has1, has2, has3, has4])
     }
 }
+
+/// Lows depend only on ranks and every variant plays at most two hole
+/// cards toward the low, so it suffices to check each pair of distinct
+/// low ranks that still has an unseen card.
+pub fn is_nut_lo(game: PQLGame, p: PQLCardSet, b: PQLCardSet, known: PQLCardSet) -> bool {
+    let player_rating = game.eval_lo_rating(p, b);
+
+    if !player_rating.is_made() {
+        return false;
+    }
+
+    let unseen = PQLCardSet::all::<false>() & !known;
+    let lo_cards = unseen & PQLCardSet::from_ranks(PQLRankSet::ALL_LO);
+
+    let mut candidates: Vec<PQLCard> = vec![];
+    for c in lo_cards.iter() {
+        if candidates.iter().all(|o| o.rank != c.rank) {
+            candidates.push(c);
+        }
+    }
+
+    candidates.iter().enumerate().all(|(i, &c1)| {
+        candidates[i + 1..].iter().all(|&c2| {
+            let opp = PQLCardSet::from([c1, c2].as_slice());
+
+            game.eval_lo_rating(opp, b) >= player_rating
+        })
+    })
+}
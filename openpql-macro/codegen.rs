@@ -91,9 +91,19 @@ fn generate_execute_method(meta: &FnMetadata) -> TokenStream2 {
         .collect();
 
     let fn_call = if meta.rtn.is_result {
-        quote! { self(#(#arg_names),*)?.into() }
+        quote! { self(#(#arg_names),*)? }
     } else {
-        quote! { self(#(#arg_names),*).into() }
+        quote! { self(#(#arg_names),*) }
+    };
+
+    // strings live on the heap; the stack only holds their index
+    let fn_call = if meta.rtn.kind == "PQLString" {
+        quote! {{
+            let value = #fn_call;
+            ctx.heap.intern_str(value).into()
+        }}
+    } else {
+        quote! { #fn_call.into() }
     };
 
     quote! {
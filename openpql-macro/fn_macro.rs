@@ -99,6 +99,7 @@ fn id_to_pql_type(id: &Ident) -> TokenStream2 {
         "PQLEquity" => quote! { PQLType::EQUITY },
         "PQLNumeric" => quote! { PQLType::NUMERIC },
         "PQLCardCount" => quote! { PQLType::CARDCOUNT },
+        "PQLCardSet" => quote! { PQLType::CARDSET },
         "PQLLong" => quote! { PQLType::LONG },
         "PQLDouble" => quote! { PQLType::DOUBLE },
         "PQLCard" => quote! { PQLType::CARD },
@@ -106,12 +107,15 @@ fn id_to_pql_type(id: &Ident) -> TokenStream2 {
         "PQLRankSet" => quote! { PQLType::RANKSET },
         "PQLRank" => quote! { PQLType::RANK },
         "PQLFlopHandCategory" => quote! { PQLType::FLOPHANDCATEGORY },
+        "PQLDrawType" => quote! { PQLType::DRAWTYPE },
         "PQLHiRating" => quote! { PQLType::HIRATING },
+        "PQLLoRating" => quote! { PQLType::LORATING },
         "PQLHandType" => quote! { PQLType::HANDTYPE },
         "PQLFraction" => quote! { PQLType::FRACTION },
         "PQLString" => quote! { PQLType::STRING },
         "PQLRange" => quote! { PQLType::RANGE },
         "PQLBoardRange" => quote! { PQLType::BOARDRANGE },
+        "VmStackValue" => quote! { PQLType::STACKVALUE },
 
         _ => panic!("Unsupported type: {str_inner}"),
     }
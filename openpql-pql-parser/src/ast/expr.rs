@@ -1,4 +1,4 @@
-use super::{BinOp, FnCall, Ident, Loc, LocInfo, Num, Spanned, Str, UnaryOp, str};
+use super::{BinOp, FnCall, HandsHaving, Ident, Loc, LocInfo, Num, Spanned, Str, UnaryOp, str};
 
 /// Parsed expression tree.
 #[derive(Clone, PartialEq, derive_more::From, derive_more::Debug)]
@@ -12,6 +12,9 @@ pub enum Expr<'i> {
     /// Function call.
     #[debug("{_0:?}")]
     FnCall(FnCall<'i>),
+    /// `handsHaving` form.
+    #[debug("{_0:?}")]
+    HandsHaving(HandsHaving<'i>),
     /// Numeric literal.
     #[debug("{_0:?}")]
     Num(Num),
@@ -53,6 +56,7 @@ impl Spanned for Expr<'_> {
             Expr::Ident(id) => id.loc,
             Expr::Str(s) => s.loc,
             Expr::FnCall(fncall) => fncall.loc,
+            Expr::HandsHaving(hh) => hh.loc,
             Expr::Num(int) => int.loc,
             Expr::BinOp(_, l, r) => (l.loc().0, r.loc().1),
             Expr::UnaryOp(_, start, e) => (*start, e.loc().1),
@@ -1,5 +1,6 @@
 use super::{
-    Entry, Error, FxHashMap, Ident, Loc, LocInfo, ResultE, Spanned, Str, String, fmt, user_err,
+    Entry, Error, FxHashMap, Ident, Loc, LocInfo, Param, ResultE, Spanned, Str, String, fmt,
+    user_err,
 };
 
 /// Parsed `from` clause, indexed by lowercased key.
@@ -15,8 +16,13 @@ impl<'i> FromClause<'i> {
     const BOARD_KEY: &'static str = "board";
     const GAME_KEY: &'static str = "game";
     const DEADCARD_KEY: &'static str = "dead";
-    const NON_PLAYER_KEYS: [&'static str; 3] =
-        [Self::BOARD_KEY, Self::GAME_KEY, Self::DEADCARD_KEY];
+    const SEED_KEY: &'static str = "seed";
+    const NON_PLAYER_KEYS: [&'static str; 4] = [
+        Self::BOARD_KEY,
+        Self::GAME_KEY,
+        Self::DEADCARD_KEY,
+        Self::SEED_KEY,
+    ];
 
     pub(crate) fn new<T: IntoIterator<Item = FromItem<'i>>>(
         items: T,
@@ -39,27 +45,43 @@ impl<'i> FromClause<'i> {
         Ok(res)
     }
 
-    fn get_val(&self, key: &str) -> Option<&Str<'_>> {
+    fn get_val(&self, key: &str) -> Option<&FromValue<'_>> {
         self.inner.get(key).as_ref().map(|item| &item.value)
     }
 
     /// Returns the `board` value, if provided.
-    pub fn get_board_range(&self) -> Option<&Str<'_>> {
+    pub fn get_board_range(&self) -> Option<&FromValue<'_>> {
         self.get_val(Self::BOARD_KEY)
     }
 
     /// Returns the `game` value, if provided.
-    pub fn get_game(&self) -> Option<&Str<'_>> {
+    pub fn get_game(&self) -> Option<&FromValue<'_>> {
         self.get_val(Self::GAME_KEY)
     }
 
     /// Returns the `dead` (dead-cards) value, if provided.
-    pub fn get_dead(&self) -> Option<&Str<'_>> {
+    pub fn get_dead(&self) -> Option<&FromValue<'_>> {
         self.get_val(Self::DEADCARD_KEY)
     }
 
+    /// Returns the `seed` value, if provided.
+    pub fn get_seed(&self) -> Option<&FromValue<'_>> {
+        self.get_val(Self::SEED_KEY)
+    }
+
+    /// Returns every `$name` placeholder of the clause.
+    pub fn get_params(&self) -> Vec<&Param<'_>> {
+        self.inner
+            .values()
+            .filter_map(|item| match &item.value {
+                FromValue::Param(param) => Some(param),
+                FromValue::Str(_) => None,
+            })
+            .collect()
+    }
+
     /// Returns all player entries, i.e. items that are not reserved keys.
-    pub fn get_players(&self) -> Vec<(&Ident<'_>, &Str<'_>)> {
+    pub fn get_players(&self) -> Vec<(&Ident<'_>, &FromValue<'_>)> {
         self.inner
             .keys()
             .filter(|k| !Self::NON_PLAYER_KEYS.contains(&k.as_str()))
@@ -75,7 +97,7 @@ impl fmt::Debug for FromClause<'_> {
             .entries(
                 self.inner
                     .values()
-                    .map(|item| (item.key.inner, item.value.inner)),
+                    .map(|item| (item.key.inner, &item.value)),
             )
             .finish()
     }
@@ -87,25 +109,43 @@ impl Spanned for FromClause<'_> {
     }
 }
 
-/// Single `key = 'value'` entry in a `from` clause.
+/// Value of a `from` entry: a string literal or a placeholder.
+#[derive(Clone, PartialEq, Eq, derive_more::From, derive_more::Debug)]
+pub enum FromValue<'i> {
+    #[debug("{_0:?}")]
+    Str(Str<'i>),
+    #[debug("{_0:?}")]
+    Param(Param<'i>),
+}
+
+impl Spanned for FromValue<'_> {
+    fn loc(&self) -> LocInfo {
+        match self {
+            Self::Str(s) => s.loc,
+            Self::Param(p) => p.loc,
+        }
+    }
+}
+
+/// Single `key = 'value'` or `key = $name` entry in a `from` clause.
 #[derive(PartialEq, Eq, Debug)]
 pub struct FromItem<'i> {
     /// Entry key.
     pub key: Ident<'i>,
-    /// Entry string value.
-    pub value: Str<'i>,
+    /// Entry value.
+    pub value: FromValue<'i>,
 }
 
 impl Spanned for FromItem<'_> {
     fn loc(&self) -> LocInfo {
-        (self.key.loc.0, self.value.loc.1)
+        (self.key.loc.0, self.value.loc().1)
     }
 }
 
 impl<'i, U, V> From<(U, V)> for FromItem<'i>
 where
     U: Into<Ident<'i>>,
-    V: Into<Str<'i>>,
+    V: Into<FromValue<'i>>,
 {
     fn from(t: (U, V)) -> Self {
         Self {
@@ -128,7 +168,10 @@ mod tests {
         let mut res = FxHashMap::default();
         for (key, val) in kvs {
             let id = Ident::from((*key, loc(src, key)));
-            let s = Str::from((strip_str(val), loc(src, val)));
+            let s = val.strip_prefix('$').map_or_else(
+                || Str::from((strip_str(val), loc(src, val))).into(),
+                |name| FromValue::from(Param::from((name, loc(src, val)))),
+            );
             res.insert((*key).to_string(), FromItem::from((id, s)));
         }
 
@@ -146,6 +189,21 @@ mod tests {
         assert_from_clause(src, &[("game", "'holdem'"), ("hero", "'AA'")]);
     }
 
+    #[test]
+    fn test_from_clause_params() {
+        let src = "from hero=$1, villain='KK', board=$board";
+
+        assert_from_clause(
+            src,
+            &[("hero", "$1"), ("villain", "'KK'"), ("board", "$board")],
+        );
+
+        let obj = parse_from_clause(src).unwrap();
+        let mut names: Vec<_> = obj.get_params().iter().map(|p| p.name).collect();
+        names.sort_unstable();
+        assert_eq!(names, ["1", "board"]);
+    }
+
     #[test]
     fn test_from_clause_norm_key() {
         let obj = parse_from_clause("from GAME=''").unwrap().inner;
@@ -159,9 +217,20 @@ mod tests {
         assert_eq!(obj.get_game(), None);
         assert_eq!(obj.get_board_range(), None);
         assert_eq!(obj.get_dead(), None);
+        assert_eq!(obj.get_seed(), None);
         //assert_eq!(obj.get_players(), &[("hero", "AA")]);
     }
 
+    #[test]
+    fn test_seed_is_not_a_player() {
+        let obj = parse_from_clause("from hero='AA', seed='42'").unwrap();
+        assert_eq!(
+            obj.get_seed(),
+            Some(&Str::from(("42", loc("from hero='AA', seed='42'", "'42'"))).into())
+        );
+        assert_eq!(obj.get_players().len(), 1);
+    }
+
     fn assert_err(src: &str, expected: Error) {
         assert_eq!(parse_from_clause(src).unwrap_err(), expected);
     }
@@ -177,6 +246,10 @@ mod tests {
         let obj = parse_from_clause("from game='holdem', hero='AA'").unwrap();
 
         assert!(format!("{obj:?}").find(r#"hero": "AA"#).is_some());
+
+        let obj = parse_from_clause("from hero=$1").unwrap();
+
+        assert!(format!("{obj:?}").find(r#"hero": $1"#).is_some());
     }
 
     #[test]
@@ -192,6 +265,6 @@ mod tests {
         let src = "from key='val'";
         let obj = parse_from_clause(src).unwrap();
         let item = obj.inner.get("key").unwrap();
-        assert_eq!(item.loc(), (item.key.loc.0, item.value.loc.1));
+        assert_eq!(item.loc(), (item.key.loc.0, item.value.loc().1));
     }
 }
@@ -0,0 +1,114 @@
+use std::fmt;
+
+use super::{Expr, Ident, Loc, LocInfo, Spanned};
+
+/// `handsHaving(player, [street,] expr)`: `expr` evaluated over every combo
+/// of the player's range instead of the dealt hand.
+#[derive(Clone, PartialEq)]
+pub struct HandsHaving<'i> {
+    /// Player whose range is enumerated.
+    pub player: Ident<'i>,
+    /// Street whose board the combos must avoid; the river if omitted.
+    pub street: Option<Box<Expr<'i>>>,
+    /// Boolean expression evaluated once per combo.
+    pub expr: Box<Expr<'i>>,
+
+    /// Source span covering the whole form.
+    pub loc: (Loc, Loc),
+}
+
+type Parts<'i> = (Ident<'i>, Expr<'i>, Option<Expr<'i>>, (Loc, Loc));
+
+impl<'i> From<Parts<'i>> for HandsHaving<'i> {
+    fn from((player, first, second, loc): Parts<'i>) -> Self {
+        let (street, expr) = match second {
+            Some(expr) => (Some(Box::new(first)), expr),
+            None => (None, first),
+        };
+
+        Self {
+            player,
+            street,
+            expr: Box::new(expr),
+            loc,
+        }
+    }
+}
+
+impl fmt::Debug for HandsHaving<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "handsHaving({:?},", self.player)?;
+
+        if let Some(street) = &self.street {
+            write!(f, "{street:?},")?;
+        }
+
+        write!(f, "{:?})", self.expr)
+    }
+}
+
+impl Spanned for HandsHaving<'_> {
+    fn loc(&self) -> LocInfo {
+        self.loc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_hands_having() {
+        let src = "handsHaving(villain, pocketPair(villain))";
+        let Ok(Expr::HandsHaving(hh)) = parse_expr(src) else {
+            panic!("not handsHaving: {src}");
+        };
+
+        assert_eq!(hh.player, Ident::from(("villain", loc(src, "villain"))));
+        assert!(hh.street.is_none());
+        assert_eq!(format!("{:?}", hh.expr), "pocketPair(villain)");
+        assert_eq!(hh.loc(), (0, src.len()));
+    }
+
+    #[test]
+    fn test_street() {
+        let src = "handsHaving(villain, flop, pocketPair(villain),)";
+        let Ok(Expr::HandsHaving(hh)) = parse_expr(src) else {
+            panic!("not handsHaving: {src}");
+        };
+
+        assert_eq!(format!("{:?}", hh.street.unwrap()), "flop");
+        assert_eq!(format!("{:?}", hh.expr), "pocketPair(villain)");
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert!(matches!(
+            parse_expr("HANDSHAVING(hero, true)"),
+            Ok(Expr::HandsHaving(_))
+        ));
+    }
+
+    #[test]
+    fn test_err() {
+        assert!(parse_expr("handsHaving(hero)").is_err());
+        assert!(parse_expr("handsHaving('AA', true)").is_err());
+        assert!(parse_expr("handsHaving(hero, flop, true, true)").is_err());
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(
+            format!("{:?}", parse_expr("handsHaving(hero, 1 = 1)").unwrap()),
+            "handsHaving(hero,1 = 1)"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                parse_expr("handsHaving(hero, turn, 1 = 1)").unwrap()
+            ),
+            "handsHaving(hero,turn,1 = 1)"
+        );
+    }
+}
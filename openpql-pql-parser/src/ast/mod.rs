@@ -7,8 +7,10 @@ mod bin_op;
 mod expr;
 mod fncall;
 mod from_clause;
+mod hands_having;
 mod ident;
 mod num;
+mod param;
 mod selector;
 mod selector_kind;
 mod stmt;
@@ -18,9 +20,11 @@ mod unary_op;
 pub use bin_op::BinOp;
 pub use expr::Expr;
 pub use fncall::FnCall;
-pub use from_clause::{FromClause, FromItem};
+pub use from_clause::{FromClause, FromItem, FromValue};
+pub use hands_having::HandsHaving;
 pub use ident::Ident;
 pub use num::{Num, NumValue};
+pub use param::Param;
 pub use selector::Selector;
 pub use selector_kind::SelectorKind;
 pub use stmt::Stmt;
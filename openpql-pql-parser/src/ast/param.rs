@@ -0,0 +1,36 @@
+use super::{Loc, LocInfo, Spanned};
+
+/// `$name` placeholder in a `from` clause, bound to a value at run time.
+#[derive(Clone, PartialEq, Eq, derive_more::From, derive_more::Debug)]
+#[debug("${}", self.name)]
+pub struct Param<'i> {
+    /// Placeholder name without the leading `$`, e.g. `1` or `board`.
+    pub name: &'i str,
+    /// Source span including the `$`.
+    pub loc: (Loc, Loc),
+}
+
+impl Spanned for Param<'_> {
+    fn loc(&self) -> LocInfo {
+        self.loc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_param() {
+        assert_eq!(parse_param("$1"), Ok(("1", (0, 2)).into()));
+        assert_eq!(parse_param("$board"), Ok(("board", (0, 6)).into()));
+        assert!(parse_param("$").is_err());
+        assert!(parse_param("$ 1").is_err());
+    }
+
+    #[test]
+    fn test_dbg() {
+        assert_eq!(format!("{:?}", Param::from(("hero", (0, 5)))), "$hero");
+    }
+}
@@ -1,33 +1,115 @@
-use super::{Error, Expr, Ident, ResultE, SelectorKind, String};
+use super::{Error, Expr, Ident, Num, NumValue, NumValueFloat, ResultE, SelectorKind, String};
 
-/// Aggregate selector such as `avg(expr) as alias`.
+/// Aggregate selector such as `avg(expr) filter (where pred) as alias`.
 #[derive(Clone, PartialEq, derive_more::Debug)]
-#[debug("{:?}({:?}){}", self.kind, self.expr, _alias_to_str(self.alias.as_ref()))]
+#[debug(
+    "{:?}({:?}){}{}",
+    self.kind,
+    self.expr,
+    _filter_to_str(self.filter.as_ref()),
+    _alias_to_str(self.alias.as_ref())
+)]
 pub struct Selector<'i> {
     /// Aggregate kind.
     pub kind: SelectorKind,
     /// Inner expression the aggregate applies to.
     pub expr: Expr<'i>,
+    /// Optional `filter (where ...)` predicate; trials for which it is
+    /// false are not aggregated by this selector.
+    pub filter: Option<Expr<'i>>,
     /// Optional `as` alias.
     pub alias: Option<Ident<'i>>,
 }
 
+fn _filter_to_str(filter: Option<&Expr>) -> String {
+    filter.map_or_else(String::default, |expr| format!(" filter (where {expr:?})"))
+}
+
 fn _alias_to_str(alias: Option<&Ident>) -> String {
     alias.map_or_else(String::default, |id| format!(" as {id:?}"))
 }
 
 impl<'i> Selector<'i> {
     /// Builds a selector, resolving `kind` against the supported aggregates.
-    pub fn new(kind: &Ident<'i>, expr: Expr<'i>, alias: Option<Ident<'i>>) -> ResultE<'i, Self> {
+    ///
+    /// `arg` is the bin count of `histogram` or the quantile of
+    /// `percentile`, which requires it; other aggregates take none.
+    pub fn new(
+        kind: &Ident<'i>,
+        expr: Expr<'i>,
+        arg: Option<Num>,
+        filter: Option<Expr<'i>>,
+        alias: Option<Ident<'i>>,
+    ) -> ResultE<'i, Self> {
         let kind = match kind.inner.to_ascii_lowercase().as_str() {
             "avg" => SelectorKind::Avg,
             "count" => SelectorKind::Count,
             "max" => SelectorKind::Max,
             "min" => SelectorKind::Min,
+            "sum" => SelectorKind::Sum,
+            "stddev" => SelectorKind::Stddev,
+            "variance" => SelectorKind::Variance,
+            "median" => SelectorKind::Median,
+            "histogram" | "dist" => SelectorKind::Histogram {
+                n_bins: arg
+                    .as_ref()
+                    .map_or(Ok(SelectorKind::DEFAULT_N_BINS), n_bins)?,
+            },
+            "percentile" => SelectorKind::Percentile {
+                per_mille: arg.as_ref().map_or_else(
+                    || Err(Error::InvalidSelectorArgument(kind.loc).into()),
+                    quantile,
+                )?,
+            },
             _ => return Err(Error::UnrecognizedSelector(kind.loc).into()),
         };
 
-        Ok(Self { kind, expr, alias })
+        match arg {
+            Some(num)
+                if !matches!(
+                    kind,
+                    SelectorKind::Histogram { .. } | SelectorKind::Percentile { .. }
+                ) =>
+            {
+                Err(Error::InvalidSelectorArgument(num.loc).into())
+            }
+            _ => Ok(Self {
+                kind,
+                expr,
+                filter,
+                alias,
+            }),
+        }
+    }
+}
+
+fn n_bins<'i>(num: &Num) -> ResultE<'i, u16> {
+    match num.inner {
+        NumValue::Int(n) if n > 0 => {
+            u16::try_from(n).map_err(|_| Error::InvalidSelectorArgument(num.loc).into())
+        }
+        _ => Err(Error::InvalidSelectorArgument(num.loc).into()),
+    }
+}
+
+/// Quantile in thousandths; finer quantiles are rejected rather than
+/// rounded.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn quantile<'i>(num: &Num) -> ResultE<'i, u16> {
+    let p = match num.inner {
+        NumValue::Int(n) => n as NumValueFloat,
+        NumValue::Float(p) => p,
+    };
+    let per_mille = (p * 1000.0).round();
+
+    if (0.0..=1000.0).contains(&per_mille) && p.mul_add(1000.0, -per_mille).abs() < 1e-6 {
+        Ok(per_mille as u16)
+    } else {
+        Err(Error::InvalidSelectorArgument(num.loc).into())
     }
 }
 
@@ -51,6 +133,56 @@ mod tests {
         assert_selector("count(_)", "count(_)");
         assert_selector("max(_)", "max(_)");
         assert_selector("min(_)", "min(_)");
+        assert_selector("histogram(_)", "histogram(_)");
+        assert_selector("dist(_, 20) as d", "histogram(_) as d");
+        assert_selector("sum(_)", "sum(_)");
+        assert_selector("stddev(_)", "stddev(_)");
+        assert_selector("variance(_)", "variance(_)");
+        assert_selector("median(_)", "median(_)");
+        assert_selector("percentile(_, 0.9)", "percentile(_)");
+    }
+
+    #[test]
+    fn test_selector_percentile() {
+        let p = |src| match parse_selector(src).unwrap().kind {
+            ast::SelectorKind::Percentile { per_mille } => per_mille,
+            _ => unreachable!(),
+        };
+
+        assert_eq!(p("percentile(_, 0.9)"), 900);
+        assert_eq!(p("percentile(_, 1)"), 1000);
+        assert_eq!(p("percentile(_, .25)"), 250);
+        assert_eq!(p("percentile(_, 0.999)"), 999);
+    }
+
+    #[test]
+    fn test_selector_filter() {
+        assert_selector(
+            "avg(equity(hero)) filter (where pairedBoard(flop))",
+            "avg(equity(hero)) filter (where pairedBoard(flop))",
+        );
+        assert_selector(
+            "count(x) FILTER (WHERE y = 1) as s1",
+            "count(x) filter (where y = 1) as s1",
+        );
+        assert_selector(
+            "histogram(x, 4) filter (where y) as h",
+            "histogram(x) filter (where y) as h",
+        );
+
+        assert!(parse_selector("avg(x) filter (y)").is_err());
+        assert!(parse_selector("avg(x) as s1 filter (where y)").is_err());
+    }
+
+    #[test]
+    fn test_selector_n_bins() {
+        let n_bins = |src| match parse_selector(src).unwrap().kind {
+            ast::SelectorKind::Histogram { n_bins } => n_bins,
+            _ => unreachable!(),
+        };
+
+        assert_eq!(n_bins("histogram(_)"), ast::SelectorKind::DEFAULT_N_BINS);
+        assert_eq!(n_bins("histogram(_, 20)"), 20);
     }
 
     fn assert_err(src: &str, expected: Error) {
@@ -61,5 +193,20 @@ mod tests {
     fn test_selector_err() {
         let src = "invalid(_)";
         assert_err(src, Error::UnrecognizedSelector(loc(src, "invalid")));
+
+        for src in [
+            "avg(_, 10)",
+            "histogram(_, 0)",
+            "histogram(_, -1)",
+            "histogram(_, 0.5)",
+            "percentile(_, 1.5)",
+            "percentile(_, 0.9995)",
+        ] {
+            let (start, _) = loc(src, ", ");
+            assert_err(
+                src,
+                Error::InvalidSelectorArgument((start + 2, src.len() - 1)),
+            );
+        }
     }
 }
@@ -1,4 +1,4 @@
-use super::fmt;
+use super::{NumValueFloat, fmt};
 
 /// Aggregate kind used by a selector.
 #[derive(Clone, Copy, PartialEq, Eq, derive_more::Debug)]
@@ -15,6 +15,35 @@ pub enum SelectorKind {
     /// Minimum value.
     #[debug("min")]
     Min,
+    /// Sum over matching rows.
+    #[debug("sum")]
+    Sum,
+    /// Sample standard deviation.
+    #[debug("stddev")]
+    Stddev,
+    /// Sample variance.
+    #[debug("variance")]
+    Variance,
+    /// Approximate median.
+    #[debug("median")]
+    Median,
+    /// Approximate quantile of `per_mille` thousandths, up to 1000.
+    #[debug("percentile")]
+    Percentile { per_mille: u16 },
+    /// Frequency of each distinct value; doubles and fractions are
+    /// bucketed into `n_bins` bins per unit.
+    #[debug("histogram")]
+    Histogram { n_bins: u16 },
+}
+
+impl SelectorKind {
+    /// Bins per unit of a `histogram` without an explicit count.
+    pub const DEFAULT_N_BINS: u16 = 10;
+
+    /// Quantile of a `percentile` in `[0, 1]`.
+    pub fn quantile(per_mille: u16) -> NumValueFloat {
+        NumValueFloat::from(per_mille) / 1000.0
+    }
 }
 
 impl fmt::Display for SelectorKind {
@@ -24,6 +53,12 @@ impl fmt::Display for SelectorKind {
             Self::Count => f.write_str("COUNT"),
             Self::Max => f.write_str("MAX"),
             Self::Min => f.write_str("MIN"),
+            Self::Sum => f.write_str("SUM"),
+            Self::Stddev => f.write_str("STDDEV"),
+            Self::Variance => f.write_str("VARIANCE"),
+            Self::Median => f.write_str("MEDIAN"),
+            Self::Percentile { .. } => f.write_str("PERCENTILE"),
+            Self::Histogram { .. } => f.write_str("HISTOGRAM"),
         }
     }
 }
@@ -38,5 +73,23 @@ mod tests {
         assert_eq!(SelectorKind::Count.to_string(), "COUNT");
         assert_eq!(SelectorKind::Max.to_string(), "MAX");
         assert_eq!(SelectorKind::Min.to_string(), "MIN");
+        assert_eq!(SelectorKind::Sum.to_string(), "SUM");
+        assert_eq!(SelectorKind::Stddev.to_string(), "STDDEV");
+        assert_eq!(SelectorKind::Variance.to_string(), "VARIANCE");
+        assert_eq!(SelectorKind::Median.to_string(), "MEDIAN");
+        assert_eq!(
+            SelectorKind::Percentile { per_mille: 900 }.to_string(),
+            "PERCENTILE"
+        );
+        assert_eq!(
+            SelectorKind::Histogram { n_bins: 10 }.to_string(),
+            "HISTOGRAM"
+        );
+    }
+
+    #[test]
+    fn test_quantile() {
+        assert_eq!(SelectorKind::quantile(900).to_bits(), 0.9f64.to_bits());
+        assert_eq!(SelectorKind::quantile(1000).to_bits(), 1.0f64.to_bits());
     }
 }
@@ -1,6 +1,6 @@
 use super::{Error, Expr, FromClause, FxHashSet, ResultE, Selector, user_err};
 
-/// A single `select ... from ... [where ...]` statement.
+/// A single `select ... from ... [where ...] [group by ...]` statement.
 #[derive(PartialEq, Debug)]
 pub struct Stmt<'i> {
     /// Aggregate selectors in the `select` list.
@@ -9,6 +9,8 @@ pub struct Stmt<'i> {
     pub from: FromClause<'i>,
     /// Optional `where` predicate.
     pub where_clause: Option<Expr<'i>>,
+    /// Optional `group by` key.
+    pub group_by: Option<Expr<'i>>,
 }
 
 fn ensure_uniq_names<'i>(selectors: &[Selector]) -> ResultE<'i, ()> {
@@ -31,6 +33,7 @@ impl<'i> Stmt<'i> {
         selectors: Vec<Selector<'i>>,
         from: FromClause<'i>,
         where_clause: Option<Expr<'i>>,
+        group_by: Option<Expr<'i>>,
     ) -> ResultE<'i, Self> {
         ensure_uniq_names(&selectors)?;
 
@@ -38,6 +41,7 @@ impl<'i> Stmt<'i> {
             selectors,
             from,
             where_clause,
+            group_by,
         })
     }
 }
@@ -90,4 +94,25 @@ mod tests {
         let _ = s("select count(_) from _='' where not 1 = 2");
         let _ = s("select count(_) from _='' where not 1 = 2 and 1 = 1");
     }
+
+    #[test]
+    fn test_stmt_group_by() {
+        let stmt = s("select count(_) from _=''");
+        assert!(stmt.group_by.is_none());
+
+        let stmt = s("select avg(_) from _='' group by handType(hero, flop)");
+        assert_eq!(
+            format!("{:?}", stmt.group_by.unwrap()),
+            "handType(hero,flop)"
+        );
+
+        let stmt = s("select avg(_) from _='' WHERE 1 = 1 Group By x");
+        assert!(stmt.where_clause.is_some());
+        assert!(stmt.group_by.is_some());
+
+        assert!(matches!(
+            e("select avg(_) from _='' group by x where 1 = 1"),
+            Error::UnrecognizedToken(..)
+        ));
+    }
 }
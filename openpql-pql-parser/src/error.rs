@@ -14,6 +14,8 @@ pub enum Error {
 
     /// Selector name is not one of the supported aggregates.
     UnrecognizedSelector(LocInfo),
+    /// Selector argument is not accepted by its aggregate.
+    InvalidSelectorArgument(LocInfo),
     /// `from` clause contains the same key more than once.
     DuplicatedKeyInFrom(LocInfo),
     /// Two selectors share the same alias.
@@ -62,6 +64,7 @@ impl From<&Error> for LocInfo {
             | Error::UnrecognizedToken(loc, _)
             | Error::ExtraToken(loc)
             | Error::UnrecognizedSelector(loc)
+            | Error::InvalidSelectorArgument(loc)
             | Error::DuplicatedKeyInFrom(loc)
             | Error::DuplicatedSelectorName(loc)
             | Error::InvalidNumericValue(loc) => *loc,
@@ -82,6 +85,7 @@ mod tests {
         assert_eq!(loc, (&Error::UnrecognizedToken(loc, vec![])).into());
         assert_eq!(loc, (&Error::ExtraToken(loc)).into());
         assert_eq!(loc, (&Error::UnrecognizedSelector(loc)).into());
+        assert_eq!(loc, (&Error::InvalidSelectorArgument(loc)).into());
         assert_eq!(loc, (&Error::DuplicatedKeyInFrom(loc)).into());
         assert_eq!(loc, (&Error::DuplicatedSelectorName(loc)).into());
         assert_eq!(loc, (&Error::InvalidNumericValue(loc)).into());
@@ -17,8 +17,8 @@ mod spanned;
 pub use error::Error;
 use error::{LalrError, ResultE, user_err};
 use parser::{
-    ExprParser, FnCallParser, FromClauseParser, IdentParser, NumParser, PQLParser, SelectorParser,
-    StrParser,
+    ExprParser, FnCallParser, FromClauseParser, IdentParser, NumParser, PQLParser, ParamParser,
+    SelectorParser, StrParser,
 };
 pub use spanned::Spanned;
 
@@ -54,6 +54,11 @@ pub fn parse_str(src: &str) -> Result<ast::Str<'_>, Error> {
     StrParser::new().parse(src).map_err(Into::into)
 }
 
+/// Parses a `$name` placeholder.
+pub fn parse_param(src: &str) -> Result<ast::Param<'_>, Error> {
+    ParamParser::new().parse(src).map_err(Into::into)
+}
+
 /// Parses a numeric literal.
 pub fn parse_num(src: &str) -> Result<ast::Num, Error> {
     NumParser::new().parse(src).map_err(Into::into)
@@ -13,7 +13,7 @@ use crate::{
 };
 
 /// Maximum number of hole cards stored inline.
-pub const MAX_HOLECARDS: usize = 5;
+pub const MAX_HOLECARDS: usize = 6;
 
 type Inner<C> = SmallVec<[C; MAX_HOLECARDS]>;
 
@@ -53,6 +53,7 @@ impl IsomorphicHand {
             2 => Self::from_arr(IsomorphicHandN::<2>::from_slice_and_map(cards, map).0),
             4 => Self::from_arr(IsomorphicHandN::<4>::from_slice_and_map(cards, map).0),
             5 => Self::from_arr(IsomorphicHandN::<5>::from_slice_and_map(cards, map).0),
+            6 => Self::from_arr(IsomorphicHandN::<6>::from_slice_and_map(cards, map).0),
             _ => unimplemented!(), // LCOV_EXCL_LINE
         }
     }
@@ -90,25 +91,29 @@ where
             match N {
                 0 => SmallVec::new_const(),
                 1 => SmallVec::from_const_with_len_unchecked(
-                    [arr[0], arr[0], arr[0], arr[0], arr[0]],
+                    [arr[0], arr[0], arr[0], arr[0], arr[0], arr[0]],
                     1,
                 ),
                 2 => SmallVec::from_const_with_len_unchecked(
-                    [arr[0], arr[1], arr[0], arr[0], arr[0]],
+                    [arr[0], arr[1], arr[0], arr[0], arr[0], arr[0]],
                     2,
                 ),
                 3 => SmallVec::from_const_with_len_unchecked(
-                    [arr[0], arr[1], arr[2], arr[0], arr[0]],
+                    [arr[0], arr[1], arr[2], arr[0], arr[0], arr[0]],
                     3,
                 ),
                 4 => SmallVec::from_const_with_len_unchecked(
-                    [arr[0], arr[1], arr[2], arr[3], arr[0]],
+                    [arr[0], arr[1], arr[2], arr[3], arr[0], arr[0]],
                     4,
                 ),
                 5 => SmallVec::from_const_with_len_unchecked(
-                    [arr[0], arr[1], arr[2], arr[3], arr[4]],
+                    [arr[0], arr[1], arr[2], arr[3], arr[4], arr[0]],
                     5,
                 ),
+                6 => SmallVec::from_const_with_len_unchecked(
+                    [arr[0], arr[1], arr[2], arr[3], arr[4], arr[5]],
+                    6,
+                ),
                 _ => unreachable!(), // LCOV_EXCL_LINE
             }
         };
@@ -222,7 +227,7 @@ mod tests {
     use crate::{CardN, FlushingSuit, cards, isocard, isocards};
 
     #[quickcheck]
-    fn test_from_arr(cards: CardN<5>) {
+    fn test_from_arr(cards: CardN<6>) {
         fn assert_eq<const N: usize>(cs: &[Card]) {
             let arr = <[Card; N]>::try_from(&cs[0..N]).unwrap();
 
@@ -235,6 +240,7 @@ mod tests {
         assert_eq::<3>(cards.as_slice());
         assert_eq::<4>(cards.as_slice());
         assert_eq::<5>(cards.as_slice());
+        assert_eq::<6>(cards.as_slice());
     }
 
     #[test]
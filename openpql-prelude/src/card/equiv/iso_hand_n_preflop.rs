@@ -1,11 +1,15 @@
 use crate::{
     Card, FlushingSuit, IsomorphicCard, IsomorphicHandN,
-    card::equiv::{IsomorphicTurnEv, isomorphic_omaha5::iso_hand5_preflop},
+    card::equiv::{
+        IsomorphicTurnEv, isomorphic_omaha5::iso_hand5_preflop,
+        isomorphic_omaha6::iso_hand6_preflop,
+    },
 };
 
 const N_HOLDEM: usize = 2;
 const N_OMAHA: usize = 4;
 const N_OMAHA5: usize = 5;
+const N_OMAHA6: usize = 6;
 
 impl IsomorphicHandN<N_HOLDEM> {
     #[must_use]
@@ -56,3 +60,15 @@ impl IsomorphicHandN<N_OMAHA5> {
         iso_hand5_preflop(cards)
     }
 }
+
+impl IsomorphicHandN<N_OMAHA6> {
+    #[must_use]
+    pub fn from_slice_preflop(cards: &[Card]) -> Self {
+        debug_assert!(
+            cards.len() >= N_OMAHA6, // LCOV_EXCL_LINE
+            "not enough cards for IsomorphicHandN<6>"
+        );
+
+        iso_hand6_preflop(cards)
+    }
+}
@@ -0,0 +1,92 @@
+//! Suit-isomorphic canonical form of a six-card Omaha starting hand.
+
+use std::array;
+
+use smallvec::SmallVec;
+
+use crate::{Card, Card64, CardCount, IsomorphicCard, IsomorphicHandN, Rank16, Suit, SuitMap};
+
+const N_OMAHA6: usize = 6;
+const N_FLUSH_PLAYER: CardCount = 2;
+
+type RanksSuit = (Rank16, Suit);
+
+pub(super) fn iso_hand6_preflop(cards: &[Card]) -> IsomorphicHandN<N_OMAHA6> {
+    let map = hand6_suit_map(Card64::from(&cards[..N_OMAHA6]));
+
+    let mut inner: [IsomorphicCard; N_OMAHA6] = array::from_fn(|i| map.iso_card(cards[i]));
+    inner.sort_unstable();
+
+    IsomorphicHandN(inner)
+}
+
+/// Relabels every suit holding at least two cards, ordered by its ranks;
+/// singleton suits can never make an Omaha flush and are left irrelevant.
+fn hand6_suit_map(c64: Card64) -> SuitMap {
+    let mut flush_suits: SmallVec<[RanksSuit; 3]> = Suit::ARR_ALL
+        .iter()
+        .map(|&suit| (c64.ranks_by_suit(suit), suit))
+        .filter(|(ranks, _)| ranks.count() >= N_FLUSH_PLAYER)
+        .collect();
+
+    flush_suits.sort_unstable_by_key(|&(ranks, _)| ranks);
+
+    match flush_suits.as_slice() {
+        [(_, s0)] => SuitMap::map1(*s0),
+        [(_, s0), (_, s1)] => SuitMap::map2(*s0, *s1),
+        [(_, s0), (_, s1), (_, s2)] => SuitMap::map3(*s0, *s1, *s2),
+        _ => unreachable!(), // LCOV_EXCL_LINE
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn swap_suit(c: Card, a: Suit, b: Suit) -> Card {
+        let suit = if c.suit == a {
+            b
+        } else if c.suit == b {
+            a
+        } else {
+            c.suit
+        };
+        Card::new(c.rank, suit)
+    }
+
+    #[quickcheck]
+    fn test_iso_hand6_preflop_suit_permutation_invariant(cs: CardN<6>, a: Suit, b: Suit) {
+        let swapped: Vec<Card> = cs.as_slice().iter().map(|&c| swap_suit(c, a, b)).collect();
+        assert_eq!(
+            iso_hand6_preflop(cs.as_slice()),
+            iso_hand6_preflop(&swapped),
+            "{cs:?} vs {swapped:?}"
+        );
+    }
+
+    #[test]
+    fn test_iso_hand6_preflop_distinguishes_flush_shapes() {
+        let triple_suited = cards!("As Ks Qh Jh Td 9d");
+        let double_suited = cards!("As Ks Qh Jh Td 9c");
+        let single_suited = cards!("As Ks Qs Jh Td 9c");
+
+        assert_ne!(
+            iso_hand6_preflop(&triple_suited),
+            iso_hand6_preflop(&double_suited)
+        );
+        assert_ne!(
+            iso_hand6_preflop(&double_suited),
+            iso_hand6_preflop(&single_suited)
+        );
+    }
+
+    #[test]
+    fn test_iso_hand6_preflop_ignores_singleton_suits() {
+        assert_eq!(
+            iso_hand6_preflop(&cards!("As Ks Qh Jd Tc 9s")),
+            iso_hand6_preflop(&cards!("As Ks Qd Jc Th 9s")),
+        );
+    }
+}
@@ -289,6 +289,13 @@ impl fmt::Debug for Card64 {
     }
 }
 
+/// The cards in iteration order, e.g. `2s3hAd`.
+impl fmt::Display for Card64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.iter().try_for_each(|card| write!(f, "{card}"))
+    }
+}
+
 impl From<Card> for Card64 {
     fn from(c: Card) -> Self {
         Self::from_card(c)
@@ -494,6 +501,10 @@ mod tests {
     fn test_display() {
         assert_eq!(format!("{:?}", c64!("As")), "Card64<As>");
         assert_eq!(format!("{:?}", c64!("As 9h")), "Card64(A, 9, _, _)");
+
+        assert_eq!(Card64::default().to_string(), "");
+        assert_eq!(c64!("As").to_string(), "As");
+        assert_eq!(c64!("As 9h").to_string().len(), 4);
     }
 
     #[quickcheck]
@@ -23,6 +23,8 @@ pub enum ParseError {
     InvalidHand(String),
     /// Input is not a valid player.
     InvalidPlayer(String),
+    /// Input is not a valid draw type.
+    InvalidDrawType(String),
 }
 
 impl Error for ParseError {}
@@ -0,0 +1,107 @@
+use super::{has_cards_to_come, set_flush_draws, set_straight_draws, straight_outs};
+use crate::{Card64, DrawSet, Suit};
+
+/// Returns the draws of a Hold'em (`SD = false`) or Short Deck (`SD = true`)
+/// hand against a flop or turn `board`; other streets have none. Every draw
+/// uses at least one hole card.
+pub fn eval_draws_holdem<const SD: bool>(player: Card64, board: Card64) -> DrawSet {
+    let mut draws = DrawSet::default();
+
+    if !has_cards_to_come(board) {
+        return draws;
+    }
+
+    for suit in Suit::ARR_ALL {
+        let n_hole = player.count_by_suit(suit) as usize;
+
+        if n_hole > 0 {
+            let n_suited = n_hole + board.count_by_suit(suit) as usize;
+            set_flush_draws::<SD>(&mut draws, player, board, suit, n_suited);
+        }
+    }
+
+    let hole = player.ranks();
+    let outs = straight_outs::<SD>(board.ranks(), |straight, board| {
+        let missing = straight & !board;
+
+        !missing.is_empty() && missing & hole == missing
+    });
+    set_straight_draws(&mut draws, outs);
+
+    draws
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::{DrawType::*, *};
+
+    fn draws(s: &str) -> DrawSet {
+        let mut s = s.split('|');
+        let player = c64!(s.next().unwrap());
+        let board = c64!(s.next().unwrap());
+
+        eval_draws_holdem::<false>(player, board)
+    }
+
+    fn best(s: &str) -> DrawType {
+        draws(s).best()
+    }
+
+    #[test]
+    fn test_flush_draws() {
+        assert_eq!(best("Ah 2h | Kh 7h 3c"), NutFlushDraw);
+        assert_eq!(best("Qh 2h | Kh 7h 3c"), FlushDraw);
+        assert_eq!(best("Ah 2c | Kh 7h 3h"), NutFlushDraw);
+        assert_eq!(best("Qh 2h | Kh 7c 3c"), BackdoorFlushDraw);
+        assert_eq!(
+            best("Qh 2h | Kh 7c 3c 9d"),
+            NoDraw,
+            "no backdoor on the turn"
+        );
+        assert_eq!(best("Qc 2d | Kh 7h 3h 9h"), NoDraw, "board four flush");
+        assert_eq!(best("Qh 2h | Kh 7h 3h"), NoDraw, "made flush");
+
+        assert!(draws("Ah 2h | Kh 7h 3c").contains(FlushDraw));
+    }
+
+    #[test]
+    fn test_straight_draws() {
+        assert_eq!(best("9c 8d | 7h 6s 2c"), OpenEnded);
+        assert_eq!(best("9c 8d | Th 6s 2c"), Gutshot);
+        assert_eq!(best("Ac 2d | 3h 4s Kc"), Gutshot, "wheel draw");
+        assert_eq!(best("9c 5d | 7h 6s 3c"), OpenEnded, "double gutshot");
+        assert_eq!(best("Ac Kd | 7h 6s 2c"), NoDraw);
+        assert_eq!(best("9c 8d | 7h 6s 5c"), NoDraw, "made straight");
+        assert_eq!(best("2c 2d | 9h 8s 7c 6d"), NoDraw, "board draw");
+    }
+
+    #[test]
+    fn test_combo_draws() {
+        let set = draws("9h 8h | 7h 6s 2h");
+
+        assert_eq!(set.best(), ComboDraw);
+        assert!(set.contains(FlushDraw));
+        assert!(set.contains(OpenEnded));
+        assert!(!set.contains(NutFlushDraw));
+    }
+
+    #[test]
+    fn test_streets() {
+        assert_eq!(best("9h 8h | "), NoDraw);
+        assert_eq!(best("9h 8h | 7h 6h 2c Kd As"), NoDraw);
+    }
+
+    #[test]
+    fn test_shortdeck() {
+        let player = c64!("Ac 9d");
+        let board = c64!("7h 6s Kc");
+
+        assert_eq!(
+            eval_draws_holdem::<true>(player, board).best(),
+            Gutshot,
+            "A-6-7-8-9 needs an eight"
+        );
+    }
+}
@@ -0,0 +1,87 @@
+mod holdem;
+mod omaha;
+
+pub use holdem::*;
+pub use omaha::*;
+
+use crate::{Board, Card64, DrawSet, DrawType, Rank, Rank16, Suit};
+
+/// Draws need a flop or a turn: a card still to come and a board to draw on.
+const fn has_cards_to_come(board: Card64) -> bool {
+    let n = board.count() as usize;
+
+    n >= Board::N_FLOP && n < Board::N_RIVER
+}
+
+/// Sets the flush draws of `suit` when the player can use `n_suited`
+/// cards of it.
+fn set_flush_draws<const SD: bool>(
+    draws: &mut DrawSet,
+    player: Card64,
+    board: Card64,
+    suit: Suit,
+    n_suited: usize,
+) {
+    match n_suited {
+        4 => {
+            draws.set(DrawType::FlushDraw);
+
+            if is_nut_flush_draw::<SD>(player, board, suit) {
+                draws.set(DrawType::NutFlushDraw);
+            }
+        }
+        3 if board.count() as usize == Board::N_FLOP => draws.set(DrawType::BackdoorFlushDraw),
+        _ => (),
+    }
+}
+
+/// `true` when the player holds the highest `suit` rank missing from the board.
+fn is_nut_flush_draw<const SD: bool>(player: Card64, board: Card64, suit: Suit) -> bool {
+    let unseen = Rank16::all::<SD>() & !board.ranks_by_suit(suit);
+
+    unseen
+        .max_rank()
+        .is_some_and(|rank| player.ranks_by_suit(suit).contains_rank(rank))
+}
+
+/// Ranks missing from the board that complete a straight; empty when a
+/// straight is already made. `plays(straight, board)` tells whether the
+/// hole cards make `straight` with `board`.
+fn straight_outs<const SD: bool>(board: Rank16, plays: impl Fn(Rank16, Rank16) -> bool) -> Rank16 {
+    let makes = |board: Rank16| {
+        Rank16::all_straights::<SD>()
+            .iter()
+            .any(|&straight| plays(straight, board))
+    };
+
+    let mut outs = Rank16::default();
+
+    if makes(board) {
+        return outs;
+    }
+
+    for &rank in Rank::all::<SD>() {
+        let mut next = board;
+        next.set(rank);
+
+        if next != board && makes(next) {
+            outs.set(rank);
+        }
+    }
+
+    outs
+}
+
+/// Sets the straight draws from their outs, and the combo draw when a
+/// flush draw is also held.
+const fn set_straight_draws(draws: &mut DrawSet, outs: Rank16) {
+    match outs.count() {
+        0 => return,
+        1 => draws.set(DrawType::Gutshot),
+        _ => draws.set(DrawType::OpenEnded),
+    }
+
+    if draws.contains(DrawType::FlushDraw) {
+        draws.set(DrawType::ComboDraw);
+    }
+}
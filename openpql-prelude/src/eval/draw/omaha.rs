@@ -0,0 +1,68 @@
+use super::{has_cards_to_come, set_flush_draws, set_straight_draws, straight_outs};
+use crate::{Card64, DrawSet, Suit};
+
+/// Returns the draws of an Omaha hand of any size against a flop or turn
+/// `board`; other streets have none. Every draw uses exactly two hole
+/// cards.
+pub fn eval_draws_omaha(player: Card64, board: Card64) -> DrawSet {
+    let mut draws = DrawSet::default();
+
+    if !has_cards_to_come(board) {
+        return draws;
+    }
+
+    for suit in Suit::ARR_ALL {
+        if player.count_by_suit(suit) >= 2 {
+            let n_suited = 2 + board.count_by_suit(suit) as usize;
+            set_flush_draws::<false>(&mut draws, player, board, suit, n_suited);
+        }
+    }
+
+    let hole = player.ranks();
+    let outs = straight_outs::<false>(board.ranks(), |straight, board| {
+        let missing = straight & !board;
+
+        missing & hole == missing && missing.count() <= 2 && (straight & hole).count() >= 2
+    });
+    set_straight_draws(&mut draws, outs);
+
+    draws
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::{DrawType::*, *};
+
+    fn best(s: &str) -> DrawType {
+        let mut s = s.split('|');
+        let player = c64!(s.next().unwrap());
+        let board = c64!(s.next().unwrap());
+
+        eval_draws_omaha(player, board).best()
+    }
+
+    #[test]
+    fn test_flush_draws() {
+        assert_eq!(best("Ah 2h Kc Qd | Kh 7h 3c"), NutFlushDraw);
+        assert_eq!(best("Qh 2h Kc Jd | Kh 7h 3c"), FlushDraw);
+        assert_eq!(best("Ah 2c Kc Qd | Kh 7h 3h"), NoDraw, "needs two hearts");
+        assert_eq!(best("Qh 2h Kc Jd | Kh 7c 3c"), BackdoorFlushDraw);
+    }
+
+    #[test]
+    fn test_straight_draws() {
+        assert_eq!(best("9c 8d 2s 2h | 7h 6s Kc"), OpenEnded);
+        assert_eq!(best("9c 8d 2s 2h | Th 6s Kc"), Gutshot);
+        assert_eq!(best("9c 2d 2s 3h | 8h 7s 6c"), NoDraw, "one card plays");
+        assert_eq!(best("Jc Td 8s 7h | 9h 6s 2c"), OpenEnded, "wrap");
+        assert_eq!(best("9c 8d 2s 2h | 7h 6s 5c"), NoDraw, "made straight");
+    }
+
+    #[test]
+    fn test_combo_draws() {
+        assert_eq!(best("9h 8h 2s 2c | 7h 6s 2h"), ComboDraw);
+        assert_eq!(best("Ah Kh 9c 8d | 7h 6s 2h"), ComboDraw, "different pairs");
+    }
+}
@@ -1,3 +1,4 @@
+pub mod draw;
 pub mod flop;
 pub mod index;
 pub mod rating;
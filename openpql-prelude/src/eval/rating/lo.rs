@@ -0,0 +1,137 @@
+use crate::{
+    Card64, LoRating,
+    rating::{lo_mask, retain_lowest},
+};
+
+/// Returns the 8-or-better low rating of the best five of `cards`.
+#[inline]
+pub const fn eval_lo(cards: Card64) -> LoRating {
+    LoRating::from_lo_mask(lo_mask(cards.ranks()))
+}
+
+/// Returns the 8-or-better Omaha low rating of `player` against `board`.
+///
+/// Exactly 2 hole cards and 3 board cards are used, so this also covers
+/// 5-card Omaha.
+#[inline]
+pub const fn eval_omaha_lo(player: Card64, board: Card64) -> LoRating {
+    const N_BOARD: u32 = 3;
+
+    let p = lo_mask(player.ranks());
+    let b = lo_mask(board.ranks());
+
+    if p.count_ones() < 2 || b.count_ones() < N_BOARD {
+        return LoRating::NONE;
+    }
+
+    let mut best = LoRating::NONE;
+    let mut rest1 = p;
+
+    while rest1 != 0 {
+        let c1 = rest1 & rest1.wrapping_neg();
+        rest1 ^= c1;
+
+        let mut rest2 = rest1;
+
+        while rest2 != 0 {
+            let c2 = rest2 & rest2.wrapping_neg();
+            rest2 ^= c2;
+
+            let hole = c1 | c2;
+            let avail = b & !hole;
+
+            if avail.count_ones() >= N_BOARD {
+                let mask = hole | retain_lowest(avail, N_BOARD);
+
+                if mask < best.0 {
+                    best = LoRating(mask);
+                }
+            }
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn lo(s: &str) -> LoRating {
+        eval_lo(c64!(s))
+    }
+
+    fn omaha_lo(player: &str, board: &str) -> LoRating {
+        eval_omaha_lo(c64!(player), c64!(board))
+    }
+
+    #[test]
+    fn test_eval_lo() {
+        assert_eq!(lo("As 2h 3d 4c 5s"), LoRating::NUTS);
+        assert_eq!(lo("As 2h 3d 4c 5s 6s 7s"), LoRating::NUTS);
+        assert_eq!(lo("As Ah 3d 4c 5s 8s Ks").to_string(), "LO(A3458)");
+        assert_eq!(lo("As Ah 3d 4c 5s 9s Ks"), LoRating::NONE);
+        assert_eq!(lo("6s 7h 8d 9c Ts"), LoRating::NONE);
+    }
+
+    #[test]
+    fn test_eval_omaha_lo() {
+        assert_eq!(omaha_lo("As 2s Kd Kc", "3h 4h 5d Qs Jc"), LoRating::NUTS);
+        // must use two hole cards
+        assert_eq!(omaha_lo("As Kh Kd Kc", "2h 3h 4d 5s 8c"), LoRating::NONE);
+        // must use three board cards
+        assert_eq!(omaha_lo("As 2h 3d 4c", "5h 6h Kd Qs Jc"), LoRating::NONE);
+        // counterfeited ace falls back to the next low card
+        assert_eq!(
+            omaha_lo("As 3h Kd Kc", "Ah 2h 4d 8s Jc").to_string(),
+            "LO(A2348)"
+        );
+        assert_eq!(
+            omaha_lo("As 3h 5d Kc", "Ah 2h 4d 8s Jc").to_string(),
+            "LO(A2345)"
+        );
+        assert_eq!(omaha_lo("As Ah 2d 2c", "3h 4h 5d Qs Jc"), LoRating::NUTS);
+    }
+
+    #[quickcheck]
+    fn test_omaha_lo_brute_force(cs: CardN<9>) {
+        let player = Card64::from(&cs[..4]);
+        let board = Card64::from(&cs[4..]);
+
+        let expected = cs[..4]
+            .iter()
+            .combinations(2)
+            .cartesian_product(cs[4..].iter().combinations(3))
+            .map(|(p, b)| {
+                eval_lo(Card64::from(
+                    p.into_iter()
+                        .chain(b)
+                        .copied()
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                ))
+            })
+            .min()
+            .unwrap();
+
+        assert_eq!(eval_omaha_lo(player, board), expected);
+    }
+
+    #[quickcheck]
+    fn test_eval_lo_subset(cs: CardN<7>) {
+        let expected = cs
+            .iter()
+            .combinations(5)
+            .map(|c| {
+                eval_lo(Card64::from(
+                    c.into_iter().copied().collect::<Vec<_>>().as_slice(),
+                ))
+            })
+            .min()
+            .unwrap();
+
+        assert_eq!(eval_lo(Card64::from(cs.as_slice())), expected);
+    }
+}
@@ -1,17 +1,21 @@
 use std::str::FromStr;
 
 use crate::{
-    Board, Card64, CardCount, FlopHandCategory, HandRating, ParseError,
+    Board, Card64, CardCount, DrawSet, FlopHandCategory, HandRating, LoRating, ParseError,
     eval::{
+        draw::{eval_draws_holdem, eval_draws_omaha},
         flop::{eval_flop_holdem, eval_flop_omaha},
-        rating::{eval_holdem, eval_omaha, eval_omaha5, eval_shortdeck},
+        rating::{
+            eval_holdem, eval_lo, eval_omaha, eval_omaha_lo, eval_omaha5, eval_omaha6,
+            eval_shortdeck,
+        },
     },
 };
 
 /// Poker variant.
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))] // LCOV_EXCL_LINE
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Game {
     /// Texas Hold'em.
     #[default]
@@ -20,6 +24,12 @@ pub enum Game {
     Omaha,
     /// 5-Card Omaha (Big O / PLO5).
     Omaha5,
+    /// 6-Card Omaha (PLO6).
+    Omaha6,
+    /// Omaha Hi-Lo 8-or-better (O8).
+    Omaha8,
+    /// 5-Card Omaha Hi-Lo 8-or-better (Big O Hi-Lo).
+    Omaha5HiLo,
     /// Short Deck (6+) Hold'em.
     ShortDeck,
 }
@@ -30,8 +40,9 @@ impl Game {
     pub const fn player_cards_len(self) -> CardCount {
         match self {
             Self::Holdem | Self::ShortDeck => 2,
-            Self::Omaha => 4,
-            Self::Omaha5 => 5,
+            Self::Omaha | Self::Omaha8 => 4,
+            Self::Omaha5 | Self::Omaha5HiLo => 5,
+            Self::Omaha6 => 6,
         }
     }
 
@@ -41,14 +52,32 @@ impl Game {
         matches!(self, Self::ShortDeck)
     }
 
+    /// Returns `true` for split-pot variants with an 8-or-better low half.
+    #[must_use]
+    pub const fn is_hilo(self) -> bool {
+        matches!(self, Self::Omaha8 | Self::Omaha5HiLo)
+    }
+
     /// Returns the rating of `player` against `board` for this variant.
     #[must_use]
     pub fn eval_rating(self, player: Card64, board: Card64) -> HandRating {
         match self {
             Self::Holdem => eval_holdem(player | board),
             Self::ShortDeck => eval_shortdeck(player | board),
-            Self::Omaha => eval_omaha(player, board),
-            Self::Omaha5 => eval_omaha5(player, board),
+            Self::Omaha | Self::Omaha8 => eval_omaha(player, board),
+            Self::Omaha5 | Self::Omaha5HiLo => eval_omaha5(player, board),
+            Self::Omaha6 => eval_omaha6(player, board),
+        }
+    }
+
+    /// Returns the 8-or-better low rating of `player` against `board` for this variant.
+    #[must_use]
+    pub fn eval_lo_rating(self, player: Card64, board: Card64) -> LoRating {
+        match self {
+            Self::Holdem | Self::ShortDeck => eval_lo(player | board),
+            Self::Omaha | Self::Omaha5 | Self::Omaha6 | Self::Omaha8 | Self::Omaha5HiLo => {
+                eval_omaha_lo(player, board)
+            }
         }
     }
 
@@ -57,7 +86,21 @@ impl Game {
     pub fn eval_flop_category(self, player: Card64, board: Board) -> FlopHandCategory {
         match self {
             Self::Holdem | Self::ShortDeck => eval_flop_holdem(player, board),
-            Self::Omaha | Self::Omaha5 => eval_flop_omaha(player, board),
+            Self::Omaha | Self::Omaha5 | Self::Omaha6 | Self::Omaha8 | Self::Omaha5HiLo => {
+                eval_flop_omaha(player, board)
+            }
+        }
+    }
+
+    /// Returns the draws of `player` against a flop or turn `board` for this variant.
+    #[must_use]
+    pub fn eval_draws(self, player: Card64, board: Card64) -> DrawSet {
+        match self {
+            Self::Holdem => eval_draws_holdem::<false>(player, board),
+            Self::ShortDeck => eval_draws_holdem::<true>(player, board),
+            Self::Omaha | Self::Omaha5 | Self::Omaha6 | Self::Omaha8 | Self::Omaha5HiLo => {
+                eval_draws_omaha(player, board)
+            }
         }
     }
 }
@@ -70,6 +113,9 @@ impl FromStr for Game {
             "holdem" => Ok(Self::Holdem),
             "omaha" => Ok(Self::Omaha),
             "omaha5" => Ok(Self::Omaha5),
+            "omaha6" => Ok(Self::Omaha6),
+            "omaha8" => Ok(Self::Omaha8),
+            "omaha5hilo" => Ok(Self::Omaha5HiLo),
             "shortdeck" => Ok(Self::ShortDeck),
             _ => Err(ParseError::InvalidGame(s.into())),
         }
@@ -83,12 +129,26 @@ impl quickcheck::Arbitrary for Game {
         #[allow(unused)]
         const fn completeness_check(e: Game) {
             match e {
-                Game::Holdem | Game::Omaha | Game::Omaha5 | Game::ShortDeck => (),
+                Game::Holdem
+                | Game::Omaha
+                | Game::Omaha5
+                | Game::Omaha6
+                | Game::Omaha8
+                | Game::Omaha5HiLo
+                | Game::ShortDeck => (),
             }
         }
 
-        *g.choose(&[Self::Holdem, Self::Omaha, Self::Omaha5, Self::ShortDeck])
-            .unwrap()
+        *g.choose(&[
+            Self::Holdem,
+            Self::Omaha,
+            Self::Omaha5,
+            Self::Omaha6,
+            Self::Omaha8,
+            Self::Omaha5HiLo,
+            Self::ShortDeck,
+        ])
+        .unwrap()
     }
 }
 
@@ -103,6 +163,9 @@ mod tests {
         assert_eq!(2, Game::Holdem.player_cards_len());
         assert_eq!(4, Game::Omaha.player_cards_len());
         assert_eq!(5, Game::Omaha5.player_cards_len());
+        assert_eq!(6, Game::Omaha6.player_cards_len());
+        assert_eq!(4, Game::Omaha8.player_cards_len());
+        assert_eq!(5, Game::Omaha5HiLo.player_cards_len());
         assert_eq!(2, Game::ShortDeck.player_cards_len());
     }
 
@@ -111,15 +174,30 @@ mod tests {
         assert!(!Game::Holdem.is_shortdeck());
         assert!(!Game::Omaha.is_shortdeck());
         assert!(!Game::Omaha5.is_shortdeck());
+        assert!(!Game::Omaha6.is_shortdeck());
         assert!(Game::ShortDeck.is_shortdeck());
     }
 
+    #[test]
+    fn test_is_hilo() {
+        assert!(!Game::Holdem.is_hilo());
+        assert!(!Game::Omaha.is_hilo());
+        assert!(!Game::Omaha5.is_hilo());
+        assert!(!Game::Omaha6.is_hilo());
+        assert!(Game::Omaha8.is_hilo());
+        assert!(Game::Omaha5HiLo.is_hilo());
+        assert!(!Game::ShortDeck.is_hilo());
+    }
+
     #[test]
     fn test_from_str() {
         assert_eq!(Ok(Game::Holdem), " HoldEM ".parse());
 
         assert_eq!(Ok(Game::Omaha), "omaha".parse());
         assert_eq!(Ok(Game::Omaha5), "omaha5".parse());
+        assert_eq!(Ok(Game::Omaha6), "omaha6".parse());
+        assert_eq!(Ok(Game::Omaha8), "Omaha8".parse());
+        assert_eq!(Ok(Game::Omaha5HiLo), "omaha5hilo".parse());
         assert_eq!(Ok(Game::ShortDeck), "shortdeck".parse());
 
         assert_eq!(
@@ -153,12 +231,52 @@ mod tests {
             Game::Omaha5.eval_rating(c64!("Ks Qh 8s 9h 2c"), c64!("7h 7c 7d As Ah")),
             mk_rating(HandType::Trips, "7", "KQ")
         );
+        assert_eq!(
+            Game::Omaha6.eval_rating(c64!("Ks Qh 8s 9h 2c 3d"), c64!("7h 7c 7d As Ah")),
+            mk_rating(HandType::Trips, "7", "KQ")
+        );
+        assert_eq!(
+            Game::Omaha8.eval_rating(c64!("Ks Qh 8s 9h"), c64!("7h 7c 7d As Ah")),
+            Game::Omaha.eval_rating(c64!("Ks Qh 8s 9h"), c64!("7h 7c 7d As Ah")),
+        );
+        assert_eq!(
+            Game::Omaha5HiLo.eval_rating(c64!("Ks Qh 8s 9h 2c"), c64!("7h 7c 7d As Ah")),
+            mk_rating(HandType::Trips, "7", "KQ")
+        );
         assert_eq!(
             Game::ShortDeck.eval_rating(c64!("Kh As Ah Ac Ad 6d 6c"), Card64::default()),
             mk_rating(HandType::Quads, "A", "K")
         );
     }
 
+    #[test]
+    fn test_eval_lo_rating() {
+        assert_eq!(
+            Game::Holdem.eval_lo_rating(c64!("As Kh"), c64!("2h 3d 4c 5s Ks")),
+            LoRating::NUTS
+        );
+        assert_eq!(
+            Game::Omaha.eval_lo_rating(c64!("As Kh Kd Kc"), c64!("2h 3d 4c 5s 8s")),
+            LoRating::NONE
+        );
+        assert_eq!(
+            Game::Omaha5.eval_lo_rating(c64!("As 2h Kd Kc Qs"), c64!("3h 4d 5c Ts 9s")),
+            LoRating::NUTS
+        );
+        assert_eq!(
+            Game::Omaha8.eval_lo_rating(c64!("As 3h Kd Kc"), c64!("Ah 2h 4d 8s Jc")),
+            Game::Omaha.eval_lo_rating(c64!("As 3h Kd Kc"), c64!("Ah 2h 4d 8s Jc")),
+        );
+        assert_eq!(
+            Game::Omaha5HiLo.eval_lo_rating(c64!("As Kh Kd Kc Qs"), c64!("2h 3d 4c 5s 8s")),
+            LoRating::NONE
+        );
+        assert_eq!(
+            Game::ShortDeck.eval_lo_rating(c64!("As 6h"), c64!("7d 8c 9s Ts Js")),
+            LoRating::NONE
+        );
+    }
+
     #[test]
     fn test_eval_flop_cat() {
         assert_eq!(
@@ -211,5 +329,29 @@ mod tests_serde {
                 variant: "Omaha5",
             }],
         );
+
+        assert_tokens(
+            &Game::Omaha6,
+            &[Token::UnitVariant {
+                name: "Game",
+                variant: "Omaha6",
+            }],
+        );
+
+        assert_tokens(
+            &Game::Omaha8,
+            &[Token::UnitVariant {
+                name: "Game",
+                variant: "Omaha8",
+            }],
+        );
+
+        assert_tokens(
+            &Game::Omaha5HiLo,
+            &[Token::UnitVariant {
+                name: "Game",
+                variant: "Omaha5HiLo",
+            }],
+        );
     }
 }
@@ -2,8 +2,10 @@
 mod game;
 mod player;
 mod starting_hand;
+mod starting_hand_order;
 mod street;
 
 pub use game::Game;
 pub use player::{MAX_PLAYERS, PerPlayer, Player, PlayerIdx};
+pub use starting_hand_order::{HandScore, StartingHandOrder};
 pub use street::Street;
@@ -17,6 +17,7 @@ pub type PerPlayer<T> = SmallVec<[T; MAX_PLAYERS as usize]>;
     Copy,
     PartialEq,
     Eq,
+    Hash,
     Default,
     derive_more::From,
     derive_more::Into,
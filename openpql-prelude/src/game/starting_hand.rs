@@ -1,4 +1,4 @@
-use std::sync::LazyLock;
+use std::{slice::ChunksExact, sync::LazyLock};
 
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
@@ -10,46 +10,52 @@ const N_SD: usize = 2;
 const N_HOLDEM: usize = 2;
 const N_OMAHA: usize = 4;
 const N_OMAHA5: usize = 5;
+const N_OMAHA6: usize = 6;
 
-fn collect_hands<const SD: bool, const N: usize>() -> Vec<Vec<Card>> {
+/// Every starting hand of `N` cards, flattened into one `Vec` so the 20M
+/// Omaha6 hands take one allocation rather than one each.
+fn collect_hands<const SD: bool, const N: usize>() -> Vec<Card> {
     #[cfg(feature = "rayon")]
-    {
-        HandN::<N>::iter_all::<SD>()
-            .into_par_iter()
-            .map(|h| h.to_vec())
-            .collect()
-    }
+    let hands: Vec<[Card; N]> = HandN::<N>::iter_all::<SD>()
+        .into_par_iter()
+        .map(Into::into)
+        .collect();
     #[cfg(not(feature = "rayon"))]
-    {
-        HandN::<N>::iter_all::<SD>().map(|h| h.to_vec()).collect()
-    }
+    let hands: Vec<[Card; N]> = HandN::<N>::iter_all::<SD>().map(Into::into).collect();
+
+    hands.into_flattened()
 }
 
-static ALL_HANDS_SHORTDECK: LazyLock<Vec<Vec<Card>>> = LazyLock::new(collect_hands::<true, N_SD>);
+static ALL_HANDS_SHORTDECK: LazyLock<Vec<Card>> = LazyLock::new(collect_hands::<true, N_SD>);
 
-static ALL_HANDS_HOLDEM: LazyLock<Vec<Vec<Card>>> = LazyLock::new(collect_hands::<false, N_HOLDEM>);
+static ALL_HANDS_HOLDEM: LazyLock<Vec<Card>> = LazyLock::new(collect_hands::<false, N_HOLDEM>);
 
-static ALL_HANDS_OMAHA: LazyLock<Vec<Vec<Card>>> = LazyLock::new(collect_hands::<false, N_OMAHA>);
+static ALL_HANDS_OMAHA: LazyLock<Vec<Card>> = LazyLock::new(collect_hands::<false, N_OMAHA>);
 
-static ALL_HANDS_OMAHA5: LazyLock<Vec<Vec<Card>>> = LazyLock::new(collect_hands::<false, N_OMAHA5>);
+static ALL_HANDS_OMAHA5: LazyLock<Vec<Card>> = LazyLock::new(collect_hands::<false, N_OMAHA5>);
 
-fn all_hands(game: Game) -> &'static [Vec<Card>] {
-    match game {
+static ALL_HANDS_OMAHA6: LazyLock<Vec<Card>> = LazyLock::new(collect_hands::<false, N_OMAHA6>);
+
+fn all_hands(game: Game) -> ChunksExact<'static, Card> {
+    let hands: &[Card] = match game {
         Game::Holdem => &ALL_HANDS_HOLDEM,
-        Game::Omaha => &ALL_HANDS_OMAHA,
-        Game::Omaha5 => &ALL_HANDS_OMAHA5,
+        Game::Omaha | Game::Omaha8 => &ALL_HANDS_OMAHA,
+        Game::Omaha5 | Game::Omaha5HiLo => &ALL_HANDS_OMAHA5,
+        Game::Omaha6 => &ALL_HANDS_OMAHA6,
         Game::ShortDeck => &ALL_HANDS_SHORTDECK,
-    }
+    };
+
+    hands.chunks_exact(game.player_cards_len() as usize)
 }
 
 fn iso_hands<const N: usize>(
-    hands: &[Vec<Card>],
+    hands: &[Card],
     to_iso: impl Fn(&[Card]) -> IsomorphicHandN<N> + Sync,
 ) -> Vec<Vec<IsomorphicCard>> {
     #[cfg(feature = "rayon")]
-    let isos: Vec<IsomorphicHandN<N>> = hands.par_iter().map(|h| to_iso(h)).collect();
+    let isos: Vec<IsomorphicHandN<N>> = hands.par_chunks_exact(N).map(&to_iso).collect();
     #[cfg(not(feature = "rayon"))]
-    let isos: Vec<IsomorphicHandN<N>> = hands.iter().map(|h| to_iso(h)).collect();
+    let isos: Vec<IsomorphicHandN<N>> = hands.chunks_exact(N).map(&to_iso).collect();
 
     let mut seen = FxHashSet::default();
     isos.into_iter()
@@ -86,19 +92,27 @@ static ALL_HANDS_OMAHA5_ISO: LazyLock<Vec<Vec<IsomorphicCard>>> = LazyLock::new(
     )
 });
 
+static ALL_HANDS_OMAHA6_ISO: LazyLock<Vec<Vec<IsomorphicCard>>> = LazyLock::new(|| {
+    iso_hands::<N_OMAHA6>(
+        &ALL_HANDS_OMAHA6,
+        IsomorphicHandN::<N_OMAHA6>::from_slice_preflop,
+    )
+});
+
 fn all_iso_hands(game: Game) -> &'static [Vec<IsomorphicCard>] {
     match game {
         Game::Holdem => &ALL_HANDS_HOLDEM_ISO,
-        Game::Omaha => &ALL_HANDS_OMAHA_ISO,
-        Game::Omaha5 => &ALL_HANDS_OMAHA5_ISO,
+        Game::Omaha | Game::Omaha8 => &ALL_HANDS_OMAHA_ISO,
+        Game::Omaha5 | Game::Omaha5HiLo => &ALL_HANDS_OMAHA5_ISO,
+        Game::Omaha6 => &ALL_HANDS_OMAHA6_ISO,
         Game::ShortDeck => &ALL_HANDS_SHORTDECK_ISO,
     }
 }
 
 impl Game {
-    /// Returns every legal starting hand for this variant.
-    #[must_use]
-    pub fn starting_hands(self) -> &'static [Vec<Card>] {
+    /// Returns every legal starting hand for this variant, as slices of
+    /// [`Self::player_cards_len`] cards.
+    pub fn starting_hands(self) -> ChunksExact<'static, Card> {
         all_hands(self)
     }
 
@@ -119,6 +133,9 @@ mod tests {
         assert_eq!(Game::Holdem.starting_hands().len(), 1326);
         assert_eq!(Game::Omaha.starting_hands().len(), 270_725);
         assert_eq!(Game::Omaha5.starting_hands().len(), 2_598_960);
+        assert_eq!(Game::Omaha8.starting_hands().len(), 270_725);
+        assert_eq!(Game::Omaha5HiLo.starting_hands().len(), 2_598_960);
+        assert_eq!(Game::Omaha6.starting_hands().len(), 20_358_520);
     }
 
     #[test]
@@ -136,5 +153,6 @@ mod tests {
         assert_eq!(Game::Holdem.starting_iso_hands().len(), 169);
         assert_eq!(Game::Omaha.starting_iso_hands().len(), 16_718);
         assert_eq!(Game::Omaha5.starting_iso_hands().len(), 134_459);
+        assert_eq!(Game::Omaha8.starting_iso_hands().len(), 16_718);
     }
 }
@@ -0,0 +1,564 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    sync::{Arc, LazyLock},
+};
+
+use crate::{Card, Game, HandMatrix, HandN, IsomorphicHandN, Rank, Suit};
+
+/// Strength of a starting hand; higher is stronger.
+pub type HandScore = u32;
+
+type ScoreFn = Arc<dyn Fn(&[Card]) -> HandScore + Send + Sync>;
+
+const N_SD: usize = 2;
+const N_HOLDEM: usize = 2;
+const N_OMAHA: usize = 4;
+const N_OMAHA5: usize = 5;
+const N_OMAHA6: usize = 6;
+
+/// All-in equity against a random hand in hundredths of a percent,
+/// estimated from 8 million deals per hand (`tests::test_equity_tables`
+/// recomputes and prints them) and laid out like
+/// [`HandMatrix`]: pairs on the diagonal, suited hands above it in the row
+/// of their lower rank, offsuit hands below it in the row of their higher.
+#[rustfmt::skip]
+const HOLDEM_EQUITY: [[u16; 13]; 13] = [
+    [5036, 3599, 3684, 3785, 3768, 3815, 4023, 4242, 4485, 4738, 5018, 5322, 5738], // 2
+    [3230, 5368, 3863, 3968, 3954, 4001, 4087, 4327, 4568, 4821, 5102, 5406, 5822], // 3
+    [3320, 3513, 5702, 4148, 4133, 4185, 4270, 4386, 4651, 4905, 5189, 5488, 5904], // 4
+    [3427, 3628, 3814, 6034, 4311, 4367, 4458, 4572, 4717, 4998, 5272, 5580, 5993], // 5
+    [3407, 3609, 3801, 3992, 6328, 4538, 4628, 4741, 4895, 5059, 5361, 5666, 5990], // 6
+    [3459, 3662, 3855, 4053, 4230, 6624, 4792, 4912, 5066, 5232, 5429, 5755, 6098], // 7
+    [3683, 3748, 3942, 4143, 4322, 4505, 6917, 5081, 5234, 5400, 5599, 5833, 6194], // 8
+    [3910, 4000, 4065, 4265, 4450, 4629, 4809, 7205, 5402, 5566, 5768, 6001, 6279], // 9
+    [4166, 4257, 4350, 4425, 4611, 4789, 4973, 5153, 7499, 5756, 5947, 6179, 6460], // T
+    [4437, 4526, 4620, 4715, 4783, 4968, 5150, 5327, 5528, 7749, 6027, 6255, 6540], // J
+    [4727, 4823, 4910, 5012, 5103, 5178, 5362, 5539, 5729, 5814, 7995, 6344, 6617], // Q
+    [5052, 5144, 5233, 5335, 5423, 5517, 5600, 5783, 5974, 6057, 6144, 8238, 6703], // K
+    [5497, 5583, 5673, 5768, 5767, 5885, 5987, 6078, 6271, 6356, 6442, 6535, 8522], // A
+];
+
+/// [`HOLDEM_EQUITY`] for the 36-card deck, from the six up.
+#[rustfmt::skip]
+const SHORTDECK_EQUITY: [[u16; 9]; 9] = [
+    [3884, 3784, 4013, 4247, 4200, 4181, 4439, 4635, 5028], // 6
+    [3527, 4464, 4359, 4609, 4577, 4554, 4573, 4848, 5248], // 7
+    [3769, 4136, 5045, 4931, 4929, 4905, 4924, 4978, 5460], // 8
+    [4021, 4393, 4738, 5631, 5276, 5251, 5272, 5328, 5609], // 9
+    [3970, 4363, 4736, 5101, 6126, 5696, 5690, 5734, 5794], // T
+    [3942, 4337, 4705, 5070, 5538, 6382, 5753, 5793, 5933], // J
+    [4210, 4349, 4725, 5087, 5524, 5589, 6638, 5878, 6012], // Q
+    [4404, 4635, 4770, 5142, 5571, 5620, 5712, 6892, 6108], // K
+    [4812, 5051, 5274, 5436, 5621, 5775, 5849, 5945, 7308], // A
+];
+
+/// Starting hands of a game ranked by a score, for percentage ranges such
+/// as `top15%`.
+///
+/// Hands with the same score are ranked together, so the strongest share
+/// of the hands always covers whole score classes.
+#[derive(Clone)]
+pub struct StartingHandOrder {
+    score: ScoreFn,
+    /// scores in descending order, each with the number of hands scoring
+    /// higher
+    above: Vec<(HandScore, usize)>,
+    n_hands: usize,
+}
+
+impl fmt::Debug for StartingHandOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StartingHandOrder")
+            .field("n_scores", &self.above.len())
+            .field("n_hands", &self.n_hands)
+            .finish_non_exhaustive()
+    }
+}
+
+impl StartingHandOrder {
+    /// Ranks every starting hand of `game` by `score`.
+    pub fn new(game: Game, score: impl Fn(&[Card]) -> HandScore + Send + Sync + 'static) -> Self {
+        let counts = match game {
+            Game::Holdem => count_scores::<false, N_HOLDEM>(&score),
+            Game::ShortDeck => count_scores::<true, N_SD>(&score),
+            Game::Omaha | Game::Omaha8 => count_scores::<false, N_OMAHA>(&score),
+            Game::Omaha5 | Game::Omaha5HiLo => count_scores::<false, N_OMAHA5>(&score),
+            Game::Omaha6 => count_scores::<false, N_OMAHA6>(&score),
+        };
+
+        Self::from_counts(score, counts)
+    }
+
+    /// [`Self::new`] for an Omaha `score` that does not depend on how the
+    /// suits are named, which scores one hand per relabeling of the suits.
+    fn new_suit_symmetric<const N: usize>(
+        score: impl Fn(&[Card]) -> HandScore + Send + Sync + 'static,
+    ) -> Self {
+        let counts = count_scores_suit_symmetric::<N>(&score);
+
+        Self::from_counts(score, counts)
+    }
+
+    fn from_counts(
+        score: impl Fn(&[Card]) -> HandScore + Send + Sync + 'static,
+        counts: BTreeMap<HandScore, usize>,
+    ) -> Self {
+        let mut above = Vec::with_capacity(counts.len());
+        let mut n_hands = 0;
+        for (score, count) in counts.into_iter().rev() {
+            above.push((score, n_hands));
+            n_hands += count;
+        }
+
+        Self {
+            score: Arc::new(score),
+            above,
+            n_hands,
+        }
+    }
+
+    /// Default order of `game`: all-in equity against a random hand for
+    /// Hold'em and Short Deck; for Omaha, a heuristic point count that
+    /// favours high pairs, suited high cards and connected ranks, which is
+    /// not an equity.
+    pub fn of(game: Game) -> Arc<Self> {
+        let order: &LazyLock<Arc<Self>> = match game {
+            Game::Holdem => &HOLDEM_ORDER,
+            Game::ShortDeck => &SHORTDECK_ORDER,
+            Game::Omaha | Game::Omaha8 => &OMAHA_ORDER,
+            Game::Omaha5 | Game::Omaha5HiLo => &OMAHA5_ORDER,
+            Game::Omaha6 => &OMAHA6_ORDER,
+        };
+
+        Arc::clone(order)
+    }
+
+    /// Share of the starting hands that score higher than `cards`.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn share_above(&self, cards: &[Card]) -> f64 {
+        let score = (self.score)(cards);
+        let i = self.above.partition_point(|&(s, _)| s > score);
+        let n_above = self.above.get(i).map_or(self.n_hands, |&(_, n)| n);
+
+        n_above as f64 / self.n_hands as f64
+    }
+
+    /// Whether `cards` is among the strongest `share` of the starting
+    /// hands, rounding up to the whole score class that crosses `share`.
+    pub fn is_in_top(&self, cards: &[Card], share: f64) -> bool {
+        self.share_above(cards) < share
+    }
+}
+
+fn count_scores<const SD: bool, const N: usize>(
+    score: &dyn Fn(&[Card]) -> HandScore,
+) -> BTreeMap<HandScore, usize> {
+    let mut counts = BTreeMap::new();
+
+    for hand in HandN::<N>::iter_all::<SD>() {
+        *counts.entry(score(&hand[..])).or_default() += 1;
+    }
+
+    counts
+}
+
+/// [`count_scores`] of the full deck for a `score` that ignores suit names:
+/// each way to spread the ranks of a hand over the suits is scored once, up
+/// to the order of the suits, and counted once per relabeling of the suits.
+fn count_scores_suit_symmetric<const N: usize>(
+    score: &dyn Fn(&[Card]) -> HandScore,
+) -> BTreeMap<HandScore, usize> {
+    let ranks = Rank::all::<false>();
+    let mut by_len = vec![vec![]; N + 1];
+    for mask in 0..1_u16 << ranks.len() {
+        if let Some(masks) = by_len.get_mut(mask.count_ones() as usize) {
+            masks.push(mask);
+        }
+    }
+
+    let mut counts = BTreeMap::new();
+    let mut masks = [0; Suit::N_SUITS as usize];
+    spread_over_suits(score, &by_len, &mut masks, 0, N, &mut counts);
+
+    counts
+}
+
+/// Picks the rank masks of the suits from `suit` on, each at least the one
+/// before, with `n_left` cards in total.
+fn spread_over_suits(
+    score: &dyn Fn(&[Card]) -> HandScore,
+    by_len: &[Vec<u16>],
+    masks: &mut [u16; Suit::N_SUITS as usize],
+    suit: usize,
+    n_left: usize,
+    counts: &mut BTreeMap<HandScore, usize>,
+) {
+    if suit == masks.len() {
+        let cards: Vec<_> = Suit::ARR_ALL
+            .iter()
+            .zip(masks.iter())
+            .flat_map(|(&s, &mask)| {
+                (0..)
+                    .zip(Rank::all::<false>())
+                    .filter(move |(i, _)| mask & (1 << i) != 0)
+                    .map(move |(_, &r)| Card::new(r, s))
+            })
+            .collect();
+
+        *counts.entry(score(&cards)).or_default() += n_relabelings(masks);
+
+        return;
+    }
+
+    let min = suit.checked_sub(1).map_or(0, |prev| masks[prev]);
+    let lens = if suit + 1 == masks.len() {
+        n_left..=n_left
+    } else {
+        0..=n_left
+    };
+
+    for len in lens {
+        let from = by_len[len].partition_point(|&mask| mask < min);
+
+        for &mask in &by_len[len][from..] {
+            masks[suit] = mask;
+            spread_over_suits(score, by_len, masks, suit + 1, n_left - len, counts);
+        }
+    }
+}
+
+/// Distinct orders of the sorted `masks`: `4!` over the factorial of the
+/// size of each run of equal masks.
+fn n_relabelings(masks: &[u16]) -> usize {
+    let factorial = |n: usize| (1..=n).product::<usize>();
+
+    masks
+        .chunk_by(|a, b| a == b)
+        .fold(factorial(masks.len()), |n, run| n / factorial(run.len()))
+}
+
+fn equity_matrix<const SD: bool, const S: usize>(
+    table: &[[u16; S]; S],
+) -> HandMatrix<HandScore, SD> {
+    let mut matrix = HandMatrix::new(&|_| 0);
+
+    for ((_, score), &equity) in matrix.iter_mut().zip(table.iter().flatten()) {
+        *score = HandScore::from(equity);
+    }
+
+    matrix
+}
+
+static HOLDEM_ORDER: LazyLock<Arc<StartingHandOrder>> = LazyLock::new(|| {
+    let matrix = equity_matrix::<false, 13>(&HOLDEM_EQUITY);
+
+    Arc::new(StartingHandOrder::new(Game::Holdem, move |cs| {
+        matrix[IsomorphicHandN::<N_HOLDEM>::from_slice_preflop(cs)]
+    }))
+});
+
+static SHORTDECK_ORDER: LazyLock<Arc<StartingHandOrder>> = LazyLock::new(|| {
+    let matrix = equity_matrix::<true, 9>(&SHORTDECK_EQUITY);
+
+    Arc::new(StartingHandOrder::new(Game::ShortDeck, move |cs| {
+        matrix[IsomorphicHandN::<N_SD>::from_slice_preflop(cs)]
+    }))
+});
+
+static OMAHA_ORDER: LazyLock<Arc<StartingHandOrder>> = LazyLock::new(|| {
+    Arc::new(StartingHandOrder::new_suit_symmetric::<N_OMAHA>(
+        omaha_points,
+    ))
+});
+
+static OMAHA5_ORDER: LazyLock<Arc<StartingHandOrder>> = LazyLock::new(|| {
+    Arc::new(StartingHandOrder::new_suit_symmetric::<N_OMAHA5>(
+        omaha_points,
+    ))
+});
+
+static OMAHA6_ORDER: LazyLock<Arc<StartingHandOrder>> = LazyLock::new(|| {
+    Arc::new(StartingHandOrder::new_suit_symmetric::<N_OMAHA6>(
+        omaha_points,
+    ))
+});
+
+/// Point count of an Omaha hand, a hand-tuned heuristic that stands in for
+/// equity because it is cheap enough to rank millions of hands. It has not
+/// been fitted to simulated equities, so it only roughly orders hands:
+/// * a pair scores `2r + 4` for the `r`-th rank from the deuce, while
+///   three or four of a rank only score `r`;
+/// * a suit held two or three times scores `h / 2 + 2` for its highest
+///   rank `h`, less 2 for the third card;
+/// * two ranks at most four apart score `5 - gap`, plus one when both
+///   are ten or higher.
+fn omaha_points(cards: &[Card]) -> HandScore {
+    let mut by_rank = [0; Rank::N_RANKS as usize];
+    let mut by_suit = [(0, 0); Suit::N_SUITS as usize];
+
+    for card in cards {
+        let rank = card.rank as HandScore;
+        let (count, high) = &mut by_suit[card.suit as usize];
+
+        by_rank[card.rank as usize] += 1;
+        *count += 1;
+        *high = rank.max(*high);
+    }
+
+    let mut points = 0;
+    let mut ranks = [0; Rank::N_RANKS as usize];
+    let mut n_ranks = 0;
+
+    for (r, &count) in (0..).zip(&by_rank) {
+        match count {
+            0 => continue,
+            1 => (),
+            2 => points += 2 * r + 4,
+            _ => points += r,
+        }
+
+        ranks[n_ranks] = r;
+        n_ranks += 1;
+    }
+
+    for &(count, high) in &by_suit {
+        match count {
+            2 => points += high / 2 + 2,
+            3 => points += high / 2,
+            _ => (),
+        }
+    }
+
+    for (i, &lo) in ranks[..n_ranks].iter().enumerate() {
+        for &hi in &ranks[i + 1..n_ranks] {
+            let gap = hi - lo;
+
+            if gap <= 4 {
+                points += 5 - gap;
+                if lo >= 8 {
+                    points += 1;
+                }
+            }
+        }
+    }
+
+    points
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn top(game: Game, share: f64) -> Vec<Vec<Card>> {
+        let order = StartingHandOrder::of(game);
+
+        game.starting_hands()
+            .filter(|hand| order.is_in_top(hand, share))
+            .map(<[Card]>::to_vec)
+            .collect()
+    }
+
+    #[test]
+    fn test_holdem() {
+        let order = StartingHandOrder::of(Game::Holdem);
+
+        assert_eq!(
+            order.share_above(&cards!("AsAh")).to_bits(),
+            0.0f64.to_bits()
+        );
+        assert!(order.share_above(&cards!("KsKh")) > 0.0);
+        assert!(order.share_above(&cards!("7s2h")) > 0.9);
+        assert!(order.share_above(&cards!("3s2h")) > 0.99);
+
+        assert_eq!(top(Game::Holdem, 0.001).len(), 6);
+        assert_eq!(top(Game::Holdem, 1.0).len(), 1326);
+        assert!(top(Game::Holdem, 0.0).is_empty());
+
+        let top15 = top(Game::Holdem, 0.15);
+        assert!((199..=199 + 16).contains(&top15.len()), "{}", top15.len());
+        assert!(order.is_in_top(&cards!("AsKh"), 0.15));
+        assert!(!order.is_in_top(&cards!("7s2h"), 0.15));
+    }
+
+    #[test]
+    fn test_shortdeck() {
+        let order = StartingHandOrder::of(Game::ShortDeck);
+
+        assert_eq!(
+            order.share_above(&cards!("AsAh")).to_bits(),
+            0.0f64.to_bits()
+        );
+        assert!(order.share_above(&cards!("7s6h")) > 0.9);
+        assert_eq!(top(Game::ShortDeck, 1.0).len(), 630);
+    }
+
+    #[test]
+    fn test_omaha_points() {
+        let score = |s: &str| omaha_points(&cards!(s));
+
+        assert!(score("AsAhKsKh") > score("AsAhKdKc"));
+        assert!(score("AsAhKdKc") > score("AsAh7d2c"));
+        assert!(score("JsTh9s8h") > score("JsTh4s3h"));
+        assert!(score("AsAh7d2c") > score("AsAhAd2c"));
+        assert!(score("9s8h7d6c") > score("Ks8h4d2c"));
+    }
+
+    #[test]
+    fn test_omaha() {
+        let order = StartingHandOrder::of(Game::Omaha);
+
+        assert_eq!(
+            order.share_above(&cards!("AsAhKsKh")).to_bits(),
+            0.0f64.to_bits()
+        );
+        assert!(order.is_in_top(&cards!("AsAhKdKc"), 0.01));
+        assert!(!order.is_in_top(&cards!("7s5h3d2c"), 0.5));
+    }
+
+    #[test]
+    fn test_suit_symmetric_counts() {
+        assert_eq!(
+            count_scores_suit_symmetric::<N_OMAHA>(&omaha_points),
+            count_scores::<false, N_OMAHA>(&omaha_points)
+        );
+
+        let n_hands = |counts: BTreeMap<HandScore, usize>| counts.values().sum::<usize>();
+        assert_eq!(
+            n_hands(count_scores_suit_symmetric::<N_OMAHA5>(&|_| 0)),
+            2_598_960
+        );
+        assert_eq!(
+            n_hands(count_scores_suit_symmetric::<N_OMAHA6>(&|_| 0)),
+            20_358_520
+        );
+    }
+
+    /// All-in equity of `hand` against a random hand over `n_deals` seeded
+    /// deals.
+    #[cfg(feature = "rand")]
+    fn equity_vs_random<const SD: bool>(hand: &[Card], n_deals: u32) -> f64 {
+        use std::cmp::Ordering;
+
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let eval = if SD { eval_shortdeck } else { eval_holdem };
+        let hero = Card64::from(hand);
+        let mut rng = StdRng::seed_from_u64(hero.into());
+        let mut card_gen = CardGen::new::<SD>(hero);
+        let mut points = 0.0;
+
+        for _ in 0..n_deals {
+            card_gen.reset();
+            let mut deal =
+                |n| -> Card64 { (0..n).map(|_| card_gen.deal(&mut rng).unwrap()).collect() };
+            let villain = deal(2);
+            let board = deal(5);
+
+            points += match eval(hero | board).cmp(&eval(villain | board)) {
+                Ordering::Greater => 1.0,
+                Ordering::Equal => 0.5,
+                Ordering::Less => 0.0,
+            };
+        }
+
+        points / f64::from(n_deals)
+    }
+
+    /// Compares every cell of `table` to [`equity_vs_random`] of one of its
+    /// hands and returns the recomputed table.
+    #[cfg(feature = "rand")]
+    fn check_equity_table<const SD: bool, const S: usize>(
+        table: &[[u16; S]; S],
+        n_deals: u32,
+        tolerance: f64,
+        cells: &dyn Fn(IsomorphicHandN<N_HOLDEM>) -> bool,
+    ) -> Vec<u16> {
+        let matrix = equity_matrix::<SD, S>(table);
+        let mut hands = HandMatrix::<Option<HandN<2>>, SD>::new(&|_| None);
+        for hand in HandN::<2>::iter_all::<SD>() {
+            hands[IsomorphicHandN::<N_HOLDEM>::from_slice_preflop(&hand[..])].get_or_insert(hand);
+        }
+
+        let mut recomputed = vec![];
+        for (iso, hand) in hands.iter() {
+            if !cells(iso) {
+                continue;
+            }
+
+            let hand = hand.unwrap();
+            let equity = equity_vs_random::<SD>(&hand[..], n_deals);
+            let expected = f64::from(matrix[iso]) / 10_000.0;
+
+            assert!(
+                (equity - expected).abs() < tolerance,
+                "{iso}: {equity} != {expected}"
+            );
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            recomputed.push((equity * 10_000.0).round() as u16);
+        }
+
+        recomputed
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_equity_spot_checks() {
+        let spots = |hands: &[&str]| {
+            let isos: Vec<_> = hands
+                .iter()
+                .map(|&hand| IsomorphicHandN::<N_HOLDEM>::from_slice_preflop(&cards!(hand)))
+                .collect();
+
+            move |iso| isos.contains(&iso)
+        };
+
+        check_equity_table::<false, 13>(
+            &HOLDEM_EQUITY,
+            20_000,
+            0.015,
+            &spots(&[
+                "AsAh", "KsKh", "2s2h", "AsKs", "AsKh", "Ts9s", "7s2h", "3s2h",
+            ]),
+        );
+        check_equity_table::<true, 9>(
+            &SHORTDECK_EQUITY,
+            20_000,
+            0.015,
+            &spots(&["AsAh", "AsKs", "7s6h"]),
+        );
+    }
+
+    /// Recomputes both equity tables and prints them in the layout of the
+    /// constants; run with `--ignored --nocapture` after changing them.
+    #[cfg(feature = "rand")]
+    #[test]
+    #[ignore = "slow"]
+    fn test_equity_tables() {
+        fn print_table(name: &str, table: &[u16], side: usize) {
+            println!("{name}:");
+            for row in table.chunks(side) {
+                println!("    [{}],", row.iter().map(u16::to_string).join(", "));
+            }
+        }
+
+        let holdem = check_equity_table::<false, 13>(&HOLDEM_EQUITY, 400_000, 0.005, &|_| true);
+        print_table("HOLDEM_EQUITY", &holdem, 13);
+
+        let shortdeck = check_equity_table::<true, 9>(&SHORTDECK_EQUITY, 400_000, 0.005, &|_| true);
+        print_table("SHORTDECK_EQUITY", &shortdeck, 9);
+    }
+
+    #[test]
+    fn test_custom() {
+        // pocket pairs first, the rest alike
+        let order = StartingHandOrder::new(Game::Holdem, |cs| u32::from(cs[0].rank == cs[1].rank));
+
+        // 78 of the 1326 hands are pairs
+        assert!(order.is_in_top(&cards!("2s2h"), 0.01));
+        assert!(!order.is_in_top(&cards!("AsKh"), 0.05));
+        assert!(order.is_in_top(&cards!("AsKh"), 0.06));
+    }
+}
@@ -5,7 +5,9 @@ use crate::{Board, Card64, CardCount, ParseError};
 /// Betting street of a poker hand.
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))] // LCOV_EXCL_LINE
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq, Copy, PartialOrd, Ord, Default, derive_more::Display)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Copy, PartialOrd, Ord, Default, derive_more::Display,
+)]
 pub enum Street {
     /// Before the flop.
     #[default]
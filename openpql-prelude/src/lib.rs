@@ -22,16 +22,21 @@ pub use card::{
 pub use error::ParseError;
 pub use eval::{
     calculate_payoffs,
-    rating::{eval_holdem, eval_omaha, eval_omaha5, eval_shortdeck},
+    rating::{
+        eval_holdem, eval_lo, eval_omaha, eval_omaha_lo, eval_omaha5, eval_omaha6, eval_shortdeck,
+    },
 };
-pub use game::{Game, MAX_PLAYERS, PerPlayer, Player, PlayerIdx, Street};
-pub use rating::{FlopHandCategory, HandRating, HandType};
+pub use game::{
+    Game, HandScore, MAX_PLAYERS, PerPlayer, Player, PlayerIdx, StartingHandOrder, Street,
+};
+pub use rating::{DrawSet, DrawType, FlopHandCategory, HandRating, HandType, LoRating};
 #[cfg(feature = "rand")]
 pub use rng::CardGen;
 
 type RatingInner = u16;
 const N_STRAIGHT: usize = 10;
 const N_STRAIGHT_SD: usize = 6;
+const N_DRAW_TYPE: usize = 7;
 const N_FLOP_CATEGORY: usize = 18;
 const N_HANDTYPE: usize = 9;
 
@@ -0,0 +1,154 @@
+use std::str::FromStr;
+
+use crate::{N_DRAW_TYPE, ParseError};
+
+/// Drawing hand of a player on the flop or turn, weakest first.
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))] // LCOV_EXCL_LINE
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, derive_more::Display,
+)]
+pub enum DrawType {
+    /// No draw.
+    #[default]
+    #[display("NODRAW")]
+    NoDraw,
+    /// Three cards to a flush on the flop.
+    #[display("BACKDOORFLUSHDRAW")]
+    BackdoorFlushDraw,
+    /// Exactly one rank completes a straight.
+    #[display("GUTSHOT")]
+    Gutshot,
+    /// Two or more ranks complete a straight.
+    #[display("OPENENDED")]
+    OpenEnded,
+    /// Four cards to a flush.
+    #[display("FLUSHDRAW")]
+    FlushDraw,
+    /// Four cards to the highest flush still possible.
+    #[display("NUTFLUSHDRAW")]
+    NutFlushDraw,
+    /// Flush draw with a straight draw.
+    #[display("COMBODRAW")]
+    ComboDraw,
+}
+
+impl DrawType {
+    /// Every variant in ascending order.
+    pub const ARR_ALL: [Self; N_DRAW_TYPE] = [
+        Self::NoDraw,
+        Self::BackdoorFlushDraw,
+        Self::Gutshot,
+        Self::OpenEnded,
+        Self::FlushDraw,
+        Self::NutFlushDraw,
+        Self::ComboDraw,
+    ];
+
+    const fn to_bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl FromStr for DrawType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().trim() {
+            "nodraw" => Ok(Self::NoDraw),
+            "backdoorflushdraw" => Ok(Self::BackdoorFlushDraw),
+            "gutshot" => Ok(Self::Gutshot),
+            "openended" => Ok(Self::OpenEnded),
+            "flushdraw" => Ok(Self::FlushDraw),
+            "nutflushdraw" => Ok(Self::NutFlushDraw),
+            "combodraw" => Ok(Self::ComboDraw),
+            _ => Err(ParseError::InvalidDrawType(s.into())),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "quickcheck"))]
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl quickcheck::Arbitrary for DrawType {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        *g.choose(&Self::ARR_ALL).unwrap()
+    }
+}
+
+/// Draws held at once. A nut flush draw is also a flush draw, and a combo
+/// draw keeps both of its parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DrawSet(u8);
+
+impl DrawSet {
+    /// Returns `true` if `draw` is held; [`DrawType::NoDraw`] only matches
+    /// an empty set.
+    #[must_use]
+    pub const fn contains(self, draw: DrawType) -> bool {
+        match draw {
+            DrawType::NoDraw => self.0 == 0,
+            _ => self.0 & draw.to_bit() != 0,
+        }
+    }
+
+    /// Adds `draw`; adding [`DrawType::NoDraw`] has no effect.
+    pub const fn set(&mut self, draw: DrawType) {
+        if !matches!(draw, DrawType::NoDraw) {
+            self.0 |= draw.to_bit();
+        }
+    }
+
+    /// Strongest draw held.
+    #[must_use]
+    pub fn best(self) -> DrawType {
+        DrawType::ARR_ALL
+            .into_iter()
+            .rev()
+            .find(|&draw| self.contains(draw))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        for draw in DrawType::ARR_ALL {
+            assert_eq!(Ok(draw), draw.to_string().to_lowercase().parse());
+        }
+
+        assert_eq!(Ok(DrawType::Gutshot), " GutShot ".parse());
+        assert!("invalid".parse::<DrawType>().is_err());
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut sorted = DrawType::ARR_ALL.to_vec();
+        sorted.sort_unstable();
+
+        assert_eq!(sorted, DrawType::ARR_ALL);
+    }
+
+    #[quickcheck]
+    fn test_draw_set(draws: Vec<DrawType>) {
+        let mut set = DrawSet::default();
+
+        for &draw in &draws {
+            set.set(draw);
+        }
+
+        for draw in DrawType::ARR_ALL {
+            let held = if draw == DrawType::NoDraw {
+                draws.iter().all(|&d| d == DrawType::NoDraw)
+            } else {
+                draws.contains(&draw)
+            };
+
+            assert_eq!(set.contains(draw), held);
+        }
+
+        assert_eq!(set.best(), draws.iter().copied().max().unwrap_or_default());
+    }
+}
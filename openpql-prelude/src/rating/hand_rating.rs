@@ -42,7 +42,7 @@ use crate::{
 /// [15, 0]:   000rrrrr rrrrrrrr  // r: bitmask of 5 ranks
 /// ```
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))] // LCOV_EXCL_LINE
-#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, derive_more::Debug)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, derive_more::Debug)]
 #[debug("{}", self)]
 pub struct HandRating(pub(crate) RatingInner);
 
@@ -0,0 +1,165 @@
+use std::fmt;
+
+use crate::{Rank, Rank16};
+
+type LoRatingInner = u8;
+
+/// Bit-packed 8-or-better low rating comparable by integer order; lower is better.
+///
+/// The order is the reverse of [`HandRating`](crate::HandRating): `min` picks the
+/// better low and [`Self::NONE`] is greater than every made low.
+///
+/// # Memory Layout
+/// ```text
+/// u8
+///
+/// Qualified low:
+/// [7, 0]:   8765432A  // bitmask of 5 distinct low ranks
+///
+/// No low:
+/// [7, 0]:   11111111
+/// ```
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))] // LCOV_EXCL_LINE
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Debug)]
+#[debug("{}", self)]
+pub struct LoRating(pub(crate) LoRatingInner);
+
+const MASK_R2_R8: u16 = 0b0000_0000_0111_1111;
+const OFFSET_RA: usize = 12;
+const N_LO: u32 = 5;
+
+impl LoRating {
+    /// Rating of a hand without a qualifying low.
+    pub const NONE: Self = Self(LoRatingInner::MAX);
+
+    /// Best possible low: A-2-3-4-5.
+    pub const NUTS: Self = Self(0b0001_1111);
+
+    /// Returns `true` when the rating is a qualifying low.
+    #[must_use]
+    #[inline]
+    pub const fn is_made(self) -> bool {
+        self.0 != Self::NONE.0
+    }
+
+    /// Returns the five low ranks, or `None` without a qualifying low.
+    #[must_use]
+    #[inline]
+    pub const fn to_ranks(self) -> Option<Rank16> {
+        if self.is_made() {
+            Some(Rank16(
+                (self.0 >> 1) as u16 | ((self.0 & 1) as u16) << OFFSET_RA,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Builds a rating from the lowest five ranks of an 8-or-better bitmask.
+    #[inline]
+    pub(crate) const fn from_lo_mask(mask: LoRatingInner) -> Self {
+        if mask.count_ones() < N_LO {
+            Self::NONE
+        } else {
+            Self(retain_lowest(mask, N_LO))
+        }
+    }
+}
+
+/// Returns the 8-or-better bitmask (A at bit 0, 8 at bit 7) of `ranks`.
+#[allow(clippy::cast_possible_truncation)]
+#[inline]
+pub const fn lo_mask(ranks: Rank16) -> LoRatingInner {
+    (((ranks.0 & MASK_R2_R8) << 1) | ((ranks.0 >> OFFSET_RA) & 1)) as LoRatingInner
+}
+
+/// Keeps the `n` lowest set bits of `mask`.
+#[inline]
+pub const fn retain_lowest(mut mask: LoRatingInner, mut n: u32) -> LoRatingInner {
+    let mut res = 0;
+
+    while n > 0 && mask != 0 {
+        let lowest = mask & mask.wrapping_neg();
+        res |= lowest;
+        mask ^= lowest;
+        n -= 1;
+    }
+
+    res
+}
+
+impl fmt::Display for LoRating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const ORDER: [Rank; 8] = [
+            Rank::RA,
+            Rank::R2,
+            Rank::R3,
+            Rank::R4,
+            Rank::R5,
+            Rank::R6,
+            Rank::R7,
+            Rank::R8,
+        ];
+
+        match self.to_ranks() {
+            Some(ranks) => {
+                let s: String = ORDER
+                    .iter()
+                    .filter(|&&r| ranks.contains_rank(r))
+                    .map(|r| r.to_char())
+                    .collect();
+
+                write!(f, "LO({s})")
+            }
+            None => write!(f, "NO_LO"),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn mk(s: &str) -> LoRating {
+        LoRating::from_lo_mask(lo_mask(r16!(s)))
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(mk("A2345").to_string(), "LO(A2345)");
+        assert_eq!(mk("87654").to_string(), "LO(45678)");
+        assert_eq!(mk("A234").to_string(), "NO_LO");
+        assert_eq!(LoRating::NUTS.to_string(), "LO(A2345)");
+    }
+
+    #[test]
+    fn test_order() {
+        assert!(mk("A2345") < mk("A2346"));
+        assert!(mk("23456") < mk("A2357"));
+        assert!(mk("A2356") < mk("23456"));
+        assert!(mk("87654") < LoRating::NONE);
+        assert_eq!(mk("A2345"), LoRating::NUTS);
+    }
+
+    #[test]
+    fn test_lowest_five() {
+        assert_eq!(mk("A234578"), mk("A2345"));
+        assert_eq!(mk("A2345KQ"), mk("A2345"));
+        assert_eq!(mk("9TJQK"), LoRating::NONE);
+    }
+
+    #[quickcheck]
+    fn test_to_ranks(ranks: Distinct<5, Rank>) {
+        let r16 = Rank16::from(&ranks[..]);
+        let rating = LoRating::from_lo_mask(lo_mask(r16));
+
+        if (r16 & Rank16::ALL_LO) == r16 {
+            assert_eq!(rating.to_ranks(), Some(r16));
+        } else {
+            assert!(!rating.is_made());
+            assert_eq!(rating.to_ranks(), None);
+        }
+    }
+}
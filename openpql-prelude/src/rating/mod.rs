@@ -1,12 +1,16 @@
+mod draw_type;
 mod flop_hand_category;
 mod hand_rating;
 mod hand_rating_view;
 mod hand_type;
+mod lo_rating;
 
+pub use draw_type::{DrawSet, DrawType};
 pub use flop_hand_category::*;
 pub use hand_rating::HandRating;
 pub use hand_rating_view::*;
 pub use hand_type::HandType;
+pub use lo_rating::{LoRating, lo_mask, retain_lowest};
 
 #[cfg(test)]
 pub mod tests {
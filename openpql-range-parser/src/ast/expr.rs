@@ -1,4 +1,4 @@
-use super::{LocInfo, Term};
+use super::{LocInfo, Term, Weight};
 
 /// Parsed range expression tree.
 #[derive(PartialEq, Eq, Debug)]
@@ -9,6 +9,10 @@ pub enum Expr {
     And(Box<Self>, Box<Self>),
     /// Union `A,B`.
     Or(Box<Self>, Box<Self>),
+    /// Combos of `A@50` or `[0.5]A` dealt with the given frequency.
+    Weighted(Box<Self>, Weight),
+    /// Strongest share of the starting hands, `top15%` or `%15`.
+    Top(Weight, LocInfo),
     /// Leaf term with its source span.
     Term(Term, LocInfo),
 }
@@ -85,6 +89,70 @@ mod tests {
         assert_expr("AsA!ss", not(term_loc("AsA", 0, 3), term_loc("ss", 4, 6)));
     }
 
+    fn weighted<S>(e: S, w: &str) -> Expr
+    where
+        Expr: From<S>,
+    {
+        Expr::Weighted(
+            Box::new(Expr::from(e)),
+            Weight::from_fraction(w, (0, 0)).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_expr_weighted() {
+        assert_expr(
+            "AA@50,KK",
+            or(weighted(term_loc("AA", 0, 2), "0.5"), term_loc("KK", 6, 8)),
+        );
+        assert_expr(
+            "[0.25]AKs, QQ@100",
+            or(
+                weighted(term_loc("AKs", 6, 9), "0.25"),
+                weighted(term_loc("QQ", 11, 13), "1"),
+            ),
+        );
+        assert_expr(
+            "(AA,KK)@12.5",
+            weighted(or(term_loc("AA", 1, 3), term_loc("KK", 4, 6)), "0.125"),
+        );
+        assert_expr("[1]AA", weighted(term_loc("AA", 3, 5), "1"));
+        assert_expr("[5]A", term_loc("[5]A", 0, 4).into());
+    }
+
+    #[test]
+    fn test_expr_weighted_err() {
+        assert_eq!(
+            parse_expr(false, "AA@101"),
+            Err(Error::InvalidWeight((2, 6)))
+        );
+        assert_eq!(
+            parse_expr(false, "KK,[1.5]AA"),
+            Err(Error::InvalidWeight((3, 8)))
+        );
+    }
+
+    fn top(share: &str, a: Loc, b: Loc) -> Expr {
+        Expr::Top(Weight::from_fraction(share, (0, 0)).unwrap(), (a, b))
+    }
+
+    #[test]
+    fn test_expr_top() {
+        assert_expr("top15%", top("0.15", 0, 6));
+        assert_expr("%7.5", top("0.075", 0, 4));
+        assert_expr("TOP100%", top("1", 0, 7));
+        assert_expr("top20%!AA", not(top("0.2", 0, 6), term_loc("AA", 7, 9)));
+        assert_expr(
+            "(top10%:AK)@50",
+            weighted(and(top("0.1", 1, 7), term_loc("AK", 8, 10)), "0.5"),
+        );
+
+        assert_eq!(
+            parse_expr(false, "AA,top120%"),
+            Err(Error::InvalidWeight((3, 10)))
+        );
+    }
+
     #[test]
     fn test_expr_precedence() {
         assert_expr(
@@ -5,6 +5,7 @@ mod rank;
 mod span;
 mod suit;
 mod term;
+mod weight;
 
 pub use card::RangeCard;
 pub use expr::Expr;
@@ -13,6 +14,7 @@ pub use rank::{CardRank, RankConst, RankVar};
 pub use span::{Span, SpanElem};
 pub use suit::{CardSuit, SuitConst, SuitVar};
 pub use term::{Term, TermElem};
+pub use weight::Weight;
 
 use super::{Error, From, LalrError, Loc, LocInfo, ResultE, ToString};
 
@@ -0,0 +1,91 @@
+use std::fmt;
+
+use super::{Error, LocInfo};
+
+type Millionths = u32;
+
+/// Frequency of the combos of a weighted range, between 0 and 1, kept in
+/// millionths so that ranges stay comparable.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default)]
+pub struct Weight(Millionths);
+
+impl Weight {
+    const SCALE: f64 = 1_000_000.0;
+
+    /// Weight of combos that are fully in the range.
+    pub const FULL: Self = Self(1_000_000);
+    /// Weight of combos that are not in the range.
+    pub const ZERO: Self = Self(0);
+
+    /// Parses the percentage of `AA@50`.
+    pub(crate) fn from_percent(src: &str, loc: LocInfo) -> Result<Self, Error> {
+        Self::from_f64(src.parse::<f64>().map_or(f64::NAN, |v| v / 100.0), loc)
+    }
+
+    /// Parses the fraction of `[0.5]AA`.
+    pub(crate) fn from_fraction(src: &str, loc: LocInfo) -> Result<Self, Error> {
+        Self::from_f64(src.parse::<f64>().unwrap_or(f64::NAN), loc)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    fn from_f64(v: f64, loc: LocInfo) -> Result<Self, Error> {
+        if (0.0..=1.0).contains(&v) {
+            Ok(Self((v * Self::SCALE).round() as Millionths))
+        } else {
+            Err(Error::InvalidWeight(loc))
+        }
+    }
+
+    /// Weight as a number between 0 and 1.
+    pub fn to_f64(self) -> f64 {
+        f64::from(self.0) / Self::SCALE
+    }
+
+    /// Weight of a combo in a range of weight `other` inside this one.
+    #[must_use]
+    pub const fn scale(self, other: Self) -> Self {
+        #[allow(clippy::cast_possible_truncation)]
+        Self((self.0 as u64 * other.0 as u64 / Self::FULL.0 as u64) as Millionths)
+    }
+}
+
+/// Decimal fraction, `0.5`.
+impl fmt::Display for Weight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_f64())
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weight() {
+        assert_eq!(Weight::from_percent("50", (0, 3)), Ok(Weight(500_000)));
+        assert_eq!(Weight::from_percent("33.3", (0, 5)), Ok(Weight(333_000)));
+        assert_eq!(Weight::from_fraction("0.25", (0, 6)), Ok(Weight(250_000)));
+        assert_eq!(Weight::from_fraction("1", (0, 3)), Ok(Weight::FULL));
+
+        assert_eq!(
+            Weight::from_percent("101", (2, 6)),
+            Err(Error::InvalidWeight((2, 6)))
+        );
+        assert_eq!(
+            Weight::from_fraction("1.5", (0, 5)),
+            Err(Error::InvalidWeight((0, 5)))
+        );
+    }
+
+    #[test]
+    fn test_scale() {
+        let half = Weight::from_fraction("0.5", (0, 0)).unwrap();
+
+        assert_eq!(half.scale(half), Weight(250_000));
+        assert_eq!(half.scale(Weight::FULL), half);
+        assert_eq!(half.scale(Weight::ZERO), Weight::ZERO);
+        assert!((half.to_f64() - 0.5).abs() < f64::EPSILON);
+    }
+}
@@ -1,4 +1,6 @@
-use super::{Array, Card, Error, Expr, Idx, parse_expr};
+use super::{
+    Arc, Array, Card, Error, Expr, Game, Idx, LocInfo, StartingHandOrder, Weight, parse_expr,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Checker<const N: usize = 2, const B: bool = false, const SD: bool = false>
@@ -12,9 +14,36 @@ impl<const N: usize, const B: bool, const SD: bool> Checker<N, B, SD>
 where
     [Idx; N]: Array<Item = Idx>,
 {
+    /// Parses `src`, ranking the starting hands of `top15%` by the default
+    /// [`StartingHandOrder`] of the game with `N` hole cards.
     pub fn from_src(src: &str) -> Result<Self, Error> {
+        Self::parse(src, &|loc| match (B, N, SD) {
+            (false, 2, true) => Ok(StartingHandOrder::of(Game::ShortDeck)),
+            (false, 2, false) => Ok(StartingHandOrder::of(Game::Holdem)),
+            (false, 4, _) => Ok(StartingHandOrder::of(Game::Omaha)),
+            (false, 5, _) => Ok(StartingHandOrder::of(Game::Omaha5)),
+            (false, 6, _) => Ok(StartingHandOrder::of(Game::Omaha6)),
+            _ => Err(Error::InvalidTopRange(loc)),
+        })
+    }
+
+    /// Parses `src`, ranking the starting hands of `top15%` by `order`.
+    pub fn from_src_with_order(src: &str, order: &Arc<StartingHandOrder>) -> Result<Self, Error> {
+        Self::parse(src, &|loc| {
+            if B {
+                Err(Error::InvalidTopRange(loc))
+            } else {
+                Ok(Arc::clone(order))
+            }
+        })
+    }
+
+    fn parse(
+        src: &str,
+        order: &dyn Fn(LocInfo) -> Result<Arc<StartingHandOrder>, Error>,
+    ) -> Result<Self, Error> {
         Ok(Self {
-            expr: parse_expr(SD, src).and_then(|expr| Expr::try_from(*expr))?,
+            expr: parse_expr(SD, src).and_then(|expr| Expr::from_ast(*expr, order))?,
         })
     }
 
@@ -22,6 +51,21 @@ where
     pub fn is_satisfied(&self, cs: &[Card]) -> bool {
         self.expr.is_satisfied(cs)
     }
+
+    /// Frequency between 0 and 1 at which the combo `cs` is in the range;
+    /// 1 for every combo of an unweighted range.
+    pub fn weight(&self, cs: &[Card]) -> f64 {
+        self.expr.weight(cs).to_f64()
+    }
+
+    pub(crate) fn combo_weight(&self, cs: &[Card]) -> Weight {
+        self.expr.weight(cs)
+    }
+
+    /// Whether any part of the range has a weight such as `AA@50`.
+    pub fn is_weighted(&self) -> bool {
+        self.expr.is_weighted()
+    }
 }
 
 impl<const N: usize, const B: bool, const SD: bool> Default for Checker<N, B, SD>
@@ -166,6 +210,17 @@ mod tests {
         assert_checker::<4, false>("[s][h][d][c]", &["2s 2h 3d 3c"], &["2s 2h 3c 3c"]);
     }
 
+    #[test]
+    fn test_omaha6() {
+        assert_checker::<6, false>(
+            "AAKK",
+            &["As Ah Ks Kh 2c 3d", "2c As 3d Ks Ah Kh"],
+            &["As Ah Ks Qh 2c 3d"],
+        );
+        assert_checker::<6, false>("[s][s][h][h][d][d]", &["As Ks Ah Kh Ad Kd"], &[]);
+        assert_eq!(e::<6>("AAKKQQJ"), Error::TooManyCardsInRange((0, 7)));
+    }
+
     #[test]
     fn test_board() {
         assert_checker::<5, true>(
@@ -209,6 +264,123 @@ mod tests {
         assert_checker::<2, false>("A:K", &["As Kh"], &[]);
     }
 
+    fn assert_weight(s: &str, hand: &str, expected: f64) {
+        let checker = Checker::<2, false, false>::from_src(s).unwrap();
+        let weight = checker.weight(&cards![hand]);
+
+        assert!(
+            (weight - expected).abs() < 1e-9,
+            "weight of {hand} in {s}: {weight} != {expected}"
+        );
+    }
+
+    #[test]
+    fn test_weight() {
+        assert_weight("AA@50,KK", "As Ah", 0.5);
+        assert_weight("AA@50,KK", "Ks Kh", 1.0);
+        assert_weight("AA@50,KK", "Qs Qh", 0.0);
+        assert_weight("[0.35]AKs-", "As Ks", 0.35);
+        assert_weight("[0.35]AKs-", "As Kh", 0.0);
+
+        // largest of a union, smallest of an intersection, nested products
+        assert_weight("AA@25,AsAh@75", "As Ah", 0.75);
+        assert_weight("AA@25:AsAh@75", "As Ah", 0.25);
+        assert_weight("(AA@50,KK)@50", "As Ah", 0.25);
+        assert_weight("(AA,KK)@50!AsAh", "As Ah", 0.0);
+
+        assert!(
+            !Checker::<2, false, false>::from_src("AA@0")
+                .unwrap()
+                .is_satisfied(&cards!["As Ah"])
+        );
+        assert!(
+            Checker::<2, false, false>::from_src("AA@0,AA")
+                .unwrap()
+                .is_satisfied(&cards!["As Ah"])
+        );
+
+        // 0.0001 of 0.0001 is below a millionth
+        assert_weight("(AA@0.01)@0.01", "As Ah", 0.0);
+        assert!(
+            !Checker::<2, false, false>::from_src("(AA@0.01)@0.01")
+                .unwrap()
+                .is_satisfied(&cards!["As Ah"])
+        );
+    }
+
+    #[test]
+    fn test_is_weighted() {
+        assert!(
+            Checker::<2, false, false>::from_src("KK,AA@50")
+                .unwrap()
+                .is_weighted()
+        );
+        assert!(
+            Checker::<2, false, false>::from_src("A!([0.5]K)")
+                .unwrap()
+                .is_weighted()
+        );
+        assert!(
+            !Checker::<2, false, false>::from_src("KK,AA")
+                .unwrap()
+                .is_weighted()
+        );
+    }
+
+    #[test]
+    fn test_top() {
+        assert_checker::<2, false>("top5%", &["As Ah", "Ks Kh"], &["7s 2h"]);
+        assert_checker::<2, false>("%100", &["7s 2h"], &[]);
+        assert_checker::<2, false>("top20%!AA", &["Ks Kh", "As Kh"], &["As Ah"]);
+        assert_checker::<2, false>("top20%:AKs", &["As Ks"], &["As Kh", "Ks Kh"]);
+        assert_checker::<4, false>("top10%", &["As Ah Ks Kh"], &["7s 5h 3d 2c"]);
+
+        let sd = Checker::<2, false, true>::from_src("top5%").unwrap();
+        assert!(sd.is_satisfied(&cards!["As Ah"]));
+        assert!(!sd.is_satisfied(&cards!["7s 6h"]));
+
+        assert_eq!(
+            Checker::<5, true, false>::from_src("AA,top10%"),
+            Err(Error::InvalidTopRange((3, 9)))
+        );
+    }
+
+    #[test]
+    fn test_top_with_order() {
+        // pocket pairs first, the rest alike
+        let order = Arc::new(StartingHandOrder::new(Game::Holdem, |cs| {
+            u32::from(cs[0].rank == cs[1].rank)
+        }));
+        let checker = Checker::<2, false, false>::from_src_with_order("top5%", &order).unwrap();
+
+        assert!(checker.is_satisfied(&cards!["2s 2h"]));
+        assert!(!checker.is_satisfied(&cards!["As Kh"]));
+    }
+
+    #[test]
+    fn test_not_partial_hands() {
+        let checker = Checker::<2, false, false>::from_src("AK!AsKs").unwrap();
+
+        assert!(checker.is_satisfied(&cards!["As"]));
+        assert!(checker.is_satisfied(&cards!["Ks"]));
+        assert!(checker.is_satisfied(&cards!["As Kh"]));
+        assert!(!checker.is_satisfied(&cards!["As Ks"]));
+
+        // a partial hand of the excluded range may still end outside it
+        let checker = Checker::<2, false, false>::from_src("**!AA").unwrap();
+
+        assert!(checker.is_satisfied(&cards!["As"]));
+        assert!(!checker.is_satisfied(&cards!["As Ah"]));
+    }
+
+    #[test]
+    fn test_partial_hands() {
+        let checker = Checker::<2, false, false>::from_src("top1%").unwrap();
+
+        assert!(checker.is_satisfied(&cards!["2s"]));
+        assert!(!checker.is_satisfied(&cards!["2s 2h"]));
+    }
+
     fn e<const N: usize>(s: &str) -> Error
     where
         [Idx; N]: Array<Item = Idx>,
@@ -1,4 +1,18 @@
-use super::{Array, Card, Deps, Error, Idx, Leaf, ast};
+use super::{
+    Arc, Array, Card, Deps, Error, Idx, Leaf, LocInfo, StartingHandOrder, ast, ast::Weight,
+};
+
+/// Ranking behind `top15%`; orders are only equal if they are the same.
+#[derive(Debug, Clone)]
+pub(super) struct TopOrder(pub Arc<StartingHandOrder>);
+
+impl PartialEq for TopOrder {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for TopOrder {}
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub(super) enum Expr<const N: usize, const B: bool>
@@ -8,6 +22,8 @@ where
     Not(Box<Self>, Box<Self>),
     And(Box<Self>, Box<Self>),
     Or(Box<Self>, Box<Self>),
+    Weighted(Box<Self>, Weight),
+    Top(Weight, TopOrder),
     Leaf(Leaf<N, B>),
 }
 
@@ -15,37 +31,69 @@ impl<const N: usize, const B: bool> Expr<N, B>
 where
     [Idx; N]: Array<Item = Idx>,
 {
+    /// Whether `cs` is in the range; fewer than `N` cards pass whenever
+    /// some completion of them might be, so `AK!AsKs` accepts `As`. A
+    /// whole combo passes exactly when its [`Self::weight`] is positive.
     #[inline]
     pub fn is_satisfied(&self, cs: &[Card]) -> bool {
         match self {
-            Self::Not(l, r) => l.is_satisfied(cs) && !r.is_satisfied(cs),
+            Self::Not(l, r) => l.is_satisfied(cs) && (cs.len() < N || !r.is_satisfied(cs)),
             Self::And(l, r) => l.is_satisfied(cs) && r.is_satisfied(cs),
             Self::Or(l, r) => l.is_satisfied(cs) || r.is_satisfied(cs),
+            // nested weights may round down to zero, as in `(AA@0.01)@0.01`
+            Self::Weighted(e, w) => {
+                *w > Weight::ZERO
+                    && e.is_satisfied(cs)
+                    && (cs.len() < N || e.weight(cs).scale(*w) > Weight::ZERO)
+            }
+            Self::Top(share, order) => cs.len() < N || order.0.is_in_top(cs, share.to_f64()),
             Self::Leaf(e) => e.is_satisfied(cs),
         }
     }
+
+    /// Weight of the combo `cs`: the largest of a union, the smallest of
+    /// an intersection and the product of nested weights.
+    pub fn weight(&self, cs: &[Card]) -> Weight {
+        match self {
+            Self::Not(l, r) if r.is_satisfied(cs) => Weight::ZERO,
+            Self::Not(l, _) => l.weight(cs),
+            Self::And(l, r) => l.weight(cs).min(r.weight(cs)),
+            Self::Or(l, r) => l.weight(cs).max(r.weight(cs)),
+            Self::Weighted(e, w) => e.weight(cs).scale(*w),
+            Self::Top(..) | Self::Leaf(_) if self.is_satisfied(cs) => Weight::FULL,
+            Self::Top(..) | Self::Leaf(_) => Weight::ZERO,
+        }
+    }
+
+    pub fn is_weighted(&self) -> bool {
+        match self {
+            Self::Not(l, r) | Self::And(l, r) | Self::Or(l, r) => {
+                l.is_weighted() || r.is_weighted()
+            }
+            Self::Weighted(..) => true,
+            Self::Top(..) | Self::Leaf(_) => false,
+        }
+    }
 }
 
-impl<const N: usize, const B: bool> TryFrom<ast::Expr> for Expr<N, B>
+impl<const N: usize, const B: bool> Expr<N, B>
 where
     [Idx; N]: Array<Item = Idx>,
 {
-    type Error = Error;
+    /// Converts a parsed range, asking `order` for the ranking of the
+    /// starting hands of any `top15%`.
+    pub fn from_ast(
+        expr: ast::Expr,
+        order: &dyn Fn(LocInfo) -> Result<Arc<StartingHandOrder>, Error>,
+    ) -> Result<Self, Error> {
+        let convert = |e: Box<ast::Expr>| Self::from_ast(*e, order).map(Box::new);
 
-    fn try_from(expr: ast::Expr) -> Result<Self, Self::Error> {
         match expr {
-            ast::Expr::Not(l, r) => Ok(Self::Not(
-                Box::new((*l).try_into()?),
-                Box::new((*r).try_into()?),
-            )),
-            ast::Expr::And(l, r) => Ok(Self::And(
-                Box::new((*l).try_into()?),
-                Box::new((*r).try_into()?),
-            )),
-            ast::Expr::Or(l, r) => Ok(Self::Or(
-                Box::new((*l).try_into()?),
-                Box::new((*r).try_into()?),
-            )),
+            ast::Expr::Not(l, r) => Ok(Self::Not(convert(l)?, convert(r)?)),
+            ast::Expr::And(l, r) => Ok(Self::And(convert(l)?, convert(r)?)),
+            ast::Expr::Or(l, r) => Ok(Self::Or(convert(l)?, convert(r)?)),
+            ast::Expr::Weighted(e, w) => Ok(Self::Weighted(convert(e)?, w)),
+            ast::Expr::Top(share, loc) => Ok(Self::Top(share, TopOrder(order(loc)?))),
             ast::Expr::Term(t, loc) => Ok(Self::Leaf((t, Deps(loc)).try_into()?)),
         }
     }
@@ -2,9 +2,9 @@ type IdxVec<const N: usize> = SmallVec<[Idx; N]>;
 type RankDiff = i8;
 
 use super::{
-    Array, Card, Card64, Error, From, Idx, Itertools, LazyLock, LocInfo, PhantomData, Rank, Rank16,
-    SmallVec, Suit, Suit4, ast,
-    ast::{List, ListElem, RangeCard, RankVar, Span, SpanElem, SuitVar, Term, TermElem},
+    Arc, Array, Card, Card64, Error, From, Game, Idx, Itertools, LazyLock, LocInfo, PhantomData,
+    Rank, Rank16, SmallVec, StartingHandOrder, Suit, Suit4, ast,
+    ast::{List, ListElem, RangeCard, RankVar, Span, SpanElem, SuitVar, Term, TermElem, Weight},
     ops, parse_expr,
 };
 
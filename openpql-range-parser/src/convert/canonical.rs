@@ -0,0 +1,243 @@
+use super::{ComboRange, Entry, HandN, RangeChecker, Rank, Suits, Weight, combo, runs};
+
+impl<const SD: bool> ComboRange<SD> {
+    /// Compact PQL range for the combos, the same for all equivalent
+    /// ranges: pairs as `JJ+` or `88-55`, kickers as `A[K-J]`, suited and
+    /// offsuit hands as `A[K-J]:ww` or `A[K-J]:xy`, then the combos of
+    /// partial classes, with each weight below 1 grouped as `[0.5](...)`.
+    pub fn to_canonical(&self) -> String {
+        let mut weights = self.combos.values().copied().collect::<Vec<_>>();
+        weights.sort_unstable();
+        weights.dedup();
+
+        let is_full = self.len() == HandN::<2>::iter_all::<SD>().count();
+        let entries = self.entries(false);
+
+        if weights.is_empty() {
+            return "[0]*".to_string();
+        }
+
+        weights
+            .iter()
+            .rev()
+            .map(|&w| {
+                let terms = if is_full && weights.len() == 1 {
+                    vec!["**".to_string()]
+                } else {
+                    canonical_terms::<SD>(&entries, w)
+                };
+
+                match w {
+                    Weight::FULL => terms.join(","),
+                    w if terms.len() == 1 && !terms[0].contains(':') => {
+                        format!("[{w}]{}", terms[0])
+                    }
+                    w => format!("[{w}]({})", terms.join(",")),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Terms of the entries of weight `weight`.
+fn canonical_terms<const SD: bool>(entries: &[(Entry, Weight)], weight: Weight) -> Vec<String> {
+    let ranks = Rank::all::<SD>();
+    let mut pairs = vec![];
+    let mut kickers: Vec<(Rank, Vec<Rank>, Vec<Rank>)> = vec![];
+    let mut combos = vec![];
+
+    for &(entry, w) in entries {
+        match entry {
+            _ if w != weight => (),
+            Entry::Class(c) if c.is_pair() => pairs.push(c.hi),
+            Entry::Class(c) => {
+                let i = kickers.iter().position(|(hi, ..)| *hi == c.hi);
+                let i = i.unwrap_or_else(|| {
+                    kickers.push((c.hi, vec![], vec![]));
+                    kickers.len() - 1
+                });
+
+                if c.suits == Suits::Suited {
+                    kickers[i].1.push(c.lo);
+                } else {
+                    kickers[i].2.push(c.lo);
+                }
+            }
+            Entry::Combo(hand) => combos.push(combo(hand)),
+        }
+    }
+
+    let mut terms = runs(ranks, &pairs)
+        .iter()
+        .map(|run| match (run[0], run[run.len() - 1]) {
+            (top, _) if run.len() == 1 => format!("{top}{top}"),
+            (Rank::RA, bottom) => format!("{bottom}{bottom}+"),
+            (top, bottom) => format!("{top}{top}-{bottom}{bottom}"),
+        })
+        .collect::<Vec<_>>();
+    let (mut suited, mut offsuit) = (vec![], vec![]);
+
+    for (hi, s, o) in kickers {
+        let both = s
+            .iter()
+            .filter(|r| o.contains(r))
+            .copied()
+            .collect::<Vec<_>>();
+        let only = |v: &[Rank]| {
+            v.iter()
+                .filter(|r| !both.contains(r))
+                .copied()
+                .collect::<Vec<_>>()
+        };
+
+        let merged = [
+            runs(ranks, &both),
+            runs(ranks, &only(&s)),
+            runs(ranks, &only(&o)),
+        ];
+        let split = [vec![], runs(ranks, &s), runs(ranks, &o)];
+        let [any, s, o] = if merged.iter().map(Vec::len).sum::<usize>()
+            <= split.iter().map(Vec::len).sum::<usize>()
+        {
+            merged
+        } else {
+            split
+        };
+
+        terms.extend(any.iter().map(|run| kicker_span(hi, run)));
+        suited.extend(s.into_iter().map(|run| (hi, run)));
+        offsuit.extend(o.into_iter().map(|run| (hi, run)));
+    }
+
+    terms.extend(suit_terms(&suited, ('w', 'w')));
+    terms.extend(suit_terms(&offsuit, ('x', 'y')));
+    terms.extend(combos);
+
+    terms
+}
+
+impl<const SD: bool> RangeChecker<2, SD> {
+    /// [`ComboRange::to_canonical`] of the range.
+    pub fn to_canonical(&self) -> String {
+        ComboRange::from_checker(self).to_canonical()
+    }
+
+    /// Whether both ranges hold the same combos at the same weights.
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        ComboRange::from_checker(self) == ComboRange::from_checker(other)
+    }
+}
+
+/// `AK` or `A[K-J]`.
+fn kicker_span(hi: Rank, run: &[Rank]) -> String {
+    match run {
+        [lo] => format!("{hi}{lo}"),
+        _ => format!("{hi}[{}-{}]", run[0], run[run.len() - 1]),
+    }
+}
+
+/// Suited or offsuit kicker runs, each as `AwKw` or `A[K-J]:ww`, or all
+/// together as `(AK,K[Q-J]):ww` when that is shorter.
+fn suit_terms(runs: &[(Rank, Vec<Rank>)], (s0, s1): (char, char)) -> Vec<String> {
+    let spans = runs
+        .iter()
+        .map(|(hi, run)| kicker_span(*hi, run))
+        .collect::<Vec<_>>();
+    let each = runs
+        .iter()
+        .zip(&spans)
+        .map(|((hi, run), span)| match run[..] {
+            [lo] => format!("{hi}{s0}{lo}{s1}"),
+            _ => format!("{span}:{s0}{s1}"),
+        })
+        .collect::<Vec<_>>();
+    let all = match &spans[..] {
+        [] => return vec![],
+        [span] => format!("{span}:{s0}{s1}"),
+        _ => format!("({}):{s0}{s1}", spans.join(",")),
+    };
+
+    if all.len() < each.join(",").len() {
+        vec![all]
+    } else {
+        each
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::{
+        convert::{Class, tests::mk_range},
+        *,
+    };
+
+    fn canonical(src: &str) -> String {
+        ComboRange::<false>::from_pio(src).unwrap().to_canonical()
+    }
+
+    #[test]
+    fn test_canonical() {
+        assert_eq!(canonical("AA,KK,QQ,JJ"), "JJ+");
+        assert_eq!(canonical("AKs,AQs,AJs"), "A[K-J]:ww");
+        assert_eq!(canonical("88-55,22,AK,AQs"), "88-55,22,AK,AwQw");
+        assert_eq!(canonical("A2s+,K9s+,QJs,AKo"), "AK,(A[Q-2],K[Q-9],QJ):ww");
+        assert_eq!(canonical("ATs+,ATo+,KQo"), "A[K-T],KxQy");
+        assert_eq!(canonical("AKs,AJs,ATs,A9s,AKo"), "AK,A[J-9]:ww");
+        assert_eq!(canonical("AA,KK:0.5,AhKh"), "AA,AhKh,[0.5]KK");
+        assert_eq!(canonical("QQ+:0.25,AKs:0.25"), "[0.25](QQ+,AwKw)");
+        assert_eq!(canonical(""), "[0]*");
+        assert_eq!(mk_range("**").to_canonical(), "**");
+        assert_eq!(mk_range("[0.5]**").to_canonical(), "[0.5]**");
+        assert_eq!(
+            ComboRange::<true>::from_pio("66+,A6s+")
+                .unwrap()
+                .to_canonical(),
+            "66+,A[K-6]:ww"
+        );
+    }
+
+    #[test]
+    fn test_equivalent() {
+        let checker = |s| RangeChecker::<2, false>::from_src(s).unwrap();
+
+        assert!(checker("AA,KK,QQ").is_equivalent(&checker("QQ+")));
+        assert!(checker("AwKw,AxKy").is_equivalent(&checker("AK")));
+        assert!(checker("AA@50").is_equivalent(&checker("[0.5]AA")));
+        assert!(!checker("AA@50").is_equivalent(&checker("AA")));
+        assert!(!checker("AK").is_equivalent(&checker("AwKw")));
+        assert_eq!(checker("AA,KK,AwKw,AwQw").to_canonical(), "KK+,A[K-Q]:ww");
+        assert_eq!(checker("AwKw, AwQw, AwJw").to_canonical(), "A[K-J]:ww");
+        assert_eq!(
+            checker("AK, [0.5]QQ, AwQw@50").to_canonical(),
+            "AK,[0.5](QQ,AwQw)"
+        );
+    }
+
+    #[quickcheck]
+    fn test_canonical_round_trip(classes: Vec<(u8, bool)>, combos: Vec<u16>) {
+        let all = HandN::<2>::iter_all::<false>().collect::<Vec<_>>();
+        let half = Weight::from_fraction("0.5", (0, 0)).unwrap();
+        let mut range = ComboRange::<false>::default();
+
+        for (i, is_half) in classes.into_iter().take(30) {
+            let class = Class::all::<false>()[usize::from(i) % 169];
+
+            for hand in class.hands() {
+                range.insert(hand, if is_half { half } else { Weight::FULL });
+            }
+        }
+
+        for i in combos.into_iter().take(5) {
+            range.insert(all[usize::from(i) % all.len()], Weight::FULL);
+        }
+
+        let canonical = range.to_canonical();
+        let checker = RangeChecker::<2, false>::from_src(&canonical).unwrap();
+
+        assert_eq!(ComboRange::from_checker(&checker), range, "{canonical}");
+        assert_eq!(checker.to_canonical(), canonical);
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt;
+
+use super::{Card, HandN, Rank, Suit};
+
+/// Suits of the combos of a [`Class`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Suits {
+    Suited,
+    Offsuit,
+    /// Pairs, or `AK` for both suited and offsuit.
+    Any,
+}
+
+/// Starting-hand class of the classic notation: `AA`, `AKs`, `AKo` or
+/// `AK`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct Class {
+    pub hi: Rank,
+    pub lo: Rank,
+    pub suits: Suits,
+}
+
+impl Class {
+    pub const fn new(hi: Rank, lo: Rank, suits: Suits) -> Self {
+        Self { hi, lo, suits }
+    }
+
+    pub const fn is_pair(self) -> bool {
+        self.hi.const_eq(self.lo)
+    }
+
+    pub fn contains(self, hand: HandN<2>) -> bool {
+        let (lo, hi) = (hand[0], hand[1]);
+
+        hi.rank == self.hi
+            && lo.rank == self.lo
+            && match self.suits {
+                Suits::Suited => lo.suit == hi.suit,
+                Suits::Offsuit => lo.suit != hi.suit,
+                Suits::Any => true,
+            }
+    }
+
+    /// Combos of the class.
+    pub fn hands(self) -> impl Iterator<Item = HandN<2>> {
+        Suit::ARR_ALL.into_iter().flat_map(move |s0| {
+            Suit::ARR_ALL.into_iter().filter_map(move |s1| {
+                let cards = [Card::new(self.lo, s0), Card::new(self.hi, s1)];
+
+                (cards[0] < cards[1] && self.contains(HandN::from_slice(&cards)))
+                    .then(|| HandN::from_slice(&cards))
+            })
+        })
+    }
+
+    /// Classes from the strongest: each pair, then the suited and offsuit
+    /// hands under it by falling kicker.
+    pub fn all<const SD: bool>() -> Vec<Self> {
+        let ranks = Rank::all::<SD>();
+        let mut classes = vec![];
+
+        for (i, &hi) in ranks.iter().enumerate().rev() {
+            classes.push(Self::new(hi, hi, Suits::Any));
+
+            for &lo in ranks[..i].iter().rev() {
+                classes.push(Self::new(hi, lo, Suits::Suited));
+                classes.push(Self::new(hi, lo, Suits::Offsuit));
+            }
+        }
+
+        classes
+    }
+
+    /// Parses `AA`, `AKs`, `AKo` or `AK`, in either rank order.
+    fn parse<const SD: bool>(s: &str) -> Option<Self> {
+        let mut chars = s.chars();
+        let r0 = rank::<SD>(chars.next()?)?;
+        let r1 = rank::<SD>(chars.next()?)?;
+        let suits = match chars.next() {
+            None => Suits::Any,
+            Some('s' | 'S') if r0 != r1 => Suits::Suited,
+            Some('o' | 'O') if r0 != r1 => Suits::Offsuit,
+            Some(_) => return None,
+        };
+
+        if chars.next().is_some() {
+            return None;
+        }
+
+        Some(Self::new(r0.max(r1), r0.min(r1), suits))
+    }
+
+    /// Classes of one entry: a class, `QQ+`, `A2s+`, `88-55` or
+    /// `KTs-K8s`.
+    pub fn parse_span<const SD: bool>(s: &str) -> Option<Vec<Self>> {
+        let ranks = Rank::all::<SD>();
+        let pos = |r: Rank| ranks.iter().position(|&x| x == r).unwrap();
+
+        let (from, to) = if let Some(s) = s.strip_suffix('+') {
+            let from = Self::parse::<SD>(s)?;
+            let to = if from.is_pair() {
+                Self::new(Rank::RA, Rank::RA, Suits::Any)
+            } else {
+                Self::new(from.hi, ranks[pos(from.hi) - 1], from.suits)
+            };
+
+            (from, to)
+        } else if let Some((l, r)) = s.split_once('-') {
+            (Self::parse::<SD>(l)?, Self::parse::<SD>(r)?)
+        } else {
+            return Some(vec![Self::parse::<SD>(s)?]);
+        };
+
+        let (lo, hi) = if from.lo <= to.lo {
+            (from, to)
+        } else {
+            (to, from)
+        };
+
+        if lo.is_pair() && hi.is_pair() {
+            Some(
+                ranks[pos(lo.hi)..=pos(hi.hi)]
+                    .iter()
+                    .map(|&r| Self::new(r, r, Suits::Any))
+                    .collect(),
+            )
+        } else if lo.hi == hi.hi && lo.suits == hi.suits && !lo.is_pair() && !hi.is_pair() {
+            Some(
+                ranks[pos(lo.lo)..=pos(hi.lo)]
+                    .iter()
+                    .map(|&r| Self::new(lo.hi, r, lo.suits))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+fn rank<const SD: bool>(c: char) -> Option<Rank> {
+    Rank::from_char(c).filter(|r| Rank::all::<SD>().contains(r))
+}
+
+/// Parses a combo such as `AsKh`.
+pub(super) fn parse_combo<const SD: bool>(s: &str) -> Option<HandN<2>> {
+    let c0 = s.get(..2)?.parse::<Card>().ok()?;
+    let c1 = s.get(2..)?.parse::<Card>().ok()?;
+    let in_deck = |c: Card| Rank::all::<SD>().contains(&c.rank);
+
+    (c0 != c1 && in_deck(c0) && in_deck(c1)).then(|| HandN::from_slice(&[c0, c1]))
+}
+
+/// Classic notation, `AKs`.
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let suits = match self.suits {
+            Suits::Suited => "s",
+            Suits::Offsuit => "o",
+            Suits::Any => "",
+        };
+
+        write!(f, "{}{}{suits}", self.hi, self.lo)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    fn spans(s: &str) -> Vec<String> {
+        Class::parse_span::<false>(s)
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(spans("AKs"), ["AKs"]);
+        assert_eq!(spans("ka"), ["AK"]);
+        assert_eq!(spans("QQ+"), ["QQ", "KK", "AA"]);
+        assert_eq!(spans("55-77"), ["55", "66", "77"]);
+        assert_eq!(spans("KTo+"), ["KTo", "KJo", "KQo"]);
+        assert_eq!(spans("KTs-K8s"), ["K8s", "K9s", "KTs"]);
+
+        for s in ["AAs", "AKx", "AKs-QJs", "AKs-AQo", "AA-AKs", "A", "AKss"] {
+            assert!(Class::parse_span::<false>(s).is_none(), "{s}");
+        }
+
+        assert!(Class::parse_span::<true>("55").is_none());
+        assert!(parse_combo::<true>("As5h").is_none());
+        assert!(parse_combo::<false>("AsAs").is_none());
+    }
+
+    #[test]
+    fn test_all() {
+        let classes = Class::all::<false>();
+
+        assert_eq!(classes.len(), 169);
+        assert!(
+            HandN::<2>::iter_all::<false>().all(|hand| classes
+                .iter()
+                .filter(|c| c.contains(hand))
+                .count()
+                == 1)
+        );
+        assert_eq!(Class::all::<true>().len(), 81);
+    }
+}
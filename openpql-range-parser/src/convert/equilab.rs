@@ -0,0 +1,112 @@
+use super::{Class, ComboRange, Entry, Error, Rank, Suits, combo, runs};
+
+impl<const SD: bool> ComboRange<SD> {
+    /// Parses a Flopzilla or Equilab hand list such as `TT+,A9s+,KQo,AhKh`.
+    pub fn from_equilab(src: &str) -> Result<Self, Error> {
+        Self::from_list(src, false)
+    }
+
+    /// Writes the range as a hand list with spans such as `TT+`, `88-55`
+    /// and `KTs-K8s`; `None` for weighted ranges, which the list can not
+    /// express.
+    pub fn to_equilab(&self) -> Option<String> {
+        if self.is_weighted() {
+            return None;
+        }
+
+        let ranks = Rank::all::<SD>();
+        let mut pairs = vec![];
+        let mut kickers: Vec<(Rank, Suits, Vec<Rank>)> = vec![];
+        let mut combos = vec![];
+
+        for (entry, _) in self.entries(false) {
+            match entry {
+                Entry::Class(c) if c.is_pair() => pairs.push(c.hi),
+                Entry::Class(c) => {
+                    match kickers
+                        .iter_mut()
+                        .find(|(hi, suits, _)| *hi == c.hi && *suits == c.suits)
+                    {
+                        Some((_, _, los)) => los.push(c.lo),
+                        None => kickers.push((c.hi, c.suits, vec![c.lo])),
+                    }
+                }
+                Entry::Combo(hand) => combos.push(combo(hand)),
+            }
+        }
+
+        let mut list = vec![];
+
+        for run in runs(ranks, &pairs) {
+            let (top, bottom) = (run[0], run[run.len() - 1]);
+
+            list.push(match run.len() {
+                1 => format!("{top}{top}"),
+                _ if top == Rank::RA => format!("{bottom}{bottom}+"),
+                _ => format!("{top}{top}-{bottom}{bottom}"),
+            });
+        }
+
+        for (hi, suits, los) in kickers {
+            let class = |lo| Class::new(hi, lo, suits);
+
+            for run in runs(ranks, &los) {
+                let (top, bottom) = (run[0], run[run.len() - 1]);
+                let is_max = ranks.iter().position(|&r| r == top) == {
+                    ranks.iter().position(|&r| r == hi).map(|i| i - 1)
+                };
+
+                list.push(match run.len() {
+                    1 => class(top).to_string(),
+                    _ if is_max => format!("{}+", class(bottom)),
+                    _ => format!("{}-{}", class(top), class(bottom)),
+                });
+            }
+        }
+
+        list.extend(combos);
+
+        Some(list.join(","))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::convert::tests::mk_range;
+
+    #[test]
+    fn test_from_equilab() {
+        assert_eq!(
+            ComboRange::<false>::from_equilab("TT+, A9s+, KQo").unwrap(),
+            ComboRange::from_pio("TT,JJ,QQ,KK,AA,AKs,AQs,AJs,ATs,A9s,KQo").unwrap()
+        );
+        assert_eq!(
+            ComboRange::<false>::from_equilab("AA:0.5"),
+            Err(Error::InvalidHand((0, 6)))
+        );
+    }
+
+    #[test]
+    fn test_to_equilab() {
+        for src in [
+            "TT+,88-55,22,AKs,A9s-A7s,AKo,KTs-K8s,T9s,AhQh",
+            "JJ+,A2s+,AJo+,KQs",
+            "",
+        ] {
+            let range = ComboRange::<false>::from_equilab(src).unwrap();
+
+            assert_eq!(range.to_equilab().as_deref(), Some(src));
+        }
+
+        assert_eq!(mk_range("AA,[0.5]KK").to_equilab(), None);
+        assert_eq!(
+            ComboRange::<true>::from_equilab("66+,A6s+")
+                .unwrap()
+                .to_equilab()
+                .as_deref(),
+            Some("66+,A6s+")
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use super::{ComboRange, Error, HandMatrix, HandN, IsomorphicHandN, Rank, Weight, split_list};
+
+impl<const SD: bool> ComboRange<SD> {
+    /// Weighs every combo by the cell of its hand class.
+    pub fn from_matrix(matrix: &HandMatrix<Weight, SD>) -> Self {
+        let mut range = Self::default();
+
+        for hand in HandN::<2>::iter_all::<SD>() {
+            range.insert(
+                hand,
+                matrix[IsomorphicHandN::<2>::from_slice_preflop(&hand[..])],
+            );
+        }
+
+        range
+    }
+
+    /// Weight of each hand class; `None` if the combos of a class have
+    /// different weights.
+    pub fn to_matrix(&self) -> Option<HandMatrix<Weight, SD>> {
+        let mut matrix = HandMatrix::<Option<Weight>, SD>::new(&|_| None);
+
+        for hand in HandN::<2>::iter_all::<SD>() {
+            let weight = self.combos.get(&hand).copied().unwrap_or_default();
+            let cell = &mut matrix[IsomorphicHandN::<2>::from_slice_preflop(&hand[..])];
+
+            match cell {
+                Some(w) if *w != weight => return None,
+                _ => *cell = Some(weight),
+            }
+        }
+
+        Some(HandMatrix::new(&|hand| matrix[hand].unwrap_or_default()))
+    }
+
+    /// Parses a matrix of weights laid out like the grids of Pio,
+    /// GTO+ and Equilab: 13 lines of 13 cells, or 9 of 9 for Short Deck,
+    /// from `AA` in the top left corner down to `22`, with suited hands
+    /// above the diagonal. This is [`HandMatrix`] turned about its
+    /// anti-diagonal.
+    pub fn from_matrix_csv(src: &str) -> Result<Self, Error> {
+        let side = Rank::all::<SD>().len();
+        let mut cells = vec![];
+
+        let rows = split_list(src, &['\n'], 0).filter(|(row, _)| !row.is_empty());
+
+        for (i, (row, loc)) in rows.enumerate() {
+            let row = split_list(row, &[','], loc.0).collect::<Vec<_>>();
+
+            if i >= side || row.len() != side {
+                return Err(Error::InvalidMatrix(loc));
+            }
+
+            for (cell, loc) in row {
+                cells.push(Weight::from_fraction(cell, loc)?);
+            }
+        }
+
+        if cells.len() != side * side {
+            return Err(Error::InvalidMatrix((src.len(), src.len())));
+        }
+
+        let mut matrix = HandMatrix::<Weight, SD>::new(&|_| Weight::ZERO);
+
+        for (i, (_, cell)) in matrix.iter_mut().enumerate() {
+            *cell = cells[flip(i, side)];
+        }
+
+        Ok(Self::from_matrix(&matrix))
+    }
+
+    /// Writes [`Self::to_matrix`] in the format of [`Self::from_matrix_csv`].
+    pub fn to_matrix_csv(&self) -> Option<String> {
+        let side = Rank::all::<SD>().len();
+        let weights = self
+            .to_matrix()?
+            .iter()
+            .map(|(_, w)| w.to_string())
+            .collect::<Vec<_>>();
+        let cells = (0..weights.len())
+            .map(|i| weights[flip(i, side)].as_str())
+            .collect::<Vec<_>>();
+
+        Some(
+            cells
+                .chunks(side)
+                .map(|row| row.join(","))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+}
+
+/// Index of the cell mirrored about the anti-diagonal of a `side` square,
+/// which maps the `22`-first [`HandMatrix`] to the `AA`-first grid and back.
+const fn flip(i: usize, side: usize) -> usize {
+    let (row, col) = (i / side, i % side);
+
+    (side - 1 - col) * side + (side - 1 - row)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::{convert::tests::mk_range, *};
+
+    #[test]
+    fn test_matrix() {
+        let range = mk_range("AA,[0.5]AwKw,[0.25]AxKy,32");
+        let matrix = range.to_matrix().unwrap();
+
+        assert_eq!(ComboRange::from_matrix(&matrix), range);
+        assert!(mk_range("AsKh").to_matrix().is_none());
+    }
+
+    #[test]
+    fn test_matrix_csv() {
+        let range = mk_range("AA,[0.5]AwKw,[0.25]AxKy,32");
+        let csv = range.to_matrix_csv().unwrap();
+        let rows = csv.lines().collect::<Vec<_>>();
+
+        // AA first, AKs to its right and AKo below it
+        assert_eq!(rows.len(), 13);
+        assert_eq!(rows[0], "1,0.5,0,0,0,0,0,0,0,0,0,0,0");
+        assert_eq!(rows[1], "0.25,0,0,0,0,0,0,0,0,0,0,0,0");
+        assert_eq!(rows[11], "0,0,0,0,0,0,0,0,0,0,0,0,1");
+        assert_eq!(rows[12], "0,0,0,0,0,0,0,0,0,0,0,1,0");
+        assert_eq!(ComboRange::from_matrix_csv(&csv).unwrap(), range);
+        assert_eq!(
+            ComboRange::from_matrix_csv(&format!("\n{csv}\n\n")).unwrap(),
+            range
+        );
+
+        let range = ComboRange::<true>::from_equilab("66+,AKs").unwrap();
+
+        assert_eq!(
+            ComboRange::from_matrix_csv(&range.to_matrix_csv().unwrap()).unwrap(),
+            range
+        );
+    }
+
+    #[test]
+    fn test_matrix_csv_error() {
+        let row = ["0"; 13].join(",");
+        let csv = [row.as_str(); 13].join("\n");
+
+        assert_eq!(
+            ComboRange::<false>::from_matrix_csv(&csv[..csv.len() - 2]),
+            Err(Error::InvalidMatrix((312, 335)))
+        );
+        assert_eq!(
+            ComboRange::<false>::from_matrix_csv(&format!("{csv}\n{row}")),
+            Err(Error::InvalidMatrix((338, 363)))
+        );
+        assert_eq!(
+            ComboRange::<false>::from_matrix_csv(&csv[..csv.len() - 26]),
+            Err(Error::InvalidMatrix((311, 311)))
+        );
+        assert_eq!(
+            ComboRange::<false>::from_matrix_csv(&csv.replacen('0', "2", 1)),
+            Err(Error::InvalidWeight((0, 1)))
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::BTreeMap;
+
+use super::{
+    Card, Error, HandMatrix, HandN, IsomorphicHandN, Loc, LocInfo, RangeChecker, Rank, Suit,
+    ast::Weight,
+};
+
+mod canonical;
+mod class;
+mod equilab;
+mod matrix;
+mod pio;
+
+use class::{Class, Suits, parse_combo};
+
+/// Hold'em or Short Deck range as the weights of its combos, for moving
+/// ranges between PQL and solvers or equity tools.
+///
+/// Every format converts both ways: `from_pio(r.to_pio())` gives back
+/// `r`, and so do the PQL text of [`Self::to_pql`], the Equilab lists of
+/// unweighted ranges and the matrices of ranges that weigh all combos of a
+/// hand class alike.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ComboRange<const SD: bool = false> {
+    combos: BTreeMap<HandN<2>, Weight>,
+}
+
+/// Token of an exported range: a whole class or a single combo.
+#[derive(Clone, Copy)]
+enum Entry {
+    Class(Class),
+    Combo(HandN<2>),
+}
+
+impl<const SD: bool> ComboRange<SD> {
+    /// Weighs every combo by `checker`.
+    pub fn from_checker(checker: &RangeChecker<2, SD>) -> Self {
+        let mut range = Self::default();
+
+        for hand in HandN::<2>::iter_all::<SD>() {
+            range.insert(hand, checker.combo_weight(&hand[..]));
+        }
+
+        range
+    }
+
+    /// # Panics
+    /// [`Self::to_pql`] writes valid ranges
+    pub fn to_checker(&self) -> RangeChecker<2, SD> {
+        RangeChecker::from_src(&self.to_pql()).unwrap()
+    }
+
+    /// PQL range such as `AA,[0.5]AwKw,[0.25]AsKh`; whole classes are
+    /// written with suit variables and partial ones combo by combo. The
+    /// empty range is `[0]*`.
+    pub fn to_pql(&self) -> String {
+        if self.is_empty() {
+            return "[0]*".to_string();
+        }
+
+        let entry = |e: &Entry| match e {
+            Entry::Class(c) if c.is_pair() => format!("{}{}", c.hi, c.lo),
+            Entry::Class(c) => match c.suits {
+                Suits::Suited => format!("{}w{}w", c.hi, c.lo),
+                Suits::Offsuit => format!("{}x{}y", c.hi, c.lo),
+                Suits::Any => format!("{}{}", c.hi, c.lo),
+            },
+            Entry::Combo(hand) => combo(*hand),
+        };
+
+        self.entries(true)
+            .iter()
+            .map(|(e, w)| match *w {
+                Weight::FULL => entry(e),
+                w => format!("[{w}]{}", entry(e)),
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Weight of the combo `cards`, 0 outside the range.
+    pub fn weight(&self, cards: &[Card]) -> f64 {
+        self.combos
+            .get(&HandN::from_slice(cards))
+            .copied()
+            .unwrap_or_default()
+            .to_f64()
+    }
+
+    pub fn len(&self) -> usize {
+        self.combos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+
+    /// Whether any combo has a weight below 1.
+    pub fn is_weighted(&self) -> bool {
+        self.combos.values().any(|&w| w != Weight::FULL)
+    }
+
+    /// Reads a range from a file of any supported format: a matrix CSV if
+    /// its first line is a row of numbers, a Pio list otherwise (which
+    /// also takes Equilab lists).
+    pub fn from_text(src: &str) -> Result<Self, Error> {
+        let first = src.lines().find(|l| !l.trim().is_empty()).unwrap_or("");
+
+        if first
+            .split(',')
+            .all(|cell| cell.trim().parse::<f64>().is_ok())
+        {
+            Self::from_matrix_csv(src)
+        } else {
+            Self::from_pio(src)
+        }
+    }
+
+    fn insert(&mut self, hand: HandN<2>, weight: Weight) {
+        if weight == Weight::ZERO {
+            self.combos.remove(&hand);
+        } else {
+            self.combos.insert(hand, weight);
+        }
+    }
+
+    fn class_weights(&self, class: Class) -> impl Iterator<Item = Weight> + '_ {
+        class
+            .hands()
+            .map(|hand| self.combos.get(&hand).copied().unwrap_or_default())
+    }
+
+    /// Weight of the combos of `class` if they all have the same.
+    fn class_weight(&self, class: Class) -> Option<Weight> {
+        let mut weights = self.class_weights(class);
+        let first = weights.next()?;
+
+        weights.all(|w| w == first).then_some(first)
+    }
+
+    /// Classes and combos of the range from the strongest, merging the
+    /// suited and offsuit hands of a rank pair into `AK` if asked to.
+    fn entries(&self, merge_suits: bool) -> Vec<(Entry, Weight)> {
+        let mut entries = vec![];
+        let push_class = |entries: &mut Vec<_>, class: Class| match self.class_weight(class) {
+            Some(Weight::ZERO) => (),
+            Some(w) => entries.push((Entry::Class(class), w)),
+            None => entries.extend(
+                self.combos
+                    .iter()
+                    .filter(|&(&hand, _)| class.contains(hand))
+                    .map(|(&hand, &w)| (Entry::Combo(hand), w))
+                    .rev(),
+            ),
+        };
+
+        for class in Class::all::<SD>() {
+            if class.suits == Suits::Offsuit && merge_suits {
+                let both = Class::new(class.hi, class.lo, Suits::Any);
+                let suited = Class::new(class.hi, class.lo, Suits::Suited);
+
+                if self.class_weight(both).is_some_and(|w| w != Weight::ZERO) {
+                    entries.pop();
+                    entries.push((Entry::Class(both), self.class_weight(suited).unwrap()));
+                    continue;
+                }
+            }
+
+            push_class(&mut entries, class);
+        }
+
+        entries
+    }
+}
+
+fn combo(hand: HandN<2>) -> String {
+    format!("{}{}", hand[1], hand[0])
+}
+
+/// Splits falling `ranks` into runs of neighbours in `all`.
+fn runs(all: &[Rank], ranks: &[Rank]) -> Vec<Vec<Rank>> {
+    let pos = |r: Rank| all.iter().position(|&x| x == r).unwrap();
+    let mut runs: Vec<Vec<Rank>> = vec![];
+
+    for &r in ranks {
+        match runs.last_mut() {
+            Some(run) if pos(run[run.len() - 1]) == pos(r) + 1 => run.push(r),
+            _ => runs.push(vec![r]),
+        }
+    }
+
+    runs
+}
+
+/// Splits `src`, found at `offset` of the whole text, into its trimmed
+/// parts and their locations.
+fn split_list<'a>(
+    src: &'a str,
+    seps: &'a [char],
+    offset: Loc,
+) -> impl Iterator<Item = (&'a str, LocInfo)> + 'a {
+    src.split(seps).scan(offset, |start, part| {
+        let loc = *start + part.len() - part.trim_start().len();
+        *start += part.len() + 1;
+
+        Some((part.trim(), (loc, loc + part.trim().len())))
+    })
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    pub fn mk_range(src: &str) -> ComboRange {
+        ComboRange::from_checker(&RangeChecker::from_src(src).unwrap())
+    }
+
+    #[test]
+    fn test_from_checker() {
+        let range = mk_range("AA,[0.5]AwKw");
+
+        assert_eq!(range.len(), 10);
+        assert!(range.is_weighted());
+        assert_eq!(range.weight(&cards!("AsKs")).to_bits(), 0.5f64.to_bits());
+        assert_eq!(range.weight(&cards!("AhAd")).to_bits(), 1.0f64.to_bits());
+        assert!(range.weight(&cards!("AsKh")).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_to_pql() {
+        for src in [
+            "AA,KK",
+            "AA,[0.5]AwKw,AxKy",
+            "AK,QwJw",
+            "AsKh,[0.5]AhKs,[0.25]QQ",
+            "[0]*",
+        ] {
+            let range = mk_range(src);
+
+            assert_eq!(range.to_pql(), src);
+            assert_eq!(ComboRange::from_checker(&range.to_checker()), range);
+        }
+    }
+
+    #[test]
+    fn test_split_list() {
+        assert_eq!(
+            split_list(" AA, KK\nQQ", &[',', '\n'], 0).collect::<Vec<_>>(),
+            [("AA", (1, 3)), ("KK", (5, 7)), ("QQ", (8, 10))]
+        );
+        assert_eq!(
+            split_list("1, 0", &[','], 4).collect::<Vec<_>>(),
+            [("1", (4, 5)), ("0", (7, 8))]
+        );
+    }
+
+    #[test]
+    fn test_from_text() {
+        assert_eq!(
+            ComboRange::<false>::from_text("AA:0.5,KK").unwrap(),
+            mk_range("[0.5]AA,KK")
+        );
+
+        let mut csv = vec!["0"; 169];
+        csv[0] = "1";
+        let csv = csv
+            .chunks(13)
+            .map(|row| row.join(","))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(
+            ComboRange::<false>::from_text(&csv).unwrap(),
+            mk_range("AA")
+        );
+    }
+}
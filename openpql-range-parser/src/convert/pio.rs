@@ -0,0 +1,130 @@
+use super::{Class, ComboRange, Entry, Error, Weight, combo, parse_combo, split_list};
+
+impl<const SD: bool> ComboRange<SD> {
+    /// Parses a Pio or GTO+ range such as `AA,AKs:0.5,AhKh:0.25`.
+    ///
+    /// Entries are classes, spans like `QQ+` or `KTs-K8s`, or combos,
+    /// separated by commas or newlines; later entries override earlier
+    /// ones.
+    pub fn from_pio(src: &str) -> Result<Self, Error> {
+        Self::from_list(src, true)
+    }
+
+    /// Writes the range class by class from the strongest, splitting classes
+    /// with mixed weights into their combos.
+    pub fn to_pio(&self) -> String {
+        self.entries(false)
+            .iter()
+            .map(|(e, w)| {
+                let hand = match e {
+                    Entry::Class(c) => c.to_string(),
+                    Entry::Combo(hand) => combo(*hand),
+                };
+
+                match *w {
+                    Weight::FULL => hand,
+                    w => format!("{hand}:{w}"),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    pub(super) fn from_list(src: &str, weighted: bool) -> Result<Self, Error> {
+        let mut range = Self::default();
+
+        for (entry, loc) in split_list(src, &[',', '\n'], 0) {
+            if entry.is_empty() {
+                continue;
+            }
+
+            let (hand, weight) = match entry.split_once(':') {
+                Some((hand, w)) if weighted => {
+                    let start = loc.0 + hand.len() + 1;
+                    let w_loc = (start + w.len() - w.trim_start().len(), loc.1);
+
+                    (hand.trim_end(), Weight::from_fraction(w.trim(), w_loc)?)
+                }
+                _ => (entry, Weight::FULL),
+            };
+
+            let hand_loc = (loc.0, loc.0 + hand.len());
+
+            if let Some(hand) = parse_combo::<SD>(hand) {
+                range.insert(hand, weight);
+                continue;
+            }
+
+            for class in Class::parse_span::<SD>(hand).ok_or(Error::InvalidHand(hand_loc))? {
+                for hand in class.hands() {
+                    range.insert(hand, weight);
+                }
+            }
+        }
+
+        Ok(range)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::{convert::tests::mk_range, *};
+
+    #[test]
+    fn test_from_pio() {
+        assert_eq!(
+            ComboRange::from_pio("AA, AKs: 0.5,\nAhKh:0.25\n").unwrap(),
+            mk_range("AA,[0.5]AwKw!AhKh,[0.25]AhKh")
+        );
+        assert_eq!(
+            ComboRange::from_pio("QQ+,AK,KQs:0,A5s-A2s").unwrap(),
+            mk_range("QQ,KK,AA,AK,Aw5w,Aw4w,Aw3w,Aw2w")
+        );
+        assert_eq!(
+            ComboRange::<false>::from_pio("AA:1,AA:0").unwrap(),
+            ComboRange::default()
+        );
+        assert_eq!(
+            ComboRange::<true>::from_pio("66+").unwrap().len(),
+            ComboRange::<true>::from_pio("AA,KK,QQ,JJ,TT,99,88,77,66")
+                .unwrap()
+                .len()
+        );
+    }
+
+    #[test]
+    fn test_from_pio_error() {
+        assert_eq!(
+            ComboRange::<false>::from_pio("AA,KQx:0.5"),
+            Err(Error::InvalidHand((3, 6)))
+        );
+        assert_eq!(
+            ComboRange::<false>::from_pio("AA, KQs: 1.5"),
+            Err(Error::InvalidWeight((9, 12)))
+        );
+        assert_eq!(
+            ComboRange::<true>::from_pio("AA,55"),
+            Err(Error::InvalidHand((3, 5)))
+        );
+    }
+
+    #[test]
+    fn test_to_pio() {
+        assert_eq!(
+            ComboRange::<false>::from_pio("QQ+,AKs:0.5")
+                .unwrap()
+                .to_pql(),
+            "AA,[0.5]AwKw,KK,QQ"
+        );
+
+        for src in ["AA,AKs:0.5,AKo", "AsKh:0.25,AhKs,KK:0.75,T9s", ""] {
+            let range = ComboRange::<false>::from_pio(src).unwrap();
+
+            assert_eq!(range.to_pio(), src);
+            assert_eq!(ComboRange::from_pio(&range.to_pio()).unwrap(), range);
+            assert_eq!(ComboRange::from_checker(&range.to_checker()), range);
+        }
+    }
+}
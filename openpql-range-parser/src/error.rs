@@ -28,6 +28,14 @@ pub enum Error {
     InvalidRank(LocInfo),
     /// Suit is invalid.
     InvalidSuit(LocInfo),
+    /// Weight or `top15%` share is not between 0 and 1 (or 0% and 100%).
+    InvalidWeight(LocInfo),
+    /// `top15%` where starting hands are not ranked, such as on a board.
+    InvalidTopRange(LocInfo),
+    /// Entry of an imported range is not a hand class, span or combo.
+    InvalidHand(LocInfo),
+    /// Imported hand matrix is not a square of 13 (or 9) rows.
+    InvalidMatrix(LocInfo),
 }
 
 /// LALRPOP parse error specialized for this grammar.
@@ -72,7 +80,11 @@ impl From<&Error> for LocInfo {
             | Error::InvalidSpan(loc)
             | Error::InvalidList(loc)
             | Error::InvalidRank(loc)
-            | Error::InvalidSuit(loc) => *loc,
+            | Error::InvalidSuit(loc)
+            | Error::InvalidWeight(loc)
+            | Error::InvalidTopRange(loc)
+            | Error::InvalidHand(loc)
+            | Error::InvalidMatrix(loc) => *loc,
         }
     }
 }
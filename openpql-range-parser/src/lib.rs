@@ -2,7 +2,13 @@
 #![cfg_attr(test, allow(clippy::needless_pass_by_value))]
 #![cfg_attr(test, allow(clippy::wildcard_imports))]
 
-use std::{convert::From, marker::PhantomData, ops, string::ToString, sync::LazyLock};
+use std::{
+    convert::From,
+    marker::PhantomData,
+    ops,
+    string::ToString,
+    sync::{Arc, LazyLock},
+};
 
 use itertools::Itertools;
 use lalrpop_util::{ParseError, lalrpop_mod, lexer::Token};
@@ -12,9 +18,11 @@ use smallvec::{Array, SmallVec};
 /// Abstract syntax tree nodes for parsed range expressions.
 pub mod ast;
 mod checker;
+mod convert;
 mod error;
 
 pub use checker::{BoardRangeChecker, RangeChecker};
+pub use convert::ComboRange;
 pub use error::Error;
 use error::{LalrError, ResultE};
 
@@ -184,5 +192,17 @@ pub mod tests {
 
         let err = Error::InvalidSuit((11, 16));
         assert_eq!(LocInfo::from(&err), (11, 16));
+
+        let err = Error::InvalidWeight((2, 6));
+        assert_eq!(LocInfo::from(&err), (2, 6));
+
+        let err = Error::InvalidTopRange((0, 6));
+        assert_eq!(LocInfo::from(&err), (0, 6));
+
+        let err = Error::InvalidHand((4, 7));
+        assert_eq!(LocInfo::from(&err), (4, 7));
+
+        let err = Error::InvalidMatrix((0, 12));
+        assert_eq!(LocInfo::from(&err), (0, 12));
     }
 }
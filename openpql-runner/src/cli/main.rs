@@ -1,7 +1,10 @@
-use std::io;
+use std::{fs, io, process::ExitCode};
 
-use clap::Command;
-use opql::PQLRunner;
+use clap::{Command, builder::PossibleValuesParser};
+use openpql_pql_parser::{ast::FromValue, parse_pql};
+use openpql_prelude::Game;
+use openpql_range_parser::{ComboRange, Error as RangeError, LocInfo};
+use opql::{OutputFormat, PQLRunner, RunOptions};
 
 fn build_cli() -> Command {
     Command::new("opql")
@@ -12,7 +15,7 @@ fn build_cli() -> Command {
             clap::Arg::new("command")
                 .long("run")
                 .value_name("PQL")
-                .help("run PQL and exit"),
+                .help("run PQL and exit; a range literal '@file' reads the range from file"),
         )
         .arg(
             clap::Arg::new("max_trials")
@@ -28,25 +31,149 @@ fn build_cli() -> Command {
                 .value_parser(clap::value_parser!(usize))
                 .help("number of worker threads (defaults to available cores)"),
         )
+        .arg(
+            clap::Arg::new("seed")
+                .long("seed")
+                .value_name("N")
+                .value_parser(clap::value_parser!(u64))
+                .help("seed for reproducible sampling (overrides the `seed` key in `from`)"),
+        )
+        .arg(
+            clap::Arg::new("precision")
+                .long("precision")
+                .value_name("SE")
+                .value_parser(clap::value_parser!(f64))
+                .help(
+                    "sample until every avg/count standard error is at most SE (--mt caps trials)",
+                ),
+        )
+        .arg(
+            clap::Arg::new("exact")
+                .long("exact")
+                .action(clap::ArgAction::SetTrue)
+                .conflicts_with_all(["max_trials", "precision", "seed"])
+                .help("enumerate every consistent deal instead of sampling"),
+        )
+        .arg(
+            clap::Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .value_parser(PossibleValuesParser::new(OutputFormat::NAMES))
+                .help("output format"),
+        )
         .arg_required_else_help(true)
 }
 
-fn main() {
+fn main() -> ExitCode {
     let matches = build_cli().get_matches();
-    let max_trials = matches.get_one::<usize>("max_trials").copied();
-    let n_threads = matches.get_one::<usize>("n_threads").copied();
+    let opts = RunOptions {
+        max_trials: matches.get_one::<usize>("max_trials").copied(),
+        n_threads: matches.get_one::<usize>("n_threads").copied(),
+        seed: matches.get_one::<u64>("seed").copied(),
+        precision: matches.get_one::<f64>("precision").copied(),
+        exact: matches.get_flag("exact"),
+    };
+    let format = matches
+        .get_one::<String>("format")
+        .map_or_else(OutputFormat::default, |s| s.parse().unwrap());
 
     if let Some(command) = matches.get_one::<String>("command") {
-        run_command(command, max_trials, n_threads);
+        match expand_range_files(command) {
+            Ok(command) => run_command(&command, format, &opts),
+            Err(err) => {
+                eprintln!("{err}");
+
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Replaces every `'@file'` literal with the range read from `file` in any
+/// format of [`ComboRange::from_text`], for the game of its statement.
+fn expand_range_files(command: &str) -> Result<String, String> {
+    let mut expanded = String::new();
+    let mut rest = command;
+
+    while let Some(start) = rest.find("'@") {
+        let Some(len) = rest[start + 2..].find('\'') else {
+            break;
+        };
+        let path = &rest[start + 2..start + 2 + len];
+        let offset = command.len() - rest.len() + start;
+
+        let src = fs::read_to_string(path).map_err(|err| format!("{path}: {err}"))?;
+        let game = statement_game(command, offset);
+        let range = match game {
+            Game::Holdem => ComboRange::<false>::from_text(&src).map(|r| r.to_pql()),
+            Game::ShortDeck => ComboRange::<true>::from_text(&src).map(|r| r.to_pql()),
+            game => {
+                return Err(format!(
+                    "{path}: range files hold Hold'em or Short Deck ranges, not {game:?} ones"
+                ));
+            }
+        }
+        .map_err(|err| describe_range_error(path, &src, game, &err))?;
+
+        expanded.push_str(&rest[..=start]);
+        expanded.push_str(&range);
+        expanded.push('\'');
+        rest = &rest[start + 3 + len..];
+    }
+
+    expanded.push_str(rest);
+
+    Ok(expanded)
+}
+
+/// Game of the statement of `command` around byte `offset`, read from its
+/// parsed `from` clause; Hold'em if the statement does not parse or names
+/// no game, in which case running it reports the problem.
+fn statement_game(command: &str, offset: usize) -> Game {
+    let start = command[..offset].rfind(';').map_or(0, |i| i + 1);
+    let end = command[offset..]
+        .find(';')
+        .map_or(command.len(), |i| offset + i);
+
+    parse_pql(&command[start..end])
+        .ok()
+        .and_then(|stmts| match stmts.first()?.from.get_game()? {
+            FromValue::Str(game) => game.inner.parse().ok(),
+            FromValue::Param(_) => None,
+        })
+        .unwrap_or_default()
+}
+
+/// `path:line:column` of the text `err` points at in the file `src`.
+fn describe_range_error(path: &str, src: &str, game: Game, err: &RangeError) -> String {
+    let (start, end) = LocInfo::from(err);
+    let start = start.min(src.len());
+    let line_start = src[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line = src[..start].matches('\n').count() + 1;
+    let column = src[line_start..start].chars().count() + 1;
+    let text = src.get(start..end).unwrap_or_default().trim();
+
+    let reason = match err {
+        RangeError::InvalidMatrix(_) => "the hand matrix is not a square of 13 (or 9) rows".into(),
+        RangeError::InvalidWeight(_) => "weights must lie between 0 and 1".into(),
+        _ => format!("not a {game:?} hand class, span or combo"),
+    };
+
+    if text.is_empty() {
+        format!("{path}:{line}:{column}: {reason}")
+    } else {
+        format!("{path}:{line}:{column}: {reason}: '{text}'")
     }
 }
 
-fn run_command(command: &str, max_trials: Option<usize>, n_threads: Option<usize>) {
-    let _ = PQLRunner::run(
+fn run_command(command: &str, format: OutputFormat, opts: &RunOptions) {
+    let _ = PQLRunner::run_formatted(
         command,
-        max_trials,
-        n_threads,
+        format,
         &mut io::stdout(),
         &mut io::stderr(),
+        |stmt| PQLRunner::try_run_stmt_with(stmt, opts),
     );
 }
@@ -41,6 +41,9 @@ pub enum PQLErrorKind {
     InvalidPlayer,
     InvalidDeadcards,
     InvalidCardCount,
+    InvalidSeed,
+    UnboundParameter,
+    InvalidParameter,
 
     // Execution
     Internal(InternalError),
@@ -9,4 +9,6 @@ pub enum RuntimeError {
     RequiresFiveCards,
     IntegerRequired,
     ValueRetrievalFailed(PQLType),
+    NoConsistentHands,
+    UnsupportedGame(PQLGame),
 }
@@ -1,4 +1,11 @@
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VmError {
     SamplingFailed,
+    /// the `where` clause rejected nearly every deal
+    WhereClauseRejected,
+    NoConsistentDeals,
+    /// exact mode would walk more than [`VmStaticData::MAX_EXACT_DEALS`] deals
+    TooManyDeals,
+    /// exact mode counts every deal once, so weighted ranges are sampled
+    WeightedRangeInExactMode,
 }
@@ -0,0 +1,9 @@
+use super::*;
+
+#[pqlfn]
+pub fn best_lo_rating(ctx: &PQLFnContext, player: PQLPlayer, street: PQLStreet) -> PQLBoolean {
+    let player_rating = lo_rating(ctx, player, street);
+
+    player_rating.is_made()
+        && (0..ctx.n_players).all(|i| player_rating <= lo_rating(ctx, i.into(), street))
+}
@@ -0,0 +1,6 @@
+use super::*;
+
+#[pqlfn]
+pub fn board_allows_made_lo(ctx: &PQLFnContext, street: PQLStreet) -> PQLBoolean {
+    core::board_allows_made_lo(ctx.get_board(street))
+}
@@ -0,0 +1,6 @@
+use super::*;
+
+#[pqlfn]
+pub fn board_has_one_distinct_lo_card(ctx: &PQLFnContext, street: PQLStreet) -> PQLBoolean {
+    core::board_has_one_distinct_lo_card(ctx.get_board(street))
+}
@@ -0,0 +1,6 @@
+use super::*;
+
+#[pqlfn]
+pub fn board_has_two_distinct_lo_cards(ctx: &PQLFnContext, street: PQLStreet) -> PQLBoolean {
+    core::board_has_two_distinct_lo_cards(ctx.get_board(street))
+}
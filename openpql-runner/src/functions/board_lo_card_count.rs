@@ -0,0 +1,6 @@
+use super::*;
+
+#[pqlfn]
+pub fn board_lo_card_count(ctx: &PQLFnContext, street: PQLStreet) -> PQLCardCount {
+    core::board_lo_card_count(ctx.get_board(street))
+}
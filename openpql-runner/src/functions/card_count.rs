@@ -0,0 +1,6 @@
+use super::*;
+
+#[pqlfn]
+pub const fn card_count(cards: PQLCardSet) -> PQLCardCount {
+    cards.count()
+}
@@ -0,0 +1,96 @@
+use super::*;
+
+/// Fewest hole cards that make the player's best high hand. Omaha hands
+/// always play exactly two.
+#[pqlfn]
+pub fn cards_play(
+    ctx: &PQLFnContext,
+    player: PQLPlayer,
+    street: PQLStreet,
+) -> Result<PQLCardCount, RuntimeError> {
+    const N_HOLDEM_HOLE_CARDS: PQLCardCount = 2;
+
+    if street == PQLStreet::Preflop {
+        return Err(RuntimeError::RequiresFiveCards);
+    }
+
+    if !matches!(ctx.game, PQLGame::Holdem | PQLGame::ShortDeck) {
+        return Ok(N_HOLDEM_HOLE_CARDS);
+    }
+
+    let board = ctx.get_c64_board(street);
+    let rating = ctx.eval_current_rating(player, street);
+    let plays = |hole: PQLCardSet| ctx.game.eval_rating(hole, board) == rating;
+
+    let n_board = ctx.get_board_slice(street).len();
+
+    if n_board == PQLBoard::N_RIVER && plays(PQLCardSet::default()) {
+        Ok(0)
+    } else if n_board + 1 >= PQLBoard::N_RIVER
+        && ctx
+            .get_player_slice(player)
+            .iter()
+            .any(|&card| plays(PQLCardSet::from(card)))
+    {
+        Ok(1)
+    } else {
+        Ok(N_HOLDEM_HOLE_CARDS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn n_played(game: PQLGame, cards: &[PQLCard], street: PQLStreet) -> PQLCardCount {
+        let ctx = TestPQLFnContext::from_cards(game, cards.to_vec());
+
+        cards_play(&ctx.as_ctx(), 0.into(), street).unwrap()
+    }
+
+    #[test]
+    fn test_cards_play() {
+        let g = PQLGame::Holdem;
+        let s = PQLStreet::River;
+
+        assert_eq!(n_played(g, &cards!("2c 3d Ah Kh Qh Jh Th"), s), 0);
+        assert_eq!(n_played(g, &cards!("9s 3d Kh Qh Jc Tc 2d"), s), 1);
+        assert_eq!(n_played(g, &cards!("As Ad Ah Kd 7c 4s 2h"), s), 2);
+        assert_eq!(
+            n_played(g, &cards!("As 3d Ah Kh Qh Jc Tc"), PQLStreet::Flop),
+            2
+        );
+        assert_eq!(
+            n_played(g, &cards!("Ks 3d Ah Kh Qh Jc Tc"), PQLStreet::Turn),
+            1
+        );
+    }
+
+    #[test]
+    fn test_cards_play_omaha() {
+        let cards = cards!("2c 3d 4s 5s Ah Kh Qh Jh Th");
+
+        assert_eq!(n_played(PQLGame::Omaha, &cards, PQLStreet::River), 2);
+        assert_eq!(n_played(PQLGame::Omaha8, &cards, PQLStreet::Flop), 2);
+
+        let cards = cards!("2c 3d 4s 5s 6c Ah Kh Qh Jh Th");
+
+        assert_eq!(n_played(PQLGame::Omaha5, &cards, PQLStreet::River), 2);
+        assert_eq!(n_played(PQLGame::Omaha5HiLo, &cards, PQLStreet::Turn), 2);
+
+        let cards = cards!("2c 3d 4s 5s 6c 7d Ah Kh Qh Jh Th");
+
+        assert_eq!(n_played(PQLGame::Omaha6, &cards, PQLStreet::River), 2);
+    }
+
+    #[test]
+    fn test_cards_play_preflop() {
+        let ctx = TestPQLFnContext::from_cards(PQLGame::Holdem, cards!("2c 3d Ah Kh Qh Jh Th"));
+
+        assert_eq!(
+            cards_play(&ctx.as_ctx(), 0.into(), PQLStreet::Preflop),
+            Err(RuntimeError::RequiresFiveCards)
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use rand::{SeedableRng, rngs::StdRng};
+
 use super::*;
 
 #[derive(Clone, Debug)]
@@ -6,9 +8,30 @@ pub struct PQLFnContext<'vm> {
     pub sampled_cards: &'vm [PQLCard],
     pub n_players: PQLPlayerCount,
     pub cache: &'vm VmCache,
+    /// Ranges of the `from` clause, one per player.
+    pub player_ranges: &'vm [PQLRange],
+    /// Cards of the `dead` clause, out of every deal.
+    pub dead_cards: PQLCardSet,
+    /// Combos of each range avoiding the dead cards, listed once per
+    /// [`Vm`]; empty outside one.
+    pub range_combos: &'vm [RangeCombos],
+    /// Drawn from the trial's generator with each sampled deal and hashed
+    /// from the cards of each enumerated one.
+    pub trial_seed: u64,
+    /// Set by [`Self::trial_rng`]; an enumeration in which it was set is
+    /// not exact.
+    pub drew_cards: &'vm AtomicBool,
 }
 
 impl PQLFnContext<'_> {
+    /// Generator for functions that draw cards of their own, seeded with
+    /// [`Self::trial_seed`] mixed with `salt`.
+    pub fn trial_rng(&self, salt: u64) -> StdRng {
+        self.drew_cards.store(true, Ordering::Relaxed);
+
+        StdRng::seed_from_u64(self.trial_seed ^ salt)
+    }
+
     /// Cached [`PQLGame::eval_rating`]; hits are shared across functions,
     /// trials and threads via [`VmCache`].
     pub fn eval_current_rating(&self, player: PQLPlayer, street: PQLStreet) -> PQLHiRating {
@@ -25,6 +48,17 @@ impl PQLFnContext<'_> {
         self.cache.rating_or_insert_with(self.game, player, board)
     }
 
+    /// Cached [`PQLGame::eval_lo_rating`], sharing hits via [`VmCache`].
+    pub fn eval_current_lo_rating(&self, player: PQLPlayer, street: PQLStreet) -> PQLLoRating {
+        self.eval_lo_rating(self.get_player_slice(player), self.get_board(street))
+    }
+
+    /// Cached low rating for an arbitrary `player`/`board`.
+    pub fn eval_lo_rating(&self, player: &[PQLCard], board: PQLBoard) -> PQLLoRating {
+        self.cache
+            .lo_rating_or_insert_with(self.game, player, board)
+    }
+
     const fn n_holecards(&self) -> PQLCardCount {
         self.game.player_cards_len()
     }
@@ -78,11 +112,15 @@ impl PQLFnContext<'_> {
             PQLGame::Holdem => HandN::<2>::iter_all::<false>()
                 .map(PQLCardSet::from)
                 .collect(),
-            PQLGame::Omaha => HandN::<4>::iter_all::<false>()
+            PQLGame::Omaha | PQLGame::Omaha8 => HandN::<4>::iter_all::<false>()
+                .map(PQLCardSet::from)
+                .collect(),
+
+            PQLGame::Omaha5 | PQLGame::Omaha5HiLo => HandN::<5>::iter_all::<false>()
                 .map(PQLCardSet::from)
                 .collect(),
 
-            PQLGame::Omaha5 => HandN::<5>::iter_all::<false>()
+            PQLGame::Omaha6 => HandN::<6>::iter_all::<false>()
                 .map(PQLCardSet::from)
                 .collect(),
 
@@ -106,6 +144,11 @@ pub mod tests {
                 sampled_cards: PQLCard::all::<true>(),
                 n_players: 2,
                 cache: Box::leak(Box::default()),
+                player_ranges: &[],
+                dead_cards: PQLCardSet::default(),
+                range_combos: &[],
+                trial_seed: 0,
+                drew_cards: Box::leak(Box::default()),
             }
         }
     }
@@ -116,6 +159,7 @@ pub mod tests {
         sampled_cards: Vec<PQLCard>,
         n_players: PQLPlayerCount,
         cache: VmCache,
+        drew_cards: Arc<AtomicBool>,
     }
 
     impl TestPQLFnContext {
@@ -125,6 +169,11 @@ pub mod tests {
                 sampled_cards: &self.sampled_cards,
                 n_players: self.n_players,
                 cache: &self.cache,
+                player_ranges: &[],
+                dead_cards: PQLCardSet::default(),
+                range_combos: &[],
+                trial_seed: 0,
+                drew_cards: &self.drew_cards,
             }
         }
 
@@ -134,6 +183,7 @@ pub mod tests {
                 sampled_cards: cards.to_vec(),
                 n_players: 0,
                 cache: VmCache::default(),
+                drew_cards: Arc::default(),
             }
         }
 
@@ -146,6 +196,7 @@ pub mod tests {
                 sampled_cards: cards,
                 n_players,
                 cache: VmCache::default(),
+                drew_cards: Arc::default(),
             }
         }
     }
@@ -179,6 +230,7 @@ pub mod tests {
                 sampled_cards,
                 n_players,
                 cache: VmCache::default(),
+                drew_cards: Arc::default(),
             }
         }
     }
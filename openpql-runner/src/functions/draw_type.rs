@@ -0,0 +1,10 @@
+use super::*;
+
+#[pqlfn]
+pub fn draw_type(ctx: &PQLFnContext, player: PQLPlayer, street: PQLStreet) -> PQLDrawType {
+    core::draw_type(
+        ctx.game,
+        ctx.get_player_slice(player),
+        ctx.get_board(street),
+    )
+}
@@ -0,0 +1,124 @@
+use std::sync::OnceLock;
+
+use super::*;
+
+/// Position of the player's best high hand among all distinct five-card
+/// hands of the deck, best first: 1 is a royal flush and, in holdem,
+/// 7462 is 7-5-4-3-2.
+#[pqlfn]
+pub fn five_card_hi_hand_number(
+    ctx: &PQLFnContext,
+    player: PQLPlayer,
+    street: PQLStreet,
+) -> Result<PQLLong, RuntimeError> {
+    if street == PQLStreet::Preflop {
+        return Err(RuntimeError::RequiresFiveCards);
+    }
+
+    let rating = ctx.eval_current_rating(player, street);
+    let ratings = five_card_ratings(ctx.game.is_shortdeck());
+    let n_weaker = ratings.partition_point(|&r| r < rating);
+
+    Ok(PQLLong::try_from(ratings.len() - n_weaker).unwrap())
+}
+
+/// Every distinct five-card high rating, weakest first.
+fn five_card_ratings(sd: bool) -> &'static [PQLHiRating] {
+    static HOLDEM: OnceLock<Vec<PQLHiRating>> = OnceLock::new();
+    static SHORTDECK: OnceLock<Vec<PQLHiRating>> = OnceLock::new();
+
+    if sd {
+        SHORTDECK.get_or_init(|| collect_ratings(PQLGame::ShortDeck, PQLRank::all::<true>()))
+    } else {
+        HOLDEM.get_or_init(|| collect_ratings(PQLGame::Holdem, PQLRank::all::<false>()))
+    }
+}
+
+/// Rates one offsuit hand per rank multiset, plus the flush of every five
+/// distinct ranks; the suits of the other hands do not change the rating.
+fn collect_ratings(game: PQLGame, ranks: &[PQLRank]) -> Vec<PQLHiRating> {
+    fn rec(
+        game: PQLGame,
+        ranks: &[PQLRank],
+        start: usize,
+        hand: &mut Vec<PQLRank>,
+        res: &mut Vec<PQLHiRating>,
+    ) {
+        if hand.len() == PQLBoard::N_RIVER {
+            // equal ranks are adjacent, so cycling the suits keeps cards
+            // distinct and never makes a flush
+            let offsuit: PQLCardSet = hand
+                .iter()
+                .zip(PQLSuit::ARR_ALL.iter().cycle())
+                .map(|(&r, &s)| PQLCard::new(r, s))
+                .collect();
+            res.push(game.eval_rating(offsuit, PQLCardSet::default()));
+
+            if hand.windows(2).all(|w| w[0] != w[1]) {
+                let suited: PQLCardSet =
+                    hand.iter().map(|&r| PQLCard::new(r, PQLSuit::S)).collect();
+                res.push(game.eval_rating(suited, PQLCardSet::default()));
+            }
+
+            return;
+        }
+
+        for i in start..ranks.len() {
+            if hand.iter().filter(|&&r| r == ranks[i]).count() < PQLSuit::ARR_ALL.len() {
+                hand.push(ranks[i]);
+                rec(game, ranks, i, hand, res);
+                hand.pop();
+            }
+        }
+    }
+
+    let mut res = vec![];
+    rec(game, ranks, 0, &mut vec![], &mut res);
+
+    res.sort_unstable();
+    res.dedup();
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn number(game: PQLGame, cards: &[PQLCard]) -> PQLLong {
+        let ctx = TestPQLFnContext::from_cards(game, cards.to_vec());
+
+        five_card_hi_hand_number(&ctx.as_ctx(), 0.into(), PQLStreet::River).unwrap()
+    }
+
+    #[test]
+    fn test_five_card_hi_hand_number() {
+        let g = PQLGame::Holdem;
+
+        assert_eq!(five_card_ratings(false).len(), 7462);
+        assert_eq!(number(g, &cards!("Ah Kh Qh Jh Th 2c 3d")), 1);
+        assert_eq!(number(g, &cards!("Kh Qh Jh Th 9h 2c 3d")), 2);
+        assert_eq!(number(g, &cards!("As Ad Ah Ac Ks 2c 3d")), 11);
+        assert_eq!(number(g, &cards!("As Ad Ah Kc Ks 2c 3d")), 167);
+    }
+
+    #[test]
+    fn test_five_card_hi_hand_number_sd() {
+        let g = PQLGame::ShortDeck;
+
+        assert_eq!(number(g, &cards!("Ah Kh Qh Jh Th 6c 7d")), 1);
+        // A-6-7-8-9 is the lowest straight flush
+        assert_eq!(number(g, &cards!("9h 8h 7h 6h Ah Kc Qd")), 6);
+    }
+
+    #[test]
+    fn test_five_card_hi_hand_number_preflop() {
+        let ctx = TestPQLFnContext::from_cards(PQLGame::Holdem, cards!("Ah Kh Qh Jh Th 2c 3d"));
+
+        assert_eq!(
+            five_card_hi_hand_number(&ctx.as_ctx(), 0.into(), PQLStreet::Preflop),
+            Err(RuntimeError::RequiresFiveCards)
+        );
+    }
+}
@@ -0,0 +1,6 @@
+use super::*;
+
+#[pqlfn]
+pub fn four_flush(ctx: &PQLFnContext, street: PQLStreet) -> PQLBoolean {
+    core::four_flush(ctx.get_board(street))
+}
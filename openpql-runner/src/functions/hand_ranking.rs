@@ -0,0 +1,94 @@
+use super::*;
+
+/// Share of all holdings that avoid the board which the player's hand
+/// beats, ties counting half: close to 1 for the nuts, 0 for the worst
+/// holding. Only hold'em and short deck, whose 1,081 or fewer holdings are
+/// quick to rate on every trial; Omaha games have millions.
+#[pqlfn]
+pub fn hand_ranking(
+    ctx: &PQLFnContext,
+    player: PQLPlayer,
+    street: PQLStreet,
+) -> Result<PQLFraction, RuntimeError> {
+    if street == PQLStreet::Preflop {
+        return Err(RuntimeError::RequiresFiveCards);
+    }
+
+    let board = ctx.get_c64_board(street);
+    let rating = ctx.eval_current_rating(player, street);
+
+    match ctx.game {
+        PQLGame::Holdem => Ok(rank_among::<false>(ctx.game, board, rating)),
+        PQLGame::ShortDeck => Ok(rank_among::<true>(ctx.game, board, rating)),
+        game => Err(RuntimeError::UnsupportedGame(game)),
+    }
+}
+
+fn rank_among<const SD: bool>(
+    game: PQLGame,
+    board: PQLCardSet,
+    rating: PQLHiRating,
+) -> PQLFraction {
+    let (mut n, mut score) = (0, 0);
+
+    for hand in HandN::<2>::iter_all::<SD>().with_dead(board) {
+        n += 1;
+        score += match game.eval_rating(hand.as_slice().into(), board).cmp(&rating) {
+            cmp::Ordering::Less => 2,
+            cmp::Ordering::Equal => 1,
+            cmp::Ordering::Greater => 0,
+        };
+    }
+
+    PQLFraction::new(score, 2 * n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn ranking(cards: &[PQLCard], street: PQLStreet) -> Result<PQLFraction, RuntimeError> {
+        let ctx = TestPQLFnContext::from_cards(PQLGame::Holdem, cards.to_vec());
+
+        hand_ranking(&ctx.as_ctx(), 0.into(), street)
+    }
+
+    #[test]
+    fn test_hand_ranking() {
+        // royal flush is the unique nuts
+        let nuts = ranking(&cards!("Ah Kh 2c 3c Qh Jh Th 4d 5s"), PQLStreet::Flop).unwrap();
+        assert_eq!(nuts, PQLFraction::new(2351, 2352));
+
+        // the board plays: every holding ties
+        let board = ranking(&cards!("2c 3d 2s 3s Ah Kh Qh Jh Th"), PQLStreet::River).unwrap();
+        assert_eq!(board, PQLFraction::new(1081, 2162));
+
+        let pair = ranking(&cards!("Ah 7d 2c 3c As 9c 4h 5s Kd"), PQLStreet::Flop)
+            .unwrap()
+            .to_double();
+        let air = ranking(&cards!("2h 3d Ac Kc As 9c 4h 5s Kd"), PQLStreet::Flop)
+            .unwrap()
+            .to_double();
+        assert!(0.0 < air && air < pair && pair < 1.0);
+    }
+
+    #[test]
+    fn test_hand_ranking_omaha() {
+        let ctx =
+            TestPQLFnContext::from_cards(PQLGame::Omaha, cards!("Ah Kh 2c 3c Qh Jh Th 4d 5s"));
+
+        assert_eq!(
+            hand_ranking(&ctx.as_ctx(), 0.into(), PQLStreet::Flop),
+            Err(RuntimeError::UnsupportedGame(PQLGame::Omaha))
+        );
+    }
+
+    #[test]
+    fn test_hand_ranking_preflop() {
+        assert_eq!(
+            ranking(&cards!("Ah Kh 2c 3c Qh Jh Th 4d 5s"), PQLStreet::Preflop),
+            Err(RuntimeError::RequiresFiveCards)
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use super::*;
+
+#[pqlfn]
+pub fn has_draw(
+    ctx: &PQLFnContext,
+    player: PQLPlayer,
+    street: PQLStreet,
+    draw: PQLDrawType,
+) -> PQLBoolean {
+    core::has_draw(
+        ctx.game,
+        ctx.get_player_slice(player),
+        ctx.get_board(street),
+        draw,
+    )
+}
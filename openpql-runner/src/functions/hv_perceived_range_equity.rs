@@ -0,0 +1,13 @@
+use super::*;
+
+/// Like [`hvrequity`], but every opponent holds `range` instead of its
+/// range from the `from` clause.
+#[pqlfn]
+pub fn hv_perceived_range_equity(
+    ctx: &PQLFnContext,
+    hero: PQLPlayer,
+    street: PQLStreet,
+    range: &PQLRange,
+) -> Result<PQLEquity, RuntimeError> {
+    range_equity(ctx, hero, street, |_| range)
+}
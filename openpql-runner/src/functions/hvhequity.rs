@@ -1,13 +1,103 @@
 use super::*;
 
+/// Boards drawn per preflop equity that is not enumerated.
+pub const N_PREFLOP_BOARDS: usize = 1000;
+
 #[pqlfn(alias = "equity")]
-pub fn hvhequity(ctx: &PQLFnContext, hero: PQLPlayer, street: PQLStreet) -> PQLEquity {
+pub fn hvhequity(
+    ctx: &PQLFnContext,
+    hero: PQLPlayer,
+    street: PQLStreet,
+) -> Result<PQLEquity, RuntimeError> {
     match street {
-        PQLStreet::Preflop => unreachable!(),
-        PQLStreet::Flop => flop_equity(ctx, hero),
-        PQLStreet::Turn => turn_equity(ctx, hero),
-        PQLStreet::River => river_equity(ctx, hero),
+        PQLStreet::Preflop => preflop_equity(ctx, hero),
+        PQLStreet::Flop => Ok(flop_equity(ctx, hero)),
+        PQLStreet::Turn => Ok(turn_equity(ctx, hero)),
+        PQLStreet::River => Ok(river_equity(ctx, hero)),
+    }
+}
+
+/// Heads-up hold'em without dead cards looks the matchup up in the shipped
+/// table of [`holdem_preflop_equity`]; heads-up short deck without dead
+/// cards enumerates the 201,376 boards that avoid the hands. Both are kept
+/// in [`VmCache`] per suit-isomorphic matchup.
+///
+/// Dead cards make the matchups too many to enumerate each one, so those
+/// spots, other games and multiway pots average [`N_PREFLOP_BOARDS`] boards
+/// drawn with [`PQLFnContext::trial_rng`]; their sampling error shows up in
+/// the standard error of the trials.
+pub fn preflop_equity(ctx: &PQLFnContext, hero: PQLPlayer) -> Result<PQLEquity, RuntimeError> {
+    let is_shortdeck = ctx.game.is_shortdeck();
+
+    if ctx.n_players == 2 && ctx.game.player_cards_len() == 2 && ctx.dead_cards.is_empty() {
+        let villain = hero.iter_opponents(ctx.n_players).next().unwrap();
+        let key = MatchupKey::new(
+            ctx.get_player_slice(hero),
+            ctx.get_player_slice(villain),
+            ctx.dead_cards,
+        );
+
+        ctx.cache.preflop_equity_or_try_insert_with(key, || {
+            if is_shortdeck {
+                enumerate_preflop::<true>(ctx, hero)
+            } else {
+                Ok(holdem_preflop_equity(
+                    ctx.get_player_slice(hero),
+                    ctx.get_player_slice(villain),
+                ))
+            }
+        })
+    } else if is_shortdeck {
+        sample_preflop::<true>(ctx, hero)
+    } else {
+        sample_preflop::<false>(ctx, hero)
+    }
+}
+
+fn enumerate_preflop<const SD: bool>(
+    ctx: &PQLFnContext,
+    hero: PQLPlayer,
+) -> Result<PQLEquity, RuntimeError> {
+    let mut sum = 0.0;
+    let mut count = 0.0;
+
+    for board in HandN::<5>::iter_all::<SD>().with_dead(ctx.get_c64_players() | ctx.dead_cards) {
+        count += 1.0;
+        sum += uncached_pot_share(ctx, hero, board.as_slice().into()).to_double();
+    }
+
+    if count == 0.0 {
+        return Err(RuntimeError::NoConsistentHands);
     }
+
+    Ok(sum / count)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn sample_preflop<const SD: bool>(
+    ctx: &PQLFnContext,
+    hero: PQLPlayer,
+) -> Result<PQLEquity, RuntimeError> {
+    let used = ctx.get_c64_players() | ctx.dead_cards;
+    let mut rng = ctx.trial_rng(u64::from(used));
+    let mut card_gen = CardGen::new::<SD>(used);
+    let mut cards = [PQLCard::default(); PQLBoard::N_RIVER];
+
+    let mut sum = 0.0;
+
+    for _ in 0..N_PREFLOP_BOARDS {
+        card_gen.reset();
+
+        for card in &mut cards {
+            *card = card_gen
+                .deal(&mut rng)
+                .ok_or(RuntimeError::NoConsistentHands)?;
+        }
+
+        sum += uncached_pot_share(ctx, hero, cards.as_slice().into()).to_double();
+    }
+
+    Ok(sum / N_PREFLOP_BOARDS as PQLEquity)
 }
 
 // TODO: tmp; optimize
@@ -23,7 +113,7 @@ pub fn turn_equity(ctx: &PQLFnContext, hero: PQLPlayer) -> PQLEquity {
     let mut sum = 0.0;
     let mut count = 0.0;
 
-    let player_cards = ctx.get_c64_players();
+    let player_cards = ctx.get_c64_players() | ctx.dead_cards;
 
     for &river in all {
         if turn.contains_card(river) || player_cards.contains_card(river) {
@@ -32,19 +122,8 @@ pub fn turn_equity(ctx: &PQLFnContext, hero: PQLPlayer) -> PQLEquity {
 
         let board = turn.with_river(river);
 
-        let ratings: PerPlayer<_> = PQLPlayer::iter(ctx.n_players)
-            .map(|player| ctx.eval_rating(ctx.get_player_slice(player), board))
-            .collect();
-
-        let max = *ratings.iter().max().unwrap();
-
         count += 1.0;
-
-        if ratings[usize::from(hero)] == max {
-            let n_winners = ratings.iter().filter(|&r| *r == max).count();
-
-            sum += PQLFraction::new(1, FractionInner::try_from(n_winners).unwrap()).to_double();
-        }
+        sum += pot_share(ctx, hero, board).to_double();
     }
 
     sum / count
@@ -62,7 +141,7 @@ pub fn flop_equity(ctx: &PQLFnContext, hero: PQLPlayer) -> PQLEquity {
         let mut sum = 0.0;
         let mut count = 0.0;
 
-        let player_cards = ctx.get_c64_players();
+        let player_cards = ctx.get_c64_players() | ctx.dead_cards;
 
         for turn_river in iter {
             let turn = turn_river[0];
@@ -78,19 +157,8 @@ pub fn flop_equity(ctx: &PQLFnContext, hero: PQLPlayer) -> PQLEquity {
 
             let board = flop.with_turn(turn).with_river(river);
 
-            let ratings: PerPlayer<_> = PQLPlayer::iter(ctx.n_players)
-                .map(|player| ctx.eval_rating(ctx.get_player_slice(player), board))
-                .collect();
-
-            let max = *ratings.iter().max().unwrap();
-
             count += 1.0;
-
-            if ratings[usize::from(hero)] == max {
-                let n_winners = ratings.iter().filter(|&r| *r == max).count();
-
-                sum += PQLFraction::new(1, FractionInner::try_from(n_winners).unwrap()).to_double();
-            }
+            sum += pot_share(ctx, hero, board).to_double();
         }
 
         sum / count
@@ -102,3 +170,64 @@ pub fn flop_equity(ctx: &PQLFnContext, hero: PQLPlayer) -> PQLEquity {
         inner(ctx, hero, HandN::<2>::iter_all::<false>())
     }
 }
+
+/// Hero's share of the pot on a complete `board`, split by
+/// [`core::pot_share_by`] with ratings from [`VmCache`].
+fn pot_share(ctx: &PQLFnContext, hero: PQLPlayer, board: PQLBoard) -> PQLFraction {
+    let n_cards = ctx.game.player_cards_len();
+    let idx_board = PQLFnContext::idx_board_start(ctx.n_players, n_cards);
+
+    core::pot_share_by(
+        ctx.game,
+        ctx.sampled_cards[..idx_board].chunks_exact(n_cards as usize),
+        hero.into(),
+        |player| ctx.eval_rating(player, board),
+        |player| ctx.eval_lo_rating(player, board),
+    )
+}
+
+/// Same as [`pot_share`] but bypasses [`VmCache`]: boards of a preflop
+/// enumeration are rarely seen twice.
+pub fn uncached_pot_share(ctx: &PQLFnContext, hero: PQLPlayer, board: PQLBoard) -> PQLFraction {
+    let idx_board = PQLFnContext::idx_board_start(ctx.n_players, ctx.game.player_cards_len());
+
+    core::fractional_river_equity(
+        ctx.game,
+        board,
+        &ctx.sampled_cards[..idx_board],
+        hero.into(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_preflop_equity_without_boards() {
+        let cards = cards!("AhAs KhKs 2c3d4s7h8c");
+        let mut ctx = PQLFnContext {
+            game: PQLGame::Holdem,
+            sampled_cards: &cards,
+            n_players: 2,
+            cache: &VmCache::default(),
+            player_ranges: &[],
+            dead_cards: PQLCardSet::all::<false>(),
+            range_combos: &[],
+            trial_seed: 0,
+            drew_cards: &AtomicBool::default(),
+        };
+
+        assert_eq!(
+            hvhequity(&ctx, 0.into(), PQLStreet::Preflop),
+            Err(RuntimeError::NoConsistentHands)
+        );
+
+        ctx.n_players = 3;
+        assert_eq!(
+            hvhequity(&ctx, 0.into(), PQLStreet::Preflop),
+            Err(RuntimeError::NoConsistentHands)
+        );
+    }
+}
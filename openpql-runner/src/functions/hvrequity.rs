@@ -0,0 +1,329 @@
+use rand::RngExt;
+
+use super::*;
+
+/// Draws of opponent hands per sampled runout before giving up on hands
+/// that keep colliding with each other.
+const MAX_REDRAWS: usize = 1000;
+
+/// Runouts one call walks over every combination of opponent hands before
+/// it samples them instead: a hundred times the [`N_PREFLOP_BOARDS`]
+/// runouts of a sampled call.
+const MAX_ENUMERATED_RUNOUTS: usize = 100 * N_PREFLOP_BOARDS;
+
+/// Opponent seat as the start of its hole cards, with the candidate hands
+/// and the range that weights them.
+type Seat<'a> = (usize, Vec<&'a [PQLCard]>, &'a PQLRange);
+
+#[pqlfn]
+pub fn hvrequity(
+    ctx: &PQLFnContext,
+    hero: PQLPlayer,
+    street: PQLStreet,
+) -> Result<PQLEquity, RuntimeError> {
+    range_equity(ctx, hero, street, |villain| {
+        &ctx.player_ranges[usize::from(villain)]
+    })
+}
+
+/// Hero's equity on `street` against opponent hands taken from
+/// `range_of` instead of the dealt ones, averaged over every combination of
+/// hands that avoids hero's hand, the board of `street` and each other,
+/// each weighted by the product of the range weights of its hands.
+///
+/// Heads-up hold'em preflop without dead cards averages the table of
+/// [`holdem_preflop_equity`] over the combos. Other preflop spots, and
+/// streets whose combinations times runouts exceed
+/// [`MAX_ENUMERATED_RUNOUTS`], average [`N_PREFLOP_BOARDS`] combinations
+/// and runouts drawn with [`PQLFnContext::trial_rng`].
+///
+/// The hands of a range come from [`PQLFnContext::range_combos`] when it is
+/// one of the players' listed ranges and from [`VmCache::range_hands`]
+/// otherwise, so each range is listed once per statement.
+pub fn range_equity<'r, F>(
+    ctx: &PQLFnContext,
+    hero: PQLPlayer,
+    street: PQLStreet,
+    range_of: F,
+) -> Result<PQLEquity, RuntimeError>
+where
+    F: Fn(PQLPlayer) -> &'r PQLRange,
+{
+    let n = ctx.game.player_cards_len() as usize;
+    let dead = ctx.get_c64_player(hero) | ctx.get_c64_board(street) | ctx.dead_cards;
+
+    let ranges: Vec<_> = hero
+        .iter_opponents(ctx.n_players)
+        .map(|villain| (usize::from(villain) * n, range_of(villain)))
+        .collect();
+    let unlisted: Vec<_> = ranges
+        .iter()
+        .map(|(_, range)| {
+            listed_hands(ctx, range)
+                .is_none()
+                .then(|| ctx.cache.range_hands(range, ctx.dead_cards))
+        })
+        .collect();
+    let seats: Vec<Seat> = ranges
+        .iter()
+        .zip(&unlisted)
+        .map(|(&(start, range), unlisted)| {
+            let hands = unlisted
+                .as_deref()
+                .or_else(|| listed_hands(ctx, range))
+                .unwrap()
+                .iter()
+                .filter(|hand| (PQLCardSet::from(hand.as_slice()) & dead).is_empty())
+                .map(Vec::as_slice)
+                .collect();
+
+            (start, hands, range)
+        })
+        .collect();
+
+    if seats.iter().any(|(_, hands, _)| hands.is_empty()) {
+        return Err(RuntimeError::NoConsistentHands);
+    }
+
+    let mut cards = ctx.sampled_cards.to_vec();
+
+    let is_enumerated = if street == PQLStreet::Preflop {
+        ctx.game == PQLGame::Holdem && ctx.n_players == 2 && ctx.dead_cards.is_empty()
+    } else {
+        let n_runouts = n_runouts(ctx.game, street);
+        let cap = MAX_ENUMERATED_RUNOUTS / n_runouts + 1;
+
+        count_combinations(&seats, dead, cap).saturating_mul(n_runouts) <= MAX_ENUMERATED_RUNOUTS
+    };
+
+    let (sum, count) = if is_enumerated {
+        let mut acc = (0.0, 0.0);
+        enumerate(ctx, hero, street, &seats, (dead, 1.0), &mut cards, &mut acc)?;
+        acc
+    } else if ctx.game.is_shortdeck() {
+        sample::<true>(ctx, hero, street, &seats, dead, &mut cards)?
+    } else {
+        sample::<false>(ctx, hero, street, &seats, dead, &mut cards)?
+    };
+
+    if count == 0.0 {
+        return Err(RuntimeError::NoConsistentHands);
+    }
+
+    Ok(sum / count)
+}
+
+/// Combos of the player whose range `range` is, listed with the [`Vm`].
+fn listed_hands<'c>(ctx: &'c PQLFnContext, range: &PQLRange) -> Option<&'c Hands> {
+    let idx = ctx
+        .player_ranges
+        .iter()
+        .position(|player_range| player_range.source() == range.source())?;
+
+    ctx.range_combos.get(idx).and_then(RangeCombos::hands)
+}
+
+/// Runouts [`hvhequity`] walks from `street` on: every pair of cards on the
+/// flop, every card on the turn.
+const fn n_runouts(game: PQLGame, street: PQLStreet) -> usize {
+    let n_cards = if game.is_shortdeck() {
+        PQLCard::all::<true>().len()
+    } else {
+        PQLCard::all::<false>().len()
+    };
+
+    match street {
+        PQLStreet::Flop => n_cards * (n_cards - 1) / 2,
+        PQLStreet::Turn => n_cards,
+        PQLStreet::Preflop | PQLStreet::River => 1,
+    }
+}
+
+/// Combinations of the hands of `seats` that avoid `used` and each other,
+/// counted up to `cap`.
+fn count_combinations(seats: &[Seat], used: PQLCardSet, cap: usize) -> usize {
+    let Some(((_, hands, _), rest)) = seats.split_first() else {
+        return 1;
+    };
+
+    let mut n = 0;
+
+    for &hand in hands {
+        let hand = PQLCardSet::from(hand);
+
+        if (hand & used).is_empty() {
+            n += count_combinations(rest, used | hand, cap - n);
+
+            if n >= cap {
+                break;
+            }
+        }
+    }
+
+    n
+}
+
+fn place(cards: &mut [PQLCard], start: usize, hand: &[PQLCard]) {
+    cards[start..start + hand.len()].copy_from_slice(hand);
+}
+
+/// Deals the remaining `seats` on top of the `used` cards of the hands
+/// placed so far, whose weights multiply to `weight`.
+fn enumerate(
+    ctx: &PQLFnContext,
+    hero: PQLPlayer,
+    street: PQLStreet,
+    seats: &[Seat],
+    (used, weight): (PQLCardSet, PQLEquity),
+    cards: &mut Vec<PQLCard>,
+    acc: &mut (PQLEquity, PQLEquity),
+) -> Result<(), RuntimeError> {
+    let Some(((start, hands, range), rest)) = seats.split_first() else {
+        let ctx = PQLFnContext {
+            sampled_cards: cards,
+            ..ctx.clone()
+        };
+
+        acc.0 = weight.mul_add(hvhequity(&ctx, hero, street)?, acc.0);
+        acc.1 += weight;
+
+        return Ok(());
+    };
+
+    for &hand in hands {
+        let hand_set = PQLCardSet::from(hand);
+
+        if (hand_set & used).is_empty() {
+            place(cards, *start, hand);
+            let dealt = (used | hand_set, weight * range.weight(hand));
+            enumerate(ctx, hero, street, rest, dealt, cards, acc)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Deals the `seats` and the board cards after `street` at random, so the
+/// sampling error of each trial shows up in its standard error.
+fn sample<const SD: bool>(
+    ctx: &PQLFnContext,
+    hero: PQLPlayer,
+    street: PQLStreet,
+    seats: &[Seat],
+    dead: PQLCardSet,
+    cards: &mut [PQLCard],
+) -> Result<(PQLEquity, PQLEquity), RuntimeError> {
+    let mut rng = ctx.trial_rng(u64::from(dead));
+    let runout_start = PQLFnContext::idx_board_start(ctx.n_players, ctx.game.player_cards_len())
+        + street.board_card_count() as usize;
+
+    let (mut sum, mut total) = (0.0, 0.0);
+
+    for _ in 0..N_PREFLOP_BOARDS {
+        let (used, weight) = (0..MAX_REDRAWS)
+            .find_map(|_| draw_hands(&mut rng, seats, dead, cards))
+            .ok_or(RuntimeError::NoConsistentHands)?;
+
+        let mut card_gen = CardGen::new::<SD>(used);
+        for card in &mut cards[runout_start..] {
+            *card = card_gen
+                .deal(&mut rng)
+                .ok_or(RuntimeError::NoConsistentHands)?;
+        }
+
+        let ctx = PQLFnContext {
+            sampled_cards: cards,
+            ..ctx.clone()
+        };
+
+        let share = uncached_pot_share(&ctx, hero, ctx.get_board(PQLStreet::River));
+        sum = weight.mul_add(share.to_double(), sum);
+        total += weight;
+    }
+
+    Ok((sum, total))
+}
+
+/// Places a random hand for every seat and returns the used cards with the
+/// product of the hands' weights; `None` when two of them collide.
+fn draw_hands(
+    rng: &mut impl RngExt,
+    seats: &[Seat],
+    dead: PQLCardSet,
+    cards: &mut [PQLCard],
+) -> Option<(PQLCardSet, PQLEquity)> {
+    seats
+        .iter()
+        .try_fold((dead, 1.0), |(used, weight), (start, hands, range)| {
+            let hand = hands[rng.random_range(0..hands.len())];
+            let hand_set = PQLCardSet::from(hand);
+
+            (hand_set & used).is_empty().then(|| {
+                place(cards, *start, hand);
+                (used | hand_set, weight * range.weight(hand))
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_range_equity_matches_hvhequity_for_one_hand() {
+        let game = PQLGame::Holdem;
+        let cards = cards!("AhAs KhKs 2c3d4s7h8c");
+        let (ranges, _) = mk_ranges(game, &["*", "KhKs"], "*");
+        let ctx = PQLFnContext {
+            game,
+            sampled_cards: &cards,
+            n_players: 2,
+            cache: &VmCache::default(),
+            player_ranges: &ranges,
+            dead_cards: PQLCardSet::default(),
+            range_combos: &[],
+            trial_seed: 0,
+            drew_cards: &AtomicBool::default(),
+        };
+
+        for street in [PQLStreet::Flop, PQLStreet::Turn, PQLStreet::River] {
+            assert_eq!(
+                hvrequity(&ctx, 0.into(), street).map(PQLEquity::to_bits),
+                hvhequity(&ctx, 0.into(), street).map(PQLEquity::to_bits)
+            );
+        }
+    }
+
+    #[test]
+    fn test_range_equity_blocked_hands() {
+        let game = PQLGame::Holdem;
+        let (ranges, _) = mk_ranges(game, &["*", "AA"], "*");
+        let cache = VmCache::default();
+        let drew_cards = AtomicBool::default();
+        let mk_ctx = |cards| PQLFnContext {
+            game,
+            sampled_cards: cards,
+            n_players: 2,
+            cache: &cache,
+            player_ranges: &ranges,
+            dead_cards: PQLCardSet::default(),
+            range_combos: &[],
+            trial_seed: 0,
+            drew_cards: &drew_cards,
+        };
+
+        // only AdAc remains
+        let cards = cards!("AhAs KhKs 2c3d4s7h8c");
+        assert_eq!(
+            hvrequity(&mk_ctx(&cards), 0.into(), PQLStreet::River),
+            Ok(0.5)
+        );
+
+        let cards = cards!("AhAs KhKs AdKd3d7h8c");
+        assert_eq!(
+            hvrequity(&mk_ctx(&cards), 0.into(), PQLStreet::Flop),
+            Err(RuntimeError::NoConsistentHands)
+        );
+    }
+}
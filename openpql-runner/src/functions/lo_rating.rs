@@ -0,0 +1,6 @@
+use super::*;
+
+#[pqlfn]
+pub fn lo_rating(ctx: &PQLFnContext, player: PQLPlayer, street: PQLStreet) -> PQLLoRating {
+    ctx.eval_current_lo_rating(player, street)
+}
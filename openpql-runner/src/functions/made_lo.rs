@@ -0,0 +1,6 @@
+use super::*;
+
+#[pqlfn]
+pub fn made_lo(ctx: &PQLFnContext, player: PQLPlayer, street: PQLStreet) -> PQLBoolean {
+    lo_rating(ctx, player, street).is_made()
+}
@@ -0,0 +1,12 @@
+use super::*;
+
+#[pqlfn]
+pub fn min_hv_perceived_range_equity(
+    ctx: &PQLFnContext,
+    hero: PQLPlayer,
+    street: PQLStreet,
+    range: &PQLRange,
+    equity: PQLEquity,
+) -> Result<PQLBoolean, RuntimeError> {
+    hv_perceived_range_equity(ctx, hero, street, range).map(|e| e >= equity)
+}
@@ -6,6 +6,6 @@ pub fn min_hvhequity(
     hero: PQLPlayer,
     street: PQLStreet,
     equity: PQLEquity,
-) -> PQLBoolean {
-    hvhequity(ctx, hero, street) >= equity
+) -> Result<PQLBoolean, RuntimeError> {
+    Ok(hvhequity(ctx, hero, street)? >= equity)
 }
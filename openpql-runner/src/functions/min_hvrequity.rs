@@ -0,0 +1,11 @@
+use super::*;
+
+#[pqlfn]
+pub fn min_hvrequity(
+    ctx: &PQLFnContext,
+    hero: PQLPlayer,
+    street: PQLStreet,
+    equity: PQLEquity,
+) -> Result<PQLBoolean, RuntimeError> {
+    hvrequity(ctx, hero, street).map(|e| e >= equity)
+}
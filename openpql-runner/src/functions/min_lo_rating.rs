@@ -0,0 +1,16 @@
+use super::*;
+
+#[pqlfn]
+pub fn min_lo_rating(
+    ctx: &PQLFnContext,
+    player: PQLPlayer,
+    street: PQLStreet,
+    rating: PQLLoRating,
+) -> PQLBoolean {
+    core::min_lo_rating(
+        ctx.game,
+        ctx.get_player_slice(player),
+        ctx.get_board(street),
+        rating,
+    )
+}
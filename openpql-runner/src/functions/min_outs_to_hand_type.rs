@@ -0,0 +1,12 @@
+use super::*;
+
+#[pqlfn]
+pub fn min_outs_to_hand_type(
+    ctx: &PQLFnContext,
+    player: PQLPlayer,
+    street: PQLStreet,
+    ht: PQLHandType,
+    min: PQLCardCount,
+) -> PQLBoolean {
+    outs_to_hand_type(ctx, player, street, ht) >= min
+}
@@ -3,32 +3,52 @@ use openpql_core::functions as core;
 use super::*;
 
 mod best_hi_rating;
+mod best_lo_rating;
+mod board_allows_made_lo;
+mod board_has_one_distinct_lo_card;
+mod board_has_two_distinct_lo_cards;
 mod board_in_range;
+mod board_lo_card_count;
 mod board_ranks;
 mod board_suit_count;
+mod card_count;
+mod cards_play;
 mod context;
+mod draw_type;
 mod duplicated_board_ranks;
 mod duplicated_hand_ranks;
 mod exact_flop_hand_category;
 mod exact_hand_type;
+mod five_card_hi_hand_number;
 mod flop_hand_category;
 mod flushing_board;
+mod four_flush;
 mod fractional_river_equity;
 mod hand_board_intersections;
+mod hand_ranking;
 mod hand_ranks;
 mod hand_type;
+mod has_draw;
 mod has_second_board_rank;
 mod has_top_board_rank;
 mod hi_rating;
+mod hv_perceived_range_equity;
 mod hvhequity;
+mod hvrequity;
 mod in_range;
 mod intersecting_hand_ranks;
+mod lo_rating;
+mod made_lo;
 mod max_hi_rating;
 mod max_rank;
 mod min_flop_hand_category;
 mod min_hand_type;
 mod min_hi_rating;
+mod min_hv_perceived_range_equity;
 mod min_hvhequity;
+mod min_hvrequity;
+mod min_lo_rating;
+mod min_outs_to_hand_type;
 mod min_rank;
 mod monotone_board;
 mod nonintersecting_hand_ranks;
@@ -36,53 +56,83 @@ mod nth_rank;
 mod nut_hi;
 mod nut_hi_for_hand_type;
 mod nut_hi_outs;
+mod nut_lo;
+mod nut_lo_outs;
+mod outs;
+mod outs_to_hand_type;
 mod overpair;
 mod paired_board;
 mod pocket_pair;
+mod preflop_table;
 mod rainbow_board;
 mod rank_count;
 mod rate_hi_hand;
+mod rate_lo_hand;
 mod river_card;
 mod river_equity;
 mod scoops;
 mod straight_board;
+mod three_flush;
 mod ties_hi;
+mod ties_lo;
 mod to_card;
 mod to_rank;
+mod to_string;
 mod turn_card;
 mod twotone_board;
 mod winning_hand_type;
 mod wins_hi;
+mod wins_lo;
 
 pub use best_hi_rating::*;
+pub use best_lo_rating::*;
+pub use board_allows_made_lo::*;
+pub use board_has_one_distinct_lo_card::*;
+pub use board_has_two_distinct_lo_cards::*;
 pub use board_in_range::*;
+pub use board_lo_card_count::*;
 pub use board_ranks::*;
 pub use board_suit_count::*;
+pub use card_count::*;
+pub use cards_play::*;
 #[cfg(test)]
 pub use context::tests::TestPQLFnContext;
 pub use context::*;
+pub use draw_type::*;
 pub use duplicated_board_ranks::*;
 pub use duplicated_hand_ranks::*;
 pub use exact_flop_hand_category::*;
 pub use exact_hand_type::*;
+pub use five_card_hi_hand_number::*;
 pub use flop_hand_category::*;
 pub use flushing_board::*;
+pub use four_flush::*;
 pub use fractional_river_equity::*;
 pub use hand_board_intersections::*;
+pub use hand_ranking::*;
 pub use hand_ranks::*;
 pub use hand_type::*;
+pub use has_draw::*;
 pub use has_second_board_rank::*;
 pub use has_top_board_rank::*;
 pub use hi_rating::*;
+pub use hv_perceived_range_equity::*;
 pub use hvhequity::*;
+pub use hvrequity::*;
 pub use in_range::*;
 pub use intersecting_hand_ranks::*;
+pub use lo_rating::*;
+pub use made_lo::*;
 pub use max_hi_rating::*;
 pub use max_rank::*;
 pub use min_flop_hand_category::*;
 pub use min_hand_type::*;
 pub use min_hi_rating::*;
+pub use min_hv_perceived_range_equity::*;
 pub use min_hvhequity::*;
+pub use min_hvrequity::*;
+pub use min_lo_rating::*;
+pub use min_outs_to_hand_type::*;
 pub use min_rank::*;
 pub use monotone_board::*;
 pub use nonintersecting_hand_ranks::*;
@@ -90,23 +140,33 @@ pub use nth_rank::*;
 pub use nut_hi::*;
 pub use nut_hi_for_hand_type::*;
 pub use nut_hi_outs::*;
+pub use nut_lo::*;
+pub use nut_lo_outs::*;
+pub use outs::*;
+pub use outs_to_hand_type::*;
 pub use overpair::*;
 pub use paired_board::*;
 pub use pocket_pair::*;
+pub use preflop_table::*;
 pub use rainbow_board::*;
 pub use rank_count::*;
 pub use rate_hi_hand::*;
+pub use rate_lo_hand::*;
 pub use river_card::*;
 pub use river_equity::*;
 pub use scoops::*;
 pub use straight_board::*;
+pub use three_flush::*;
 pub use ties_hi::*;
+pub use ties_lo::*;
 pub use to_card::*;
 pub use to_rank::*;
+pub use to_string::*;
 pub use turn_card::*;
 pub use twotone_board::*;
 pub use winning_hand_type::*;
 pub use wins_hi::*;
+pub use wins_lo::*;
 
 pub trait PQLFn: fmt::Debug + Sync {
     fn arg_types(&self) -> Vec<PQLType>;
@@ -0,0 +1,11 @@
+use super::*;
+
+// TODO: deadcards
+#[pqlfn]
+pub fn nut_lo(ctx: &PQLFnContext, player: PQLPlayer, street: PQLStreet) -> PQLBoolean {
+    core::nut_lo(
+        ctx.game,
+        ctx.get_player_slice(player),
+        ctx.get_board(street),
+    )
+}
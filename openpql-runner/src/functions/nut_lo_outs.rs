@@ -0,0 +1,11 @@
+use super::*;
+
+// TODO: deadcards
+#[pqlfn]
+pub fn nut_lo_outs(ctx: &PQLFnContext, player: PQLPlayer, street: PQLStreet) -> PQLCardCount {
+    core::nut_lo_outs(
+        ctx.game,
+        ctx.get_player_slice(player),
+        ctx.get_board(street),
+    )
+}
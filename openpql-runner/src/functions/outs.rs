@@ -0,0 +1,81 @@
+use super::*;
+
+/// Live cards (neither dealt nor dead) that, dealt as the next board card, turn the player from
+/// behind into the best high hand among the dealt hands, ties included. A
+/// player already best on `street` has no outs, nor do preflop and river.
+#[pqlfn]
+pub fn outs(ctx: &PQLFnContext, player: PQLPlayer, street: PQLStreet) -> PQLCardSet {
+    if matches!(street, PQLStreet::Preflop | PQLStreet::River) {
+        return PQLCardSet::default();
+    }
+
+    let board = ctx.get_c64_board(street);
+    let hero = ctx.get_c64_player(player);
+    let hands: Vec<_> = (0..ctx.n_players)
+        .map(|i| ctx.get_c64_player(i.into()))
+        .collect();
+    let is_best = |board| {
+        let rating = ctx.game.eval_rating(hero, board);
+
+        hands
+            .iter()
+            .all(|&hand| ctx.game.eval_rating(hand, board) <= rating)
+    };
+
+    if is_best(board) {
+        return PQLCardSet::default();
+    }
+
+    let all = if ctx.game.is_shortdeck() {
+        PQLCardSet::all::<true>()
+    } else {
+        PQLCardSet::all::<false>()
+    };
+
+    (all & !(ctx.get_c64_players() | board | ctx.dead_cards))
+        .iter()
+        .filter(|&card| is_best(board | PQLCardSet::from(card)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_outs() {
+        // JhTh vs 9d9s on 8h7d2h3c: 9 hearts, the 9c straight, and 3 jacks
+        // and 3 tens pairing over the nines
+        let ctx =
+            TestPQLFnContext::from_cards(PQLGame::Holdem, cards!("Th Jh 9d 9s 8h 7d 2h 3c 4c"));
+        let outs = outs(&ctx.as_ctx(), 0.into(), PQLStreet::Turn);
+
+        assert_eq!(outs.count(), 16);
+        assert!(outs.contains_card(card!("Qh")));
+        assert!(outs.contains_card(card!("9c")));
+        assert!(outs.contains_card(card!("Js")));
+        assert!(!outs.contains_card(card!("2c")), "gives villain two pair");
+    }
+
+    #[test]
+    fn test_outs_already_best() {
+        let ctx =
+            TestPQLFnContext::from_cards(PQLGame::Holdem, cards!("Ah As Kh Ks 2c 3d 4s 7h 8c"));
+
+        assert!(outs(&ctx.as_ctx(), 0.into(), PQLStreet::Turn).is_empty());
+        assert!(outs(&ctx.as_ctx(), 0.into(), PQLStreet::Flop).is_empty());
+        // the last two kings
+        assert_eq!(outs(&ctx.as_ctx(), 1.into(), PQLStreet::Turn).count(), 2);
+    }
+
+    #[test]
+    fn test_outs_no_next_card() {
+        let ctx =
+            TestPQLFnContext::from_cards(PQLGame::Holdem, cards!("Th Jh 9d 9s 8h 7d 2h 3c 4c"));
+
+        for street in [PQLStreet::Preflop, PQLStreet::River] {
+            assert!(outs(&ctx.as_ctx(), 0.into(), street).is_empty());
+        }
+    }
+}
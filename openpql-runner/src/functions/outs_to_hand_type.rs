@@ -0,0 +1,21 @@
+use super::*;
+
+/// Outs only exist on the flop and the turn; preflop and river report none.
+#[pqlfn]
+pub fn outs_to_hand_type(
+    ctx: &PQLFnContext,
+    player: PQLPlayer,
+    street: PQLStreet,
+    ht: PQLHandType,
+) -> PQLCardCount {
+    if matches!(street, PQLStreet::Preflop | PQLStreet::River) {
+        return 0;
+    }
+
+    core::outs_to_hand_type(
+        ctx.game,
+        ctx.get_player_slice(player),
+        ctx.get_board(street),
+        ht,
+    )
+}
@@ -0,0 +1,244 @@
+use std::sync::OnceLock;
+
+use openpql_prelude::{HandMatrix, IsomorphicHandN};
+
+use super::*;
+
+/// Heads-up hold'em preflop equities of every suit-isomorphic matchup,
+/// written by `tests::generate_holdem_table`; `just gen-preflop-table`
+/// runs it in release to regenerate the file.
+///
+/// The first [`N_CLASS_PAIRS`] bytes count the matchups of each pair of
+/// hand classes `(i, j)`, `i <= j` in [`HandMatrix`] order; then comes one
+/// little-endian `u32` per matchup, pair by pair and in key order within a
+/// pair: the hero's wins times two plus ties over all [`N_BOARDS`] boards.
+static HOLDEM_TABLE: &[u8] = include_bytes!("holdem_preflop_equity.bin");
+
+const N_CLASSES: usize = 169;
+const N_CLASS_PAIRS: usize = N_CLASSES * (N_CLASSES + 1) / 2;
+/// Boards of a heads-up hold'em matchup: 5 of the 48 cards left.
+const N_BOARDS: u32 = 1_712_304;
+
+/// Preflop equity of `hero` against `villain` in heads-up hold'em without
+/// dead cards, looked up in the table of every suit-isomorphic matchup.
+pub fn holdem_preflop_equity(hero: &[PQLCard], villain: &[PQLCard]) -> PQLEquity {
+    let (hero_class, villain_class) = (class_index(hero), class_index(villain));
+    let (key, flipped) = if hero_class <= villain_class {
+        matchup_key(hero, villain, hero_class == villain_class)
+    } else {
+        let (key, _) = matchup_key(villain, hero, false);
+        (key, true)
+    };
+    let (i, j) = (hero_class.min(villain_class), hero_class.max(villain_class));
+
+    let pos = pair_keys(i, j).binary_search(&key).unwrap();
+    let idx = N_CLASS_PAIRS + 4 * (offsets()[pair_index(i, j)] + pos);
+    let points = u32::from_le_bytes(HOLDEM_TABLE[idx..idx + 4].try_into().unwrap());
+    let equity = PQLEquity::from(points) / PQLEquity::from(2 * N_BOARDS);
+
+    if flipped { 1.0 - equity } else { equity }
+}
+
+/// Position of the class of the two-card `hand` in [`HandMatrix`] order.
+fn class_index(hand: &[PQLCard]) -> usize {
+    static CLASSES: OnceLock<HandMatrix<usize>> = OnceLock::new();
+
+    CLASSES.get_or_init(|| {
+        let mut classes = HandMatrix::new(&|_| 0);
+
+        for (i, (_, idx)) in classes.iter_mut().enumerate() {
+            *idx = i;
+        }
+
+        classes
+    })[IsomorphicHandN::<2>::from_slice_preflop(hand)]
+}
+
+/// Index of the class pair `(i, j)`, `i <= j`, in the table.
+const fn pair_index(i: usize, j: usize) -> usize {
+    i * N_CLASSES - i * (i.saturating_sub(1)) / 2 + j - i
+}
+
+/// Position of the first matchup of each class pair.
+fn offsets() -> &'static [usize] {
+    static OFFSETS: OnceLock<Vec<usize>> = OnceLock::new();
+
+    OFFSETS.get_or_init(|| {
+        HOLDEM_TABLE[..N_CLASS_PAIRS]
+            .iter()
+            .scan(0, |acc, &n| {
+                let offset = *acc;
+                *acc += usize::from(n);
+                Some(offset)
+            })
+            .collect()
+    })
+}
+
+/// Keys of the matchups of class `i` against class `j`, `i <= j`, in key
+/// order; each pair's keys are listed on its first lookup.
+fn pair_keys(i: usize, j: usize) -> &'static [MatchupKey] {
+    static KEYS: OnceLock<Vec<OnceLock<Vec<MatchupKey>>>> = OnceLock::new();
+
+    KEYS.get_or_init(|| (0..N_CLASS_PAIRS).map(|_| OnceLock::new()).collect())[pair_index(i, j)]
+        .get_or_init(|| {
+            class_matchups(i, j)
+                .into_iter()
+                .map(|(key, _)| key)
+                .collect()
+        })
+}
+
+/// Combos of each class, in [`HandMatrix`] order.
+fn class_combos(class: usize) -> &'static [HandN<2>] {
+    static COMBOS: OnceLock<Vec<Vec<HandN<2>>>> = OnceLock::new();
+
+    &COMBOS.get_or_init(|| {
+        let mut combos = vec![vec![]; N_CLASSES];
+
+        for hand in HandN::<2>::iter_all::<false>() {
+            combos[class_index(hand.as_slice())].push(hand);
+        }
+
+        combos
+    })[class]
+}
+
+/// Key of `hero` against `villain`; hands of the same class take the
+/// smaller key of the two orientations, flipping the matchup if needed.
+fn matchup_key(hero: &[PQLCard], villain: &[PQLCard], same_class: bool) -> (MatchupKey, bool) {
+    let key = MatchupKey::new(hero, villain, PQLCardSet::default());
+
+    if same_class {
+        let flipped = MatchupKey::new(villain, hero, PQLCardSet::default());
+
+        if flipped < key {
+            return (flipped, true);
+        }
+    }
+
+    (key, false)
+}
+
+/// Matchups of class `i` against class `j`, `i <= j`, in key order, each
+/// with a hero and a villain hand it stands for.
+fn class_matchups(i: usize, j: usize) -> Vec<(MatchupKey, [HandN<2>; 2])> {
+    let villains = class_combos(j);
+
+    let mut matchups = class_combos(i)
+        .iter()
+        .flat_map(|&hero| villains.iter().map(move |&villain| (hero, villain)))
+        .filter(|(hero, villain)| (PQLCardSet::from(*hero) & PQLCardSet::from(*villain)).is_empty())
+        .map(
+            |(hero, villain)| match matchup_key(&hero[..], &villain[..], i == j) {
+                (key, false) => (key, [hero, villain]),
+                (key, true) => (key, [villain, hero]),
+            },
+        )
+        .collect::<Vec<_>>();
+
+    matchups.sort_unstable_by_key(|(key, _)| *key);
+    matchups.dedup_by(|(l, _), (r, _)| l == r);
+
+    matchups
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use std::{fs, io::Write as _};
+
+    use super::*;
+    use crate::*;
+
+    fn equity(hero: &str, villain: &str) -> PQLEquity {
+        holdem_preflop_equity(&cards!(hero), &cards!(villain))
+    }
+
+    #[test]
+    fn test_table_size() {
+        let n_matchups: usize = HOLDEM_TABLE[..N_CLASS_PAIRS]
+            .iter()
+            .map(|&n| usize::from(n))
+            .sum();
+
+        assert_eq!(n_matchups, 47_008);
+        assert_eq!(HOLDEM_TABLE.len(), N_CLASS_PAIRS + 4 * n_matchups);
+
+        for (i, j) in [(0, 0), (0, 1), (12, 168), (168, 168)] {
+            assert_eq!(
+                pair_keys(i, j).len(),
+                usize::from(HOLDEM_TABLE[pair_index(i, j)])
+            );
+        }
+    }
+
+    #[test]
+    fn test_equities() {
+        // 791_433 wins and 3_974 ties of 1_712_304 boards
+        assert!((equity("AhKh", "QsQd") - 0.462_144_572_459_096).abs() < 1e-12);
+        assert!((equity("QsQd", "AhKh") - 0.537_855_427_540_904).abs() < 1e-12);
+        assert!((equity("AhKh", "QsQd") - equity("AcKc", "QhQd")).abs() < 1e-12);
+        assert!((equity("AhAd", "AsAc") - 0.5).abs() < 1e-12);
+
+        for (hero, villain) in [("AhKh", "AsKd"), ("7s6s", "6h6d"), ("Ts9h", "Th9s")] {
+            assert!((equity(hero, villain) + equity(villain, hero) - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_pair_index() {
+        let mut idx = 0;
+
+        for i in 0..N_CLASSES {
+            for j in i..N_CLASSES {
+                assert_eq!(pair_index(i, j), idx);
+                idx += 1;
+            }
+        }
+    }
+
+    /// Writes the table; takes about 100 minutes on one core in release.
+    #[test]
+    #[ignore = "generates holdem_preflop_equity.bin"]
+    fn generate_holdem_table() {
+        let mut counts = vec![];
+        let mut points = vec![];
+
+        for i in 0..N_CLASSES {
+            for j in i..N_CLASSES {
+                let matchups = class_matchups(i, j);
+                counts.push(u8::try_from(matchups.len()).unwrap());
+
+                for (_, [hero, villain]) in matchups {
+                    let (hero, villain) = (PQLCardSet::from(hero), PQLCardSet::from(villain));
+                    let mut n = 0;
+
+                    for board in HandN::<5>::iter_all::<false>().with_dead(hero | villain) {
+                        let board = PQLCardSet::from(board);
+                        let rating = |hand| PQLGame::Holdem.eval_rating(hand, board);
+
+                        n += match rating(hero).cmp(&rating(villain)) {
+                            cmp::Ordering::Greater => 2,
+                            cmp::Ordering::Equal => 1,
+                            cmp::Ordering::Less => 0,
+                        };
+                    }
+
+                    points.push(n);
+                }
+            }
+        }
+
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/functions/holdem_preflop_equity.bin"
+        );
+        let mut file = fs::File::create(path).unwrap();
+        file.write_all(&counts).unwrap();
+
+        for n in points {
+            file.write_all(&u32::to_le_bytes(n)).unwrap();
+        }
+    }
+}
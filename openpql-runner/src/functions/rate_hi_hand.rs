@@ -3,7 +3,12 @@ use super::*;
 #[pqlfn]
 pub fn rate_hi_hand(ctx: &PQLFnContext, text: &PQLString) -> Result<PQLHiRating, RuntimeError> {
     let game = match ctx.game {
-        PQLGame::Holdem | PQLGame::Omaha | PQLGame::Omaha5 => PQLGame::Holdem,
+        PQLGame::Holdem
+        | PQLGame::Omaha
+        | PQLGame::Omaha5
+        | PQLGame::Omaha6
+        | PQLGame::Omaha8
+        | PQLGame::Omaha5HiLo => PQLGame::Holdem,
         PQLGame::ShortDeck => PQLGame::ShortDeck,
     };
 
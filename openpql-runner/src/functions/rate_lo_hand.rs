@@ -0,0 +1,12 @@
+use super::*;
+
+#[pqlfn]
+pub fn rate_lo_hand(_ctx: &PQLFnContext, text: &PQLString) -> Result<PQLLoRating, RuntimeError> {
+    parse_cards(text).map_or(Err(RuntimeError::InvalidHand), |cards| {
+        if cards.count() == 5 {
+            Ok(PQLGame::Holdem.eval_lo_rating(cards, PQLCardSet::default()))
+        } else {
+            Err(RuntimeError::RequiresFiveCards)
+        }
+    })
+}
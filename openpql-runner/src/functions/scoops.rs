@@ -3,5 +3,13 @@ use super::*;
 // TODO: optimize
 #[pqlfn]
 pub fn scoops(ctx: &PQLFnContext, player: PQLPlayer) -> PQLBoolean {
-    best_hi_rating(ctx, player, PQLStreet::River) && !ties_hi(ctx, player)
+    let wins_hi_outright = best_hi_rating(ctx, player, PQLStreet::River) && !ties_hi(ctx, player);
+
+    if !wins_hi_outright || !ctx.game.is_hilo() {
+        return wins_hi_outright;
+    }
+
+    let no_lo = (0..ctx.n_players).all(|i| !lo_rating(ctx, i.into(), PQLStreet::River).is_made());
+
+    no_lo || (best_lo_rating(ctx, player, PQLStreet::River) && !ties_lo(ctx, player))
 }
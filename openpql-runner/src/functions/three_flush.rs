@@ -0,0 +1,6 @@
+use super::*;
+
+#[pqlfn]
+pub fn three_flush(ctx: &PQLFnContext, street: PQLStreet) -> PQLBoolean {
+    core::three_flush(ctx.get_board(street))
+}
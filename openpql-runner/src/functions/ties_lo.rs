@@ -0,0 +1,14 @@
+use super::*;
+
+#[pqlfn]
+pub fn ties_lo(ctx: &PQLFnContext, player: PQLPlayer) -> PQLBoolean {
+    if !best_lo_rating(ctx, player, PQLStreet::River) {
+        return false;
+    }
+
+    let player_rating = lo_rating(ctx, player, PQLStreet::River);
+
+    (0..ctx.n_players)
+        .map(PQLPlayer::from)
+        .any(|other| player != other && player_rating == lo_rating(ctx, other, PQLStreet::River))
+}
@@ -0,0 +1,7 @@
+use super::*;
+
+/// Text of a value, as printed in the report.
+#[pqlfn]
+pub fn to_string(value: VmStackValue) -> PQLString {
+    value.to_string()
+}
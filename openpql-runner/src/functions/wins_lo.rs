@@ -0,0 +1,6 @@
+use super::*;
+
+#[pqlfn]
+pub fn wins_lo(ctx: &PQLFnContext, player: PQLPlayer) -> PQLBoolean {
+    best_lo_rating(ctx, player, PQLStreet::River)
+}
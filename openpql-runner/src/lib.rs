@@ -7,11 +7,17 @@
 use std::{
     any::Any,
     borrow::Borrow,
-    cmp, convert, fmt, io, mem,
+    cmp,
+    collections::BTreeMap,
+    convert, fmt, io, iter, mem,
     num::{ParseFloatError, ParseIntError},
     ops, ptr,
     rc::Rc,
     str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
     thread,
 };
 
@@ -22,13 +28,18 @@ use openpql_pql_parser::{Error as SyntaxError, Spanned, *};
 use openpql_prelude::{CardGen, HandN, ParseError, PerPlayer, PlayerIdx};
 use openpql_range_parser::{BoardRangeChecker, Error as RangeError, RangeChecker};
 use runner_output::*;
+use rustc_hash::FxHashMap;
 
 mod error;
 mod functions;
 mod helper_loc;
 mod output_aggregator;
+mod output_format;
+mod quantile_sketch;
+mod query;
 mod runner;
 mod runner_output;
+mod statement_result;
 mod types;
 mod vm;
 
@@ -36,16 +47,25 @@ pub use error::*;
 use functions::*;
 use helper_loc::*;
 use output_aggregator::*;
+pub use output_format::*;
+use quantile_sketch::*;
+pub use query::*;
 pub use runner::*;
+pub use statement_result::*;
 #[cfg(test)]
 pub use tests::*;
 pub use types::*;
-use vm::{Vm, VmBinOpCmp, VmCache, VmExecContext, VmProgram, VmSampledData, VmStackValue};
+use vm::{
+    Hands, MatchupKey, RangeCombos, Vm, VmBinOpCmp, VmCache, VmEnumerator, VmExecContext,
+    VmProgram, VmSampledData, VmStackValue, VmStaticData, range_hands,
+};
 
 type HeapIdx = usize;
+#[cfg(test)]
 type FractionInner = i32;
 type RangeSrc = String;
 type FnCheckRange = Box<dyn Fn(&[PQLCard]) -> bool + Send + Sync>;
+type FnRangeWeight = Box<dyn Fn(&[PQLCard]) -> PQLDouble + Send + Sync>;
 
 fn parse_cards(text: &str) -> Option<PQLCardSet> {
     let mut res = PQLCardSet::default();
@@ -76,8 +96,8 @@ pub mod tests {
     pub use rand::{SeedableRng, prelude::*, rngs};
 
     pub use super::{
-        PQLBoardRange, PQLGame, PQLHiRating, PQLRange,
-        functions::{PQLFnContext, TestPQLFnContext, rate_hi_hand},
+        PQLBoardRange, PQLGame, PQLHiRating, PQLLoRating, PQLRange,
+        functions::{PQLFnContext, TestPQLFnContext, rate_hi_hand, rate_lo_hand},
     };
     use super::{PQLCard, PQLCardCount, PQLError, PQLErrorKind, PQLRank};
 
@@ -107,4 +127,8 @@ pub mod tests {
     pub fn mk_rating(text: &str) -> PQLHiRating {
         rate_hi_hand(&PQLFnContext::default(), &text.to_string()).unwrap()
     }
+
+    pub fn mk_lo_rating(text: &str) -> PQLLoRating {
+        rate_lo_hand(&PQLFnContext::default(), &text.to_string()).unwrap()
+    }
 }
@@ -2,21 +2,38 @@ use super::*;
 
 type Count = usize;
 
-#[derive(Clone, Debug, derive_more::From, derive_more::Display)]
-#[display("{_0}")]
+/// Aggregator of a selector; `sum`, `stddev` and `variance` share the
+/// running sums of `avg`.
+#[derive(Clone, Debug, derive_more::From)]
 pub enum OutputAggregator {
     Avg(OutputAggregatorAvg),
+    #[from(skip)]
+    Sum(OutputAggregatorAvg),
+    #[from(skip)]
+    Stddev(OutputAggregatorAvg),
+    #[from(skip)]
+    Variance(OutputAggregatorAvg),
+    Quantile(OutputAggregatorQuantile),
     Count(OutputAggregatorCount),
     Max(OutputAggregatorCmp<false, true>),
     Min(OutputAggregatorCmp<false, false>),
     MaxSd(OutputAggregatorCmp<true, true>),
     MinSd(OutputAggregatorCmp<true, false>),
+    Histogram(OutputAggregatorHistogram<false>),
+    HistogramSd(OutputAggregatorHistogram<true>),
 }
 
 impl OutputAggregator {
     pub fn new(game: PQLGame, kind: ast::SelectorKind) -> Self {
         match (kind, game) {
             (ast::SelectorKind::Avg, _) => OutputAggregatorAvg::default().into(),
+            (ast::SelectorKind::Sum, _) => Self::Sum(OutputAggregatorAvg::default()),
+            (ast::SelectorKind::Stddev, _) => Self::Stddev(OutputAggregatorAvg::default()),
+            (ast::SelectorKind::Variance, _) => Self::Variance(OutputAggregatorAvg::default()),
+            (ast::SelectorKind::Median, _) => OutputAggregatorQuantile::new(0.5).into(),
+            (ast::SelectorKind::Percentile { per_mille }, _) => {
+                OutputAggregatorQuantile::new(ast::SelectorKind::quantile(per_mille)).into()
+            }
             (ast::SelectorKind::Count, _) => OutputAggregatorCount::default().into(),
             (ast::SelectorKind::Max, PQLGame::ShortDeck) => {
                 OutputAggregatorCmp::<true, true>::default().into()
@@ -26,17 +43,72 @@ impl OutputAggregator {
             }
             (ast::SelectorKind::Max, _) => OutputAggregatorCmp::<false, true>::default().into(),
             (ast::SelectorKind::Min, _) => OutputAggregatorCmp::<false, false>::default().into(),
+            (ast::SelectorKind::Histogram { n_bins }, PQLGame::ShortDeck) => {
+                OutputAggregatorHistogram::<true>::new(n_bins).into()
+            }
+            (ast::SelectorKind::Histogram { n_bins }, _) => {
+                OutputAggregatorHistogram::<false>::new(n_bins).into()
+            }
+        }
+    }
+
+    /// Aggregator of the same kind that has seen no value.
+    pub fn to_empty(&self) -> Self {
+        match self {
+            Self::Avg(_) => OutputAggregatorAvg::default().into(),
+            Self::Sum(_) => Self::Sum(OutputAggregatorAvg::default()),
+            Self::Stddev(_) => Self::Stddev(OutputAggregatorAvg::default()),
+            Self::Variance(_) => Self::Variance(OutputAggregatorAvg::default()),
+            Self::Quantile(inner) => OutputAggregatorQuantile::new(inner.p).into(),
+            Self::Count(_) => OutputAggregatorCount::default().into(),
+            Self::Max(_) => OutputAggregatorCmp::<false, true>::default().into(),
+            Self::Min(_) => OutputAggregatorCmp::<false, false>::default().into(),
+            Self::MaxSd(_) => OutputAggregatorCmp::<true, true>::default().into(),
+            Self::MinSd(_) => OutputAggregatorCmp::<true, false>::default().into(),
+            Self::Histogram(inner) => OutputAggregatorHistogram::<false>::new(inner.n_bins).into(),
+            Self::HistogramSd(inner) => OutputAggregatorHistogram::<true>::new(inner.n_bins).into(),
         }
     }
 
     pub fn push_value(&mut self, val: VmStackValue) {
         match self {
-            Self::Avg(inner) => inner.push_val(val),
+            Self::Avg(inner) | Self::Sum(inner) | Self::Stddev(inner) | Self::Variance(inner) => {
+                inner.push_val(val);
+            }
+            Self::Quantile(inner) => inner.push_val(val),
             Self::Count(inner) => inner.push_val(val),
             Self::Max(inner) => inner.push_val(val),
             Self::Min(inner) => inner.push_val(val),
             Self::MaxSd(inner) => inner.push_val(val),
             Self::MinSd(inner) => inner.push_val(val),
+            Self::Histogram(inner) => inner.push_val(val),
+            Self::HistogramSd(inner) => inner.push_val(val),
+        }
+    }
+
+    pub fn value(&self) -> SelectorValue {
+        match self {
+            Self::Avg(inner) => SelectorValue::Float(inner.to_f()),
+            Self::Sum(inner) => SelectorValue::Float(inner.num),
+            Self::Stddev(inner) => SelectorValue::Float(inner.stats().variance.sqrt()),
+            Self::Variance(inner) => SelectorValue::Float(inner.stats().variance),
+            Self::Quantile(inner) => SelectorValue::Float(inner.sketch.quantile(inner.p)),
+            Self::Count(inner) => SelectorValue::Int(inner.count as u64),
+            Self::Max(inner) => inner.value(),
+            Self::Min(inner) => inner.value(),
+            Self::MaxSd(inner) => inner.value(),
+            Self::MinSd(inner) => inner.value(),
+            Self::Histogram(inner) => inner.value(),
+            Self::HistogramSd(inner) => inner.value(),
+        }
+    }
+
+    /// Sampling statistics of `avg` and `count` selectors.
+    pub const fn stats(&self) -> Option<OutputStats> {
+        match self {
+            Self::Avg(inner) => Some(inner.stats()),
+            Self::Count(inner) => Some(inner.stats()),
+            _ => None,
         }
     }
 
@@ -44,28 +116,77 @@ impl OutputAggregator {
     /// both aggregators must be of the same variant
     pub fn merge(&mut self, other: Self) {
         match (self, other) {
-            (Self::Avg(l), Self::Avg(r)) => l.merge(r),
+            (Self::Avg(l), Self::Avg(r))
+            | (Self::Sum(l), Self::Sum(r))
+            | (Self::Stddev(l), Self::Stddev(r))
+            | (Self::Variance(l), Self::Variance(r)) => l.merge(r),
+            (Self::Quantile(l), Self::Quantile(r)) => l.sketch.merge(r.sketch),
             (Self::Count(l), Self::Count(r)) => l.merge(r),
             (Self::Max(l), Self::Max(r)) => l.merge(r),
             (Self::Min(l), Self::Min(r)) => l.merge(r),
             (Self::MaxSd(l), Self::MaxSd(r)) => l.merge(r),
             (Self::MinSd(l), Self::MinSd(r)) => l.merge(r),
+            (Self::Histogram(l), Self::Histogram(r)) => l.merge(r),
+            (Self::HistogramSd(l), Self::HistogramSd(r)) => l.merge(r),
             _ => unreachable!(),
         }
     }
 }
 
+impl fmt::Display for OutputAggregator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+/// Sample mean and variance of a selector's per-trial values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OutputStats {
+    pub mean: PQLDouble,
+    pub variance: PQLDouble,
+    pub n: Count,
+}
+
+impl OutputStats {
+    /// two-sided 95% quantile of the standard normal distribution
+    const Z_95: PQLDouble = 1.959_963_984_540_054;
+
+    /// Standard error of the mean.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn std_err(self) -> PQLDouble {
+        (self.variance / self.n as PQLDouble).sqrt()
+    }
+
+    /// Normal-approximation 95% confidence interval of the mean.
+    pub fn ci95(self) -> (PQLDouble, PQLDouble) {
+        let half_width = Self::Z_95 * self.std_err();
+
+        (self.mean - half_width, self.mean + half_width)
+    }
+}
+
+/// Running sum, plus mean and squared deviations kept with Welford's update
+/// so the variance does not cancel catastrophically.
 #[derive(Clone, Copy, Debug, Default, derive_more::Display)]
 #[display("{}", self.to_f())]
 pub struct OutputAggregatorAvg {
     num: PQLDouble,
+    mean: PQLDouble,
+    m2: PQLDouble,
     den: Count,
 }
 
 impl OutputAggregatorAvg {
+    #[allow(clippy::cast_precision_loss)]
     pub fn push_val(&mut self, val: VmStackValue) {
-        self.num += PQLNumeric::try_from(val).unwrap().to_dbl();
+        let v = PQLNumeric::try_from(val).unwrap().to_dbl();
+
+        self.num += v;
         self.den += 1;
+
+        let delta = v - self.mean;
+        self.mean += delta / self.den as PQLDouble;
+        self.m2 = delta.mul_add(v - self.mean, self.m2);
     }
 
     #[allow(clippy::cast_precision_loss)]
@@ -73,29 +194,105 @@ impl OutputAggregatorAvg {
         self.num / self.den as PQLDouble
     }
 
+    /// Unbiased sample variance; `NaN` with fewer than two values.
+    #[allow(clippy::cast_precision_loss)]
+    pub const fn stats(self) -> OutputStats {
+        let variance = if self.den < 2 {
+            PQLDouble::NAN
+        } else {
+            self.m2 / (self.den - 1) as PQLDouble
+        };
+
+        OutputStats {
+            mean: self.num / self.den as PQLDouble,
+            variance,
+            n: self.den,
+        }
+    }
+
+    /// Combines the moments with Chan et al.'s pairwise update.
+    #[allow(clippy::cast_precision_loss)]
     pub fn merge(&mut self, other: Self) {
+        if other.den == 0 {
+            return;
+        }
+        if self.den == 0 {
+            *self = other;
+            return;
+        }
+
+        let (n_a, n_b) = (self.den as PQLDouble, other.den as PQLDouble);
+        let n = n_a + n_b;
+        let delta = other.mean - self.mean;
+
         self.num += other.num;
+        self.mean = delta.mul_add(n_b / n, self.mean);
+        self.m2 += (delta * delta).mul_add(n_a * n_b / n, other.m2);
         self.den += other.den;
     }
 }
 
+/// Approximate `p`-quantile of the values, for `median` and `percentile`.
+#[derive(Clone, Debug)]
+pub struct OutputAggregatorQuantile {
+    p: PQLDouble,
+    sketch: QuantileSketch,
+}
+
+impl OutputAggregatorQuantile {
+    pub fn new(p: PQLDouble) -> Self {
+        Self {
+            p,
+            sketch: QuantileSketch::default(),
+        }
+    }
+
+    pub fn push_val(&mut self, val: VmStackValue) {
+        self.sketch
+            .push(PQLNumeric::try_from(val).unwrap().to_dbl());
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, derive_more::Display)]
-#[display("{_0}")]
-pub struct OutputAggregatorCount(Count);
+#[display("{count}")]
+pub struct OutputAggregatorCount {
+    count: Count,
+    total: Count,
+}
 
 impl OutputAggregatorCount {
     pub fn push_val(&mut self, val: VmStackValue) {
         match val {
             VmStackValue::Bool(true) => {
-                self.0 += 1;
+                self.count += 1;
             }
             VmStackValue::Bool(false) => {}
             _ => unreachable!(),
         }
+
+        self.total += 1;
+    }
+
+    /// Statistics of the hit rate `count / total`.
+    #[allow(clippy::cast_precision_loss)]
+    pub const fn stats(self) -> OutputStats {
+        let (k, n) = (self.count as PQLDouble, self.total as PQLDouble);
+        let variance = if self.total < 2 {
+            PQLDouble::NAN
+        } else {
+            k * (n - k) / (n * (n - 1.0))
+        };
+
+        OutputStats {
+            mean: k / n,
+            variance,
+            n: self.total,
+        }
     }
 
     pub const fn merge(&mut self, other: Self) {
-        self.0 += other.0;
+        self.count += other.count;
+        self.total += other.total;
     }
 }
 
@@ -124,6 +321,10 @@ pub fn compare<const SD: bool>(lhs: VmStackValue, rhs: VmStackValue) -> Option<c
 }
 
 impl<const SD: bool, const MAX: bool> OutputAggregatorCmp<SD, MAX> {
+    fn value(self) -> SelectorValue {
+        self.0.map_or(SelectorValue::None, SelectorValue::from)
+    }
+
     pub fn push_val(&mut self, rhs: VmStackValue) {
         let order = const {
             if MAX {
@@ -150,6 +351,113 @@ impl<const SD: bool, const MAX: bool> OutputAggregatorCmp<SD, MAX> {
     }
 }
 
+/// Frequency of each distinct value.
+///
+/// Doubles and fractions are counted per bin `[k / n_bins, (k + 1) / n_bins)`,
+/// with `1` itself in the top bin of `[0, 1]`. Other values are counted
+/// as they are with a linear lookup, which suits the few distinct
+/// outputs of categorical functions.
+#[derive(Clone, Debug)]
+pub struct OutputAggregatorHistogram<const SD: bool> {
+    n_bins: u16,
+    bins: BTreeMap<i64, Count>,
+    values: Vec<(VmStackValue, Count)>,
+}
+
+impl<const SD: bool> fmt::Display for OutputAggregatorHistogram<SD> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl<const SD: bool> OutputAggregatorHistogram<SD> {
+    pub const fn new(n_bins: u16) -> Self {
+        Self {
+            n_bins,
+            bins: BTreeMap::new(),
+            values: Vec::new(),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn bin(&self, v: PQLDouble) -> i64 {
+        let n = i64::from(self.n_bins);
+        let k = (v * PQLDouble::from(self.n_bins)).floor() as i64;
+
+        if v <= 1.0 { k.min(n - 1) } else { k }
+    }
+
+    pub fn push_val(&mut self, val: VmStackValue) {
+        self.push_n(val, 1);
+    }
+
+    fn push_n(&mut self, val: VmStackValue, n: Count) {
+        match val {
+            VmStackValue::Double(v) => *self.bins.entry(self.bin(v)).or_default() += n,
+            VmStackValue::Frac(v) => {
+                *self.bins.entry(self.bin(v.to_double())).or_default() += n;
+            }
+            _ => match self.values.iter_mut().find(|(v, _)| *v == val) {
+                Some((_, count)) => *count += n,
+                None => self.values.push((val, n)),
+            },
+        }
+    }
+
+    /// Numeric bins first, then values in ascending order.
+    #[allow(clippy::cast_precision_loss)]
+    fn value(&self) -> SelectorValue {
+        let n = PQLDouble::from(self.n_bins);
+        let bins = self.bins.iter().map(|(&k, &count)| HistogramBin {
+            value: SelectorValue::Float(k as PQLDouble / n),
+            upper: Some((k + 1) as PQLDouble / n),
+            count: count as u64,
+        });
+
+        let mut values = self.values.clone();
+        values.sort_by(|(l, _), (r, _)| total_order::<SD>(*l, *r));
+
+        SelectorValue::Histogram(
+            bins.chain(values.into_iter().map(|(v, count)| HistogramBin {
+                value: v.into(),
+                upper: None,
+                count: count as u64,
+            }))
+            .collect(),
+        )
+    }
+
+    pub fn merge(&mut self, other: Self) {
+        for (k, count) in other.bins {
+            *self.bins.entry(k).or_default() += count;
+        }
+
+        for (v, count) in other.values {
+            self.push_n(v, count);
+        }
+    }
+}
+
+/// Orders values of the same type: comparable values by their ordering,
+/// cards and streets by theirs and the rest by their text.
+pub fn total_order<const SD: bool>(lhs: VmStackValue, rhs: VmStackValue) -> cmp::Ordering {
+    let game = if SD {
+        PQLGame::ShortDeck
+    } else {
+        PQLGame::Holdem
+    };
+
+    match (lhs, rhs) {
+        (VmStackValue::Bool(l), VmStackValue::Bool(r)) => l.cmp(&r),
+        (VmStackValue::Card(l), VmStackValue::Card(r)) => l.cmp(&r),
+        (VmStackValue::Street(l), VmStackValue::Street(r)) => l.cmp(&r),
+        _ => VmBinOpCmp::compare(game, lhs, rhs)
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| lhs.to_string().cmp(&rhs.to_string())),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,6 +485,110 @@ mod tests {
         );
     }
 
+    fn assert_close(lhs: PQLDouble, rhs: PQLDouble) {
+        assert!((lhs - rhs).abs() < 1e-9, "{lhs} != {rhs}");
+    }
+
+    #[test]
+    fn test_stats_avg() {
+        let mut agg = OutputAggregator::new(PQLGame::default(), ast::SelectorKind::Avg);
+        for v in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            agg.push_value(v.into());
+        }
+
+        let stats = agg.stats().unwrap();
+        assert_close(stats.mean, 5.0);
+        assert_close(stats.variance, 32.0 / 7.0);
+        assert_close(stats.std_err(), (32.0 / 7.0 / 8.0 as PQLDouble).sqrt());
+
+        let (lo, hi) = stats.ci95();
+        assert_close(hi - stats.mean, stats.mean - lo);
+        assert_close(hi - lo, 2.0 * OutputStats::Z_95 * stats.std_err());
+    }
+
+    #[test]
+    fn test_stats_count() {
+        let mut agg = OutputAggregator::new(PQLGame::default(), ast::SelectorKind::Count);
+        for v in [true, false, false, true] {
+            agg.push_value(v.into());
+        }
+
+        let stats = agg.stats().unwrap();
+        assert_close(stats.mean, 0.5);
+        assert_close(stats.variance, 1.0 / 3.0);
+        assert_eq!(stats.n, 4);
+    }
+
+    #[test]
+    fn test_stats_degenerate() {
+        let mut agg = OutputAggregator::new(PQLGame::default(), ast::SelectorKind::Avg);
+        agg.push_value(1.0.into());
+        assert!(agg.stats().unwrap().variance.is_nan());
+
+        agg.push_value(1.0.into());
+        assert_close(agg.stats().unwrap().variance, 0.0);
+
+        let agg = OutputAggregator::new(PQLGame::default(), ast::SelectorKind::Max);
+        assert!(agg.stats().is_none());
+    }
+
+    fn mk_numeric(sel: ast::SelectorKind, values: &[PQLDouble]) -> OutputAggregator {
+        let mut agg = OutputAggregator::new(PQLGame::default(), sel);
+        for &v in values {
+            agg.push_value(v.into());
+        }
+        agg
+    }
+
+    #[test]
+    fn test_aggregator_sum_variance() {
+        let vals = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+
+        assert_eq!(mk_numeric(ast::SelectorKind::Sum, &vals).to_string(), "40");
+
+        let SelectorValue::Float(variance) = mk_numeric(ast::SelectorKind::Variance, &vals).value()
+        else {
+            panic!("variance should be a double")
+        };
+        assert_close(variance, 32.0 / 7.0);
+
+        let SelectorValue::Float(stddev) = mk_numeric(ast::SelectorKind::Stddev, &vals).value()
+        else {
+            panic!("stddev should be a double")
+        };
+        assert_close(stddev, (32.0 / 7.0 as PQLDouble).sqrt());
+
+        assert!(mk_numeric(ast::SelectorKind::Sum, &vals).stats().is_none());
+    }
+
+    #[test]
+    fn test_aggregator_quantile() {
+        let vals: Vec<_> = (1..=100).rev().map(PQLDouble::from).collect();
+
+        for (sel, exact) in [
+            (ast::SelectorKind::Median, 50.0),
+            (ast::SelectorKind::Percentile { per_mille: 900 }, 90.0),
+            (ast::SelectorKind::Percentile { per_mille: 0 }, 1.0),
+        ] {
+            let SelectorValue::Float(v) = mk_numeric(sel, &vals).value() else {
+                panic!("quantile should be a double")
+            };
+            assert!((v - exact).abs() <= exact * QuantileSketch::ALPHA, "{v}");
+        }
+    }
+
+    #[quickcheck]
+    fn test_merge_quantile(lhs: Vec<Int>, rhs: Vec<Int>) {
+        let to_f = |vals: &[Int]| -> Vec<_> { vals.iter().map(|&v| PQLDouble::from(v)).collect() };
+
+        let mut merged = mk_numeric(ast::SelectorKind::Median, &to_f(&lhs));
+        merged.merge(mk_numeric(ast::SelectorKind::Median, &to_f(&rhs)));
+
+        let expected = mk_numeric(ast::SelectorKind::Median, &to_f(&[lhs, rhs].concat()));
+
+        assert_eq!(merged.to_string(), expected.to_string());
+    }
+
     fn assert_cmp<T>(game: PQLGame, sel: ast::SelectorKind, values: &[T], expected: &str)
     where
         T: Copy,
@@ -251,8 +663,33 @@ mod tests {
         merged.merge(mk_avg(&rhs));
 
         let all: Vec<_> = lhs.into_iter().chain(rhs).collect();
+        let expected = mk_avg(&all);
+
+        assert_eq!(merged.to_string(), expected.to_string());
+
+        let (merged, expected) = (merged.stats().unwrap(), expected.stats().unwrap());
+
+        assert_eq!(merged.mean.to_bits(), expected.mean.to_bits());
+        assert_eq!(merged.n, expected.n);
+        if expected.n < 2 {
+            assert!(merged.variance.is_nan());
+        } else {
+            let tol = 1e-9 * expected.variance.max(1.0);
+            assert!(
+                (merged.variance - expected.variance).abs() <= tol,
+                "{merged:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_avg_variance_with_large_offset() {
+        let mut agg = OutputAggregator::new(PQLGame::default(), ast::SelectorKind::Variance);
+        for v in [4.0, 7.0, 13.0, 16.0] {
+            agg.push_value(PQLDouble::from(1e9 + v).into());
+        }
 
-        assert_eq!(merged.to_string(), mk_avg(&all).to_string());
+        assert_eq!(agg.value(), SelectorValue::Float(30.0));
     }
 
     #[quickcheck]
@@ -269,8 +706,17 @@ mod tests {
         merged.merge(mk_count(&rhs));
 
         let all: Vec<_> = lhs.into_iter().chain(rhs).collect();
+        let expected = mk_count(&all);
 
-        assert_eq!(merged.to_string(), mk_count(&all).to_string());
+        assert_eq!(merged.to_string(), expected.to_string());
+        assert_eq!(
+            merged
+                .stats()
+                .map(|s| (s.n, s.mean.to_bits(), s.variance.to_bits())),
+            expected
+                .stats()
+                .map(|s| (s.n, s.mean.to_bits(), s.variance.to_bits())),
+        );
     }
 
     #[test]
@@ -291,6 +737,25 @@ mod tests {
         assert_eq!(lhs.to_string(), "FULL_HOUSE", "keeps the max");
     }
 
+    #[test]
+    fn test_to_empty() {
+        let mut agg = OutputAggregator::new(PQLGame::ShortDeck, ast::SelectorKind::Max);
+        agg.push_value(PQLHandType::Flush.into());
+
+        let mut empty = agg.to_empty();
+        assert_eq!(empty.to_string(), "None");
+
+        empty.push_value(PQLHandType::FullHouse.into());
+        agg.merge(empty);
+        assert_eq!(agg.to_string(), "FLUSH", "keeps the short deck order");
+
+        let mut hist = mk_histogram(PQLGame::default(), &[0.3.into()]).to_empty();
+        assert_eq!(hist.to_string(), "{}");
+
+        hist.push_value(0.3.into());
+        assert_eq!(hist.to_string(), "{[0.25, 0.5): 1}", "keeps the bins");
+    }
+
     #[test]
     fn test_aggregator_min_sd() {
         assert_cmp(
@@ -304,4 +769,74 @@ mod tests {
             "PAIR",
         );
     }
+
+    fn mk_histogram(game: PQLGame, vals: &[VmStackValue]) -> OutputAggregator {
+        let mut agg = OutputAggregator::new(game, ast::SelectorKind::Histogram { n_bins: 4 });
+        for &v in vals {
+            agg.push_value(v);
+        }
+        agg
+    }
+
+    #[test]
+    fn test_aggregator_histogram() {
+        let vals = [
+            PQLHandType::Flush.into(),
+            PQLHandType::Pair.into(),
+            PQLHandType::FullHouse.into(),
+            PQLHandType::Flush.into(),
+        ];
+
+        assert_eq!(
+            mk_histogram(PQLGame::default(), &vals).to_string(),
+            "{PAIR: 1, FLUSH: 2, FULL_HOUSE: 1}"
+        );
+        assert_eq!(
+            mk_histogram(PQLGame::ShortDeck, &vals).to_string(),
+            "{PAIR: 1, FULL_HOUSE: 1, FLUSH: 2}"
+        );
+        assert!(mk_histogram(PQLGame::default(), &vals).stats().is_none());
+    }
+
+    #[test]
+    fn test_aggregator_histogram_bins() {
+        let vals = [
+            0.0.into(),
+            0.3.into(),
+            1.0.into(),
+            0.75.into(),
+            PQLFraction::new(1, 2).into(),
+            1.25.into(),
+        ];
+
+        assert_eq!(
+            mk_histogram(PQLGame::default(), &vals).to_string(),
+            "{[0, 0.25): 1, [0.25, 0.5): 1, [0.5, 0.75): 1, [0.75, 1]: 2, [1.25, 1.5): 1}"
+        );
+    }
+
+    #[quickcheck]
+    fn test_merge_histogram(lhs: Vec<(bool, u8)>, rhs: Vec<(bool, u8)>) {
+        fn mk_vals(vals: &[(bool, u8)]) -> Vec<VmStackValue> {
+            vals.iter()
+                .map(|&(is_num, v)| {
+                    if is_num {
+                        (PQLDouble::from(v) / 100.0).into()
+                    } else {
+                        PQLRank::all::<false>()[usize::from(v % 13)].into()
+                    }
+                })
+                .collect()
+        }
+
+        let mut merged = mk_histogram(PQLGame::default(), &mk_vals(&lhs));
+        merged.merge(mk_histogram(PQLGame::default(), &mk_vals(&rhs)));
+
+        let all: Vec<_> = lhs.into_iter().chain(rhs).collect();
+
+        assert_eq!(
+            merged.value(),
+            mk_histogram(PQLGame::default(), &mk_vals(&all)).value()
+        );
+    }
 }
@@ -0,0 +1,334 @@
+use super::*;
+
+/// How [`PQLRunner::run_formatted`] writes statement results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-oriented report, streamed statement by statement.
+    #[default]
+    Text,
+    /// One row per selector, with a header.
+    Csv,
+    /// A JSON array of [`StatementResult`]s.
+    #[cfg(feature = "serde")]
+    Json,
+}
+
+impl OutputFormat {
+    /// Names accepted by [`FromStr`].
+    pub const NAMES: &[&str] = &[
+        "text",
+        "csv",
+        #[cfg(feature = "serde")]
+        "json",
+    ];
+
+    const CSV_HEADER: &str = "statement,selector,kind,arg,alias,expr,value,\
+                              mean,variance,std_err,ci95_lo,ci95_hi,\
+                              n_trials,n_failures,n_rejected,exact,group";
+
+    /// Writes the results of every statement once all of them have run;
+    /// text results are streamed by the runner instead.
+    pub(crate) fn write_all<W: io::Write>(
+        self,
+        results: &[(usize, StatementResult)],
+        stream: &mut W,
+    ) -> io::Result<()> {
+        match self {
+            Self::Text => Ok(()),
+            Self::Csv => write_csv(results, stream),
+            #[cfg(feature = "serde")]
+            Self::Json => {
+                let results: Vec<_> = results.iter().map(|(_, res)| res).collect();
+
+                serde_json::to_writer_pretty(&mut *stream, &results)?;
+                writeln!(stream)
+            }
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().trim() {
+            "text" => Ok(Self::Text),
+            "csv" => Ok(Self::Csv),
+            #[cfg(feature = "serde")]
+            "json" => Ok(Self::Json),
+            _ => Err(format!("unsupported output format: {s}")),
+        }
+    }
+}
+
+/// Quotes a CSV field when it contains a separator, quote or newline.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// One row per selector; rows of a `group by` key carry the key and the
+/// trials of that key.
+fn write_csv<W: io::Write>(results: &[(usize, StatementResult)], stream: &mut W) -> io::Result<()> {
+    writeln!(stream, "{}", OutputFormat::CSV_HEADER)?;
+
+    for (i_stmt, res) in results {
+        let groups = res
+            .groups
+            .iter()
+            .map(|group| (&group.selectors, group.n_trials, group.key.to_string()));
+
+        for (selectors, n_trials, group) in
+            iter::once((&res.selectors, res.n_trials, String::new())).chain(groups)
+        {
+            for (i_sel, sel) in selectors.iter().enumerate() {
+                let stats = sel.stats.map_or_else(
+                    || ",,,,".to_string(),
+                    |s| {
+                        format!(
+                            "{},{},{},{},{}",
+                            s.mean, s.variance, s.std_err, s.ci95.0, s.ci95.1
+                        )
+                    },
+                );
+
+                writeln!(
+                    stream,
+                    "{i_stmt},{i_sel},{},{},{},{},{},{stats},{n_trials},{},{},{},{}",
+                    sel.kind,
+                    sel.arg.map(|arg| arg.to_string()).unwrap_or_default(),
+                    csv_field(sel.alias.as_deref().unwrap_or_default()),
+                    csv_field(&sel.expr),
+                    csv_field(&sel.value.to_string()),
+                    res.n_failures,
+                    res.n_rejected,
+                    res.exact,
+                    csv_field(&group),
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mk_result() -> StatementResult {
+        StatementResult {
+            selectors: vec![
+                SelectorResult {
+                    kind: "count".into(),
+                    arg: None,
+                    alias: Some("won".into()),
+                    expr: "winsHi(hero)".into(),
+                    value: SelectorValue::Int(3),
+                    stats: Some(SelectorStats {
+                        mean: 0.75,
+                        variance: 0.25,
+                        std_err: 0.25,
+                        ci95: (0.25, 1.25),
+                    }),
+                },
+                SelectorResult {
+                    kind: "max".into(),
+                    arg: None,
+                    alias: None,
+                    expr: "handType(hero, river)".into(),
+                    value: SelectorValue::HandType(PQLHandType::Flush),
+                    stats: None,
+                },
+            ],
+            group_by: None,
+            groups: vec![],
+            n_trials: 4,
+            n_failures: 1,
+            n_rejected: 2,
+            exact: false,
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("TEXT".parse(), Ok(OutputFormat::Text));
+        assert_eq!(" csv ".parse(), Ok(OutputFormat::Csv));
+        assert!("xml".parse::<OutputFormat>().is_err());
+
+        for name in OutputFormat::NAMES {
+            assert!(name.parse::<OutputFormat>().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("hero"), "hero");
+        assert_eq!(csv_field("equity(hero, flop)"), "\"equity(hero, flop)\"");
+        assert_eq!(csv_field("a\"b"), "\"a\"\"b\"");
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut out = Vec::new();
+        OutputFormat::Csv
+            .write_all(&[(1, mk_result())], &mut out)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!(
+                "{}\n\
+                 1,0,count,,won,winsHi(hero),3,0.75,0.25,0.25,0.25,1.25,4,1,2,false,\n\
+                 1,1,max,,,\"handType(hero, river)\",FLUSH,,,,,,4,1,2,false,\n",
+                OutputFormat::CSV_HEADER
+            )
+        );
+    }
+
+    #[test]
+    fn test_csv_groups() {
+        let mut res = mk_result();
+        res.group_by = Some("handType(hero, flop)".into());
+        res.groups = vec![GroupResult {
+            key: SelectorValue::HandType(PQLHandType::Pair),
+            selectors: res.selectors[..1].to_vec(),
+            n_trials: 2,
+        }];
+
+        let mut out = Vec::new();
+        OutputFormat::Csv.write_all(&[(0, res)], &mut out).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap().lines().last(),
+            Some("0,0,count,,won,winsHi(hero),3,0.75,0.25,0.25,0.25,1.25,2,1,2,false,PAIR")
+        );
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            mk_result().to_string(),
+            "won 0 = 3\n  \
+             p = 0.75, var = 0.25, se = 0.25, 95% ci = [0.25, 1.25]\n\
+             MAX 1 = FLUSH\n\
+             4 trials\n"
+        );
+    }
+
+    #[test]
+    fn test_text_histogram() {
+        let bin = |value, upper, count| HistogramBin {
+            value,
+            upper,
+            count,
+        };
+        let res = StatementResult {
+            selectors: vec![SelectorResult {
+                kind: "histogram".into(),
+                arg: None,
+                alias: None,
+                expr: "equity(hero, flop)".into(),
+                value: SelectorValue::Histogram(vec![
+                    bin(SelectorValue::Float(0.0), Some(0.5), 1),
+                    bin(SelectorValue::Float(0.5), Some(1.0), 3),
+                ]),
+                stats: None,
+            }],
+            group_by: None,
+            groups: vec![],
+            n_trials: 4,
+            n_failures: 0,
+            n_rejected: 0,
+            exact: true,
+        };
+
+        assert_eq!(
+            res.to_string(),
+            "HISTOGRAM 0:\n  \
+             [0, 0.5) = 1 (25.00%)\n  \
+             [0.5, 1] = 3 (75.00%)\n\
+             4 deals (exact)\n"
+        );
+        assert_eq!(
+            res.selectors[0].value.to_string(),
+            "{[0, 0.5): 1, [0.5, 1]: 3}"
+        );
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests_serde {
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let res = StatementResult {
+            selectors: vec![SelectorResult {
+                kind: "avg".into(),
+                arg: None,
+                alias: None,
+                expr: "equity(hero)".into(),
+                value: SelectorValue::Float(0.5),
+                stats: None,
+            }],
+            group_by: None,
+            groups: vec![],
+            n_trials: 10,
+            n_failures: 0,
+            n_rejected: 0,
+            exact: true,
+        };
+
+        let mut out = Vec::new();
+        OutputFormat::Json.write_all(&[(0, res)], &mut out).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!([{
+                "selectors": [{
+                    "kind": "avg",
+                    "arg": null,
+                    "alias": null,
+                    "expr": "equity(hero)",
+                    "value": 0.5,
+                    "stats": null,
+                }],
+                "group_by": null,
+                "groups": [],
+                "n_trials": 10,
+                "n_failures": 0,
+                "n_rejected": 0,
+                "exact": true,
+            }])
+        );
+    }
+
+    #[test]
+    fn test_json_histogram() {
+        let value = SelectorValue::Histogram(vec![
+            HistogramBin {
+                value: SelectorValue::Float(0.5),
+                upper: Some(1.0),
+                count: 3,
+            },
+            HistogramBin {
+                value: SelectorValue::HandType(PQLHandType::Flush),
+                upper: None,
+                count: 1,
+            },
+        ]);
+
+        assert_eq!(
+            serde_json::to_value(&value).unwrap(),
+            serde_json::json!([
+                {"value": 0.5, "upper": 1.0, "count": 3},
+                {"value": "FLUSH", "upper": null, "count": 1},
+            ])
+        );
+    }
+}
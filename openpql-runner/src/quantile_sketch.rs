@@ -0,0 +1,221 @@
+use super::*;
+
+type Count = usize;
+type BucketIdx = i32;
+
+/// Quantile sketch over logarithmic buckets (`DDSketch`): every estimate
+/// is within a relative error of [`Self::ALPHA`] of a true quantile.
+///
+/// Sketches merge by adding bucket counts, so the estimate does not
+/// depend on how the values were split between threads.
+#[derive(Clone, Debug)]
+pub struct QuantileSketch {
+    pos: BTreeMap<BucketIdx, Count>,
+    neg: BTreeMap<BucketIdx, Count>,
+    n_zero: Count,
+    min: PQLDouble,
+    max: PQLDouble,
+}
+
+impl Default for QuantileSketch {
+    fn default() -> Self {
+        Self {
+            pos: BTreeMap::new(),
+            neg: BTreeMap::new(),
+            n_zero: 0,
+            min: PQLDouble::INFINITY,
+            max: PQLDouble::NEG_INFINITY,
+        }
+    }
+}
+
+impl QuantileSketch {
+    pub const ALPHA: PQLDouble = 0.005;
+    /// `sqrt(GAMMA) = 1 + ALPHA` bounds the error on either side of a bucket
+    const GAMMA: PQLDouble = (1.0 + Self::ALPHA) * (1.0 + Self::ALPHA);
+    /// magnitudes below this are counted as zero
+    const MIN_VALUE: PQLDouble = 1e-9;
+
+    /// bucket `i` holds the magnitudes around `GAMMA^i`, so that `1` is
+    /// estimated exactly
+    #[allow(clippy::cast_possible_truncation)]
+    fn bucket(v: PQLDouble) -> BucketIdx {
+        v.log(Self::GAMMA).round() as BucketIdx
+    }
+
+    fn bucket_value(i: BucketIdx) -> PQLDouble {
+        Self::GAMMA.powi(i)
+    }
+
+    pub fn len(&self) -> Count {
+        self.n_zero + self.pos.values().sum::<Count>() + self.neg.values().sum::<Count>()
+    }
+
+    pub fn push(&mut self, v: PQLDouble) {
+        self.min = self.min.min(v);
+        self.max = self.max.max(v);
+
+        if v.abs() < Self::MIN_VALUE {
+            self.n_zero += 1;
+        } else if v > 0.0 {
+            *self.pos.entry(Self::bucket(v)).or_default() += 1;
+        } else {
+            *self.neg.entry(Self::bucket(-v)).or_default() += 1;
+        }
+    }
+
+    pub fn merge(&mut self, other: Self) {
+        for (i, count) in other.pos {
+            *self.pos.entry(i).or_default() += count;
+        }
+
+        for (i, count) in other.neg {
+            *self.neg.entry(i).or_default() += count;
+        }
+
+        self.n_zero += other.n_zero;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// Estimate of the value of rank `floor(p * (n - 1))` in ascending
+    /// order, exact for the smallest and largest; `NaN` when the sketch is
+    /// empty.
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn quantile(&self, p: PQLDouble) -> PQLDouble {
+        let n = self.len();
+
+        if n == 0 {
+            return PQLDouble::NAN;
+        }
+
+        let rank = (p * (n - 1) as PQLDouble).floor() as Count;
+
+        if rank == 0 {
+            return self.min;
+        } else if rank == n - 1 {
+            return self.max;
+        }
+
+        let neg = self
+            .neg
+            .iter()
+            .rev()
+            .map(|(&i, &count)| (-Self::bucket_value(i), count));
+        let zero = iter::once((0.0, self.n_zero));
+        let pos = self
+            .pos
+            .iter()
+            .map(|(&i, &count)| (Self::bucket_value(i), count));
+
+        let mut seen = 0;
+        for (v, count) in neg.chain(zero).chain(pos) {
+            seen += count;
+
+            if seen > rank {
+                return v;
+            }
+        }
+
+        unreachable!() // LCOV_EXCL_LINE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mk_sketch(vals: &[PQLDouble]) -> QuantileSketch {
+        let mut sketch = QuantileSketch::default();
+        for &v in vals {
+            sketch.push(v);
+        }
+        sketch
+    }
+
+    fn assert_close(estimate: PQLDouble, exact: PQLDouble) {
+        assert!(
+            (estimate - exact).abs() <= QuantileSketch::ALPHA * exact.abs() * (1.0 + 1e-9),
+            "{estimate} != {exact}"
+        );
+    }
+
+    #[test]
+    fn test_quantile() {
+        let vals: Vec<_> = (1..=100).map(PQLDouble::from).collect();
+        let sketch = mk_sketch(&vals);
+
+        assert_eq!(sketch.len(), 100);
+        assert_close(sketch.quantile(0.0), 1.0);
+        assert_close(sketch.quantile(0.5), 50.0);
+        assert_close(sketch.quantile(0.9), 90.0);
+        assert_close(sketch.quantile(1.0), 100.0);
+    }
+
+    #[test]
+    fn test_quantile_signs() {
+        let sketch = mk_sketch(&[-2.0, 0.0, 0.0, 0.5, -0.25]);
+
+        assert_close(sketch.quantile(0.0), -2.0);
+        assert_close(sketch.quantile(0.25), -0.25);
+        assert_close(sketch.quantile(0.5), 0.0);
+        assert_close(sketch.quantile(1.0), 0.5);
+    }
+
+    #[test]
+    fn test_exact_bounds() {
+        let sketch = mk_sketch(&[0.0, 1.0, 1.0, 0.7]);
+
+        for (p, exact) in [(0.0, 0.0), (0.75, 1.0), (1.0, 1.0)] {
+            assert_eq!(sketch.quantile(p).to_bits(), PQLDouble::to_bits(exact));
+        }
+
+        let sketch = mk_sketch(&[0.31, 0.32]);
+
+        for (p, exact) in [(0.0, 0.31), (1.0, 0.32)] {
+            assert_eq!(sketch.quantile(p).to_bits(), PQLDouble::to_bits(exact));
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(QuantileSketch::default().quantile(0.5).is_nan());
+    }
+
+    #[quickcheck]
+    fn test_quantile_accuracy(vals: Vec<i16>, p: u8) {
+        if vals.is_empty() {
+            return;
+        }
+
+        let p = PQLDouble::from(p) / PQLDouble::from(u8::MAX);
+        let mut vals: Vec<_> = vals.into_iter().map(PQLDouble::from).collect();
+        let sketch = mk_sketch(&vals);
+
+        vals.sort_by(PQLDouble::total_cmp);
+        #[allow(clippy::cast_precision_loss)]
+        #[allow(clippy::cast_possible_truncation)]
+        #[allow(clippy::cast_sign_loss)]
+        let rank = (p * (vals.len() - 1) as PQLDouble).floor() as usize;
+
+        assert_close(sketch.quantile(p), vals[rank]);
+    }
+
+    #[quickcheck]
+    fn test_merge(lhs: Vec<i16>, rhs: Vec<i16>) {
+        let to_f =
+            |vals: &[i16]| -> Vec<_> { vals.iter().map(|&v| PQLDouble::from(v) / 100.0).collect() };
+
+        let mut merged = mk_sketch(&to_f(&lhs));
+        merged.merge(mk_sketch(&to_f(&rhs)));
+
+        let all = mk_sketch(&to_f(&[lhs, rhs].concat()));
+
+        assert_eq!(merged.len(), all.len());
+        for p in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(merged.quantile(p).to_bits(), all.quantile(p).to_bits());
+        }
+    }
+}
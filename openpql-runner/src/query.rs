@@ -0,0 +1,271 @@
+use super::*;
+
+/// Execution options of [`Query::run`] and [`PQLRunner::try_run_stmt_with`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RunOptions {
+    /// Trials per statement, or the trial cap of adaptive runs.
+    pub max_trials: Option<usize>,
+    /// Target standard error; enables adaptive sampling.
+    pub precision: Option<PQLDouble>,
+    /// Enumerates every consistent deal instead of sampling.
+    pub exact: bool,
+    /// Worker threads; defaults to the available cores.
+    pub n_threads: Option<usize>,
+    /// Seed for reproducible sampling.
+    pub seed: Option<u64>,
+}
+
+/// Values of the `$name` placeholders of a query, keyed by name without
+/// the `$` (`$1` binds to `"1"`).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    inner: FxHashMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds `name` to `value`, replacing any previous value.
+    #[must_use]
+    pub fn bind(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.inner.insert(name.into(), value.into());
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.inner.get(name).map(String::as_str)
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Params {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::new(), |params, (name, value)| {
+            params.bind(name, value)
+        })
+    }
+}
+
+/// Parsed PQL source, ready to be run any number of times.
+///
+/// ```ignore
+/// let results = Query::prepare(src)?.run(&RunOptions::default())?;
+/// ```
+#[derive(Debug)]
+pub struct Query<'s> {
+    src: &'s str,
+    stmts: Vec<ast::Stmt<'s>>,
+}
+
+impl<'s> Query<'s> {
+    pub fn prepare(src: &'s str) -> PQLResult<Self> {
+        let stmts = parse_pql(src).map_err(|err| PQLError::from((LocInfo::from(&err), err)))?;
+
+        Ok(Self { src, stmts })
+    }
+
+    pub const fn n_stmts(&self) -> usize {
+        self.stmts.len()
+    }
+
+    /// Runs every statement, stopping at the first error; `$name`
+    /// placeholders are rejected as unbound.
+    pub fn run(&self, opts: &RunOptions) -> PQLResult<Vec<StatementResult>> {
+        self.stmts
+            .iter()
+            .map(|stmt| {
+                PQLRunner::try_run_stmt_with(stmt, opts)
+                    .map(|output| StatementResult::new(&output, stmt, self.src))
+            })
+            .collect()
+    }
+
+    /// Compiles every statement once, leaving `$name` placeholders to be
+    /// bound by [`PreparedQuery::run`].
+    pub fn compile(&self) -> PQLResult<PreparedQuery<'_>> {
+        let compiled = self
+            .stmts
+            .iter()
+            .map(CompiledStmt::prepare)
+            .collect::<PQLResult<_>>()?;
+
+        Ok(PreparedQuery {
+            query: self,
+            compiled,
+        })
+    }
+}
+
+/// Compiled [`Query`] whose placeholders are bound anew on every run.
+///
+/// ```ignore
+/// let query = Query::prepare("select avg(equity(hero)) from hero=$1, villain='QQ+', board=$board")?;
+/// let mut prepared = query.compile()?;
+///
+/// for (hand, board) in spots {
+///     let params = Params::new().bind("1", hand).bind("board", board);
+///     let results = prepared.run(&params, &RunOptions::default())?;
+/// }
+/// ```
+#[derive(Debug)]
+pub struct PreparedQuery<'q> {
+    query: &'q Query<'q>,
+    compiled: Vec<CompiledStmt>,
+}
+
+impl PreparedQuery<'_> {
+    /// Binds `params` and runs every statement, stopping at the first
+    /// error; placeholders missing from `params` are errors.
+    pub fn run(&mut self, params: &Params, opts: &RunOptions) -> PQLResult<Vec<StatementResult>> {
+        self.query
+            .stmts
+            .iter()
+            .zip(&mut self.compiled)
+            .map(|(stmt, compiled)| {
+                compiled.bind(&stmt.from, params)?;
+
+                compiled
+                    .run_with(stmt, opts)
+                    .map(|output| StatementResult::new(&output, stmt, self.query.src))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prepare_err() {
+        let err = Query::prepare("select avg(").unwrap_err();
+
+        assert!(matches!(err.kind, PQLErrorKind::SyntaxError(_)));
+    }
+
+    #[test]
+    fn test_run() {
+        let query = Query::prepare(
+            "select count(winsHi(hero)) as won, max(handType(hero, river)), \
+                    avg(riverEquity(hero)) \
+             from hero='AhAs', villain='KhKs', board='2c3d4s7h8c'; \
+             select min(hiRating(hero, river)) from hero='AhAs', board='2c3d4s7h8c'",
+        )
+        .unwrap();
+        let opts = RunOptions {
+            max_trials: Some(10),
+            n_threads: Some(1),
+            ..Default::default()
+        };
+
+        let results = query.run(&opts).unwrap();
+        assert_eq!(query.n_stmts(), 2);
+        assert_eq!(results.len(), 2);
+
+        let sels = &results[0].selectors;
+        assert_eq!(sels[0].alias.as_deref(), Some("won"));
+        assert_eq!(sels[0].expr, "winsHi(hero)");
+        assert_eq!(sels[0].value, SelectorValue::Int(10));
+        assert_eq!(sels[1].value, SelectorValue::HandType(PQLHandType::Pair));
+        assert_eq!(sels[2].value.to_f64(), Some(1.0));
+        assert_eq!(results[0].n_trials, 10);
+
+        assert!(matches!(
+            results[1].selectors[0].value,
+            SelectorValue::HiRating(_)
+        ));
+    }
+
+    #[test]
+    fn test_run_exact() {
+        let query = Query::prepare(
+            "select count(winsHi(hero)) from hero='AhAs', villain='KhKs', board='2c3d4s7h'",
+        )
+        .unwrap();
+        let opts = RunOptions {
+            exact: true,
+            ..Default::default()
+        };
+
+        let results = query.run(&opts).unwrap();
+        assert!(results[0].exact);
+        assert_eq!(results[0].n_trials, 44);
+        assert_eq!(results[0].selectors[0].value, SelectorValue::Int(42));
+        assert_eq!(results[0].selectors[0].stats, None);
+    }
+
+    #[test]
+    fn test_run_err() {
+        let query =
+            Query::prepare("select count(winsHi(hero)) from hero='AhAs', villain='AhAs'").unwrap();
+        let opts = RunOptions {
+            exact: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            query.run(&opts).unwrap_err().kind,
+            VmError::NoConsistentDeals.into()
+        );
+    }
+
+    #[test]
+    fn test_params() {
+        let params = Params::new().bind("1", "AA").bind("1", "KK");
+        assert_eq!(params.get("1"), Some("KK"));
+        assert_eq!(params.get("board"), None);
+
+        let params: Params = [("1", "AA"), ("board", "2c3d4s")].into_iter().collect();
+        assert_eq!(params.get("board"), Some("2c3d4s"));
+    }
+
+    #[test]
+    fn test_compile_bind() {
+        let src = "select count(winsHi(hero)) from hero=$1, villain='KhKs', board=$board";
+        let query = Query::prepare(src).unwrap();
+        let mut prepared = query.compile().unwrap();
+        let opts = RunOptions {
+            exact: true,
+            n_threads: Some(1),
+            ..Default::default()
+        };
+
+        for (hand, board, n_won) in [
+            ("AhAs", "2c3d4s7h8c", 1),
+            ("QhQs", "2c3d4s7h8c", 0),
+            ("QhQs", "2c3d4sQd8c", 1),
+        ] {
+            let params = Params::new().bind("1", hand).bind("board", board);
+            let results = prepared.run(&params, &opts).unwrap();
+
+            assert_eq!(results[0].n_trials, 1);
+            assert_eq!(results[0].selectors[0].value, SelectorValue::Int(n_won));
+        }
+
+        let err = prepared
+            .run(&Params::new().bind("1", "AhAs"), &opts)
+            .unwrap_err();
+        assert_eq!(err.kind, PQLErrorKind::UnboundParameter);
+        assert_eq!(&src[err.loc.0..err.loc.1], "$board");
+
+        assert_eq!(
+            query.run(&opts).unwrap_err().kind,
+            PQLErrorKind::UnboundParameter
+        );
+    }
+
+    #[test]
+    fn test_compile_err() {
+        let query = Query::prepare("select count(winsHi(hero)) from game=$g, hero='AA'").unwrap();
+
+        assert_eq!(
+            query.compile().unwrap_err().kind,
+            PQLErrorKind::InvalidParameter
+        );
+    }
+}
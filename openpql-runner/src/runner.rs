@@ -1,45 +1,122 @@
 // TODO: remove!; tmp implementation
 #![cfg_attr(coverage_nightly, coverage(off))]
 
+use std::hash::BuildHasher;
+
+use rand::{SeedableRng, rngs::StdRng};
+use rustc_hash::FxBuildHasher;
+
 use super::*;
 
 pub struct PQLRunner {}
 
+/// Failed samples allowed per requested trial: fixed board cards past the
+/// flop can collide with most of the dealt hands.
+const MAX_FAILURES_PER_TRIAL: usize = 10;
+
+/// Deals the `where` clause may reject per requested trial before a
+/// condition that (almost) never holds is reported.
+const MAX_REJECTIONS_PER_TRIAL: usize = 100;
+
+/// Compiled programs of a statement, run on every trial.
+#[derive(Debug)]
+struct StmtPrograms {
+    where_program: Option<VmProgram>,
+    where_loc: LocInfo,
+    group_program: Option<VmProgram>,
+    selectors: Vec<SelectorProgram>,
+}
+
+/// Compiled selector with its `filter (where ...)` predicate.
+#[derive(Debug)]
+struct SelectorProgram {
+    filter: Option<VmProgram>,
+    value: VmProgram,
+}
+
+fn is_satisfied(vm: &mut Vm, predicate: Option<&VmProgram>) -> PQLResult<bool> {
+    match predicate {
+        Some(prog) => Ok(matches!(
+            prog.execute(&mut vm.as_context())?,
+            VmStackValue::Bool(true)
+        )),
+        None => Ok(true),
+    }
+}
+
+/// Executes the compiled programs on the cards currently held by `vm`,
+/// counting the deal as rejected when the where clause is false. Each
+/// selector only aggregates the trials that pass its own filter. Strings
+/// made by the previous trial are dropped first.
+fn execute_trial(vm: &mut Vm, progs: &StmtPrograms, output: &mut RunnerOutput) -> PQLResult<()> {
+    vm.heap.clear_scratch();
+
+    if !is_satisfied(vm, progs.where_program.as_ref())? {
+        output.n_rejected += 1;
+        return Ok(());
+    }
+
+    let key = match &progs.group_program {
+        Some(gp) => Some(gp.execute(&mut vm.as_context())?),
+        None => None,
+    };
+
+    for (idx, sel) in progs.selectors.iter().enumerate() {
+        if !is_satisfied(vm, sel.filter.as_ref())? {
+            continue;
+        }
+
+        let val = sel.value.execute(&mut vm.as_context())?;
+
+        output.push_value(idx, val);
+        if let Some(key) = key {
+            output.group_mut(key).push_value(idx, val);
+        }
+    }
+    output.n_succ += 1;
+    if let Some(key) = key {
+        output.group_mut(key).n_succ += 1;
+    }
+
+    Ok(())
+}
+
+/// Returns the generator of random stream `stream`: derived from `seed`
+/// when given, so each worker of a seeded run gets its own reproducible
+/// stream; freshly seeded from the OS otherwise.
+fn trial_rng(seed: Option<u64>, stream: u64) -> StdRng {
+    // odd constant from the golden ratio; spreads consecutive stream ids
+    const STREAM_MIX: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    seed.map_or_else(
+        || StdRng::from_rng(&mut rand::rng()),
+        |seed| StdRng::seed_from_u64(seed ^ stream.wrapping_add(1).wrapping_mul(STREAM_MIX)),
+    )
+}
+
 /// Runs `n_trails` successful trials on its own clone of the [`Vm`]
 /// (sharing `cache` with the other clones).
 fn run_trials(
     mut vm: Vm,
     n_trails: usize,
-    where_program: Option<&VmProgram>,
-    programs: &[VmProgram],
+    mut rng: StdRng,
+    progs: &StmtPrograms,
     selectors: &[ast::Selector],
 ) -> PQLResult<RunnerOutput> {
-    let mut rng = rand::rng();
     let mut output = RunnerOutput::new(vm.static_data.game, selectors);
 
     while output.n_succ < n_trails {
-        if output.n_fail == n_trails {
-            // TODO: fix this
+        if output.n_fail >= MAX_FAILURES_PER_TRIAL * n_trails {
             return Err(((0, 1), VmError::SamplingFailed).into());
         }
 
+        if output.n_rejected >= MAX_REJECTIONS_PER_TRIAL * n_trails {
+            return Err((progs.where_loc, VmError::WhereClauseRejected).into());
+        }
+
         match vm.sample(&mut rng) {
             Some(()) => {
-                if let Some(wp) = where_program {
-                    let keep =
-                        matches!(wp.execute(&mut vm.as_context())?, VmStackValue::Bool(true));
-
-                    if !keep {
-                        //TODO: refine this
-                        output.n_fail += 1;
-                        continue;
-                    }
-                }
-
-                for (idx, program) in programs.iter().enumerate() {
-                    output.push_value(idx, program.execute(&mut vm.as_context())?);
-                }
-                output.n_succ += 1;
+                execute_trial(&mut vm, progs, &mut output)?;
             }
             None => output.n_fail += 1,
         }
@@ -48,71 +125,116 @@ fn run_trials(
     Ok(output)
 }
 
-impl PQLRunner {
-    // TODO: check max selectors
-    // TODO: refactor run logic
-    // TODO: remove
-    #[allow(clippy::missing_panics_doc)]
-    pub fn try_run_stmt(
-        stmt: &ast::Stmt<'_>,
-        max_trials: Option<usize>,
-        n_threads: Option<usize>,
-    ) -> PQLResult<RunnerOutput> {
-        let mut vm = Vm::from_stmt(stmt)?;
+/// Runs every deal of shard `shard` (out of `n_shards`) of the player
+/// `hands` on its own clone of the [`Vm`].
+///
+/// Each deal gets a trial seed hashed from its cards, so functions that
+/// draw cards of their own draw differently per deal, the same way on any
+/// number of threads; the output is exact only if none of them drew.
+fn enumerate_trials(
+    mut vm: Vm,
+    hands: &[Hands],
+    shard: usize,
+    n_shards: usize,
+    progs: &StmtPrograms,
+    selectors: &[ast::Selector],
+) -> PQLResult<RunnerOutput> {
+    let static_data = vm.static_data.clone();
+    let mut output = RunnerOutput::new(static_data.game, selectors);
+    vm.sampled_data.drew_cards = Arc::default();
 
-        if let Some(n) = max_trials {
-            vm.static_data.n_trails = n;
-        }
+    VmEnumerator::new(&static_data, hands, shard, n_shards).for_each_deal(&mut |cards| {
+        vm.sampled_data.cards.copy_from_slice(cards);
+        vm.sampled_data.seed = FxBuildHasher.hash_one(cards);
+        execute_trial(&mut vm, progs, &mut output)
+    })?;
+
+    output.is_exact = !vm.sampled_data.drew_cards.load(Ordering::Relaxed);
+
+    Ok(output)
+}
+
+/// Compiled form of a statement, ready to be run by any number of workers.
+#[derive(Debug)]
+pub struct CompiledStmt {
+    vm: Vm,
+    progs: StmtPrograms,
+}
+
+impl CompiledStmt {
+    fn new(stmt: &ast::Stmt<'_>) -> PQLResult<Self> {
+        Self::compile(stmt, Vm::from_stmt(stmt)?)
+    }
 
-        let n_trails = vm.static_data.n_trails;
+    /// Compiles `stmt` without binding its `$name` placeholders; call
+    /// [`Self::bind`] before running it.
+    pub(crate) fn prepare(stmt: &ast::Stmt<'_>) -> PQLResult<Self> {
+        Self::compile(stmt, VmStaticData::bind(&stmt.from, None)?.into())
+    }
+
+    /// Rebuilds the static data of `from` with `params`, keeping the
+    /// compiled programs.
+    pub(crate) fn bind(&mut self, from: &ast::FromClause<'_>, params: &Params) -> PQLResult<()> {
+        self.vm.rebind(VmStaticData::bind(from, Some(params))?);
+
+        Ok(())
+    }
 
+    fn compile(stmt: &ast::Stmt<'_>, mut vm: Vm) -> PQLResult<Self> {
         let where_program = match &stmt.where_clause {
             Some(expr) => Some(vm::compile_where(&mut vm, expr)?),
             None => None,
         };
+        let where_loc = stmt.where_clause.as_ref().map_or((0, 1), Spanned::loc);
 
-        let programs = stmt
+        let group_program = match &stmt.group_by {
+            Some(expr) => Some(vm::compile_group_by(&mut vm, expr)?),
+            None => None,
+        };
+
+        let selectors = stmt
             .selectors
             .iter()
-            .map(|s| vm::compile_selector(&mut vm, s))
+            .map(|s| {
+                Ok(SelectorProgram {
+                    filter: match &s.filter {
+                        Some(expr) => Some(vm::compile_where(&mut vm, expr)?),
+                        None => None,
+                    },
+                    value: vm::compile_selector(&mut vm, s)?,
+                })
+            })
             .collect::<PQLResult<Vec<_>>>()?;
 
-        // wasm has no threads: spawning panics at runtime, so clamp to 1
-        // and take the direct path below
-        let n_threads = if cfg!(target_family = "wasm") {
-            1
-        } else {
-            n_threads
-                .or_else(|| thread::available_parallelism().map(usize::from).ok())
-                .unwrap_or(1)
-                .clamp(1, n_trails.max(1))
-        };
+        Ok(Self {
+            vm,
+            progs: StmtPrograms {
+                where_program,
+                where_loc,
+                group_program,
+                selectors,
+            },
+        })
+    }
+
+    /// Runs `job(vm, i, n_threads, progs)` on `n_threads` workers and
+    /// merges their outputs.
+    #[allow(clippy::missing_panics_doc)]
+    fn run_parallel<F>(&self, n_threads: usize, job: F) -> PQLResult<RunnerOutput>
+    where
+        F: Fn(Vm, usize, usize, &StmtPrograms) -> PQLResult<RunnerOutput> + Sync,
+    {
+        let progs = &self.progs;
 
         if n_threads == 1 {
-            return run_trials(
-                vm,
-                n_trails,
-                where_program.as_ref(),
-                &programs,
-                &stmt.selectors,
-            );
+            return job(self.vm.clone(), 0, 1, progs);
         }
 
         let outputs = thread::scope(|scope| {
-            let vm = &vm;
-            let programs = &programs;
-            let where_program = where_program.as_ref();
-            let selectors = &stmt.selectors;
+            let job = &job;
 
             (0..n_threads)
-                .map(|i| {
-                    // distribute the remainder over the first few threads
-                    let quota = n_trails / n_threads + usize::from(i < n_trails % n_threads);
-
-                    scope.spawn(move || {
-                        run_trials(vm.clone(), quota, where_program, programs, selectors)
-                    })
-                })
+                .map(|i| scope.spawn(move || job(self.vm.clone(), i, n_threads, progs)))
                 .collect::<Vec<_>>()
                 .into_iter()
                 .map(|handle| handle.join().unwrap())
@@ -127,26 +249,257 @@ impl PQLRunner {
             })
             .unwrap())
     }
+}
+
+/// wasm has no threads: spawning panics at runtime, so clamp to 1
+fn clamp_threads(n_threads: Option<usize>, max: usize) -> usize {
+    if cfg!(target_family = "wasm") {
+        1
+    } else {
+        n_threads
+            .or_else(|| thread::available_parallelism().map(usize::from).ok())
+            .unwrap_or(1)
+            .clamp(1, max.max(1))
+    }
+}
+
+/// Samples `n_trails` successful trials, split over `n_threads` workers;
+/// worker `i` draws from random stream `first_stream + i`.
+fn sample_trials(
+    compiled: &CompiledStmt,
+    n_trails: usize,
+    n_threads: usize,
+    (seed, first_stream): (Option<u64>, u64),
+    selectors: &[ast::Selector],
+) -> PQLResult<RunnerOutput> {
+    compiled.run_parallel(n_threads, |vm, i, n_threads, progs| {
+        // distribute the remainder over the first few threads
+        let quota = n_trails / n_threads + usize::from(i < n_trails % n_threads);
+        let rng = trial_rng(seed, first_stream + i as u64);
+
+        run_trials(vm, quota, rng, progs, selectors)
+    })
+}
+
+impl CompiledStmt {
+    fn sample(
+        &self,
+        stmt: &ast::Stmt<'_>,
+        max_trials: Option<usize>,
+        n_threads: Option<usize>,
+        seed: Option<u64>,
+    ) -> PQLResult<RunnerOutput> {
+        let n_trails = max_trials.unwrap_or(self.vm.static_data.n_trails);
+        let n_threads = clamp_threads(n_threads, n_trails);
+        let seed = seed.or(self.vm.static_data.seed);
+
+        sample_trials(self, n_trails, n_threads, (seed, 0), &stmt.selectors)
+    }
+
+    fn sample_adaptive(
+        &self,
+        stmt: &ast::Stmt<'_>,
+        precision: PQLDouble,
+        max_trials: Option<usize>,
+        n_threads: Option<usize>,
+        seed: Option<u64>,
+    ) -> PQLResult<RunnerOutput> {
+        let max_trials = max_trials.unwrap_or(VmStaticData::MAX_ADAPTIVE_TRIALS);
+        let n_threads = clamp_threads(n_threads, max_trials);
+        let seed = seed.or(self.vm.static_data.seed);
+
+        let mut output = RunnerOutput::new(self.vm.static_data.game, &stmt.selectors);
+        let mut first_stream = 0;
+
+        while output.n_succ < max_trials {
+            let n_trails =
+                (n_threads * VmStaticData::N_BATCH_TRIALS).min(max_trials - output.n_succ);
+
+            output.merge(sample_trials(
+                self,
+                n_trails,
+                n_threads.min(n_trails),
+                (seed, first_stream),
+                &stmt.selectors,
+            )?);
+            first_stream += n_threads as u64;
+
+            if output.max_std_err() <= precision {
+                break;
+            }
+        }
+
+        Ok(output)
+    }
+
+    fn enumerate(&self, stmt: &ast::Stmt<'_>, n_threads: Option<usize>) -> PQLResult<RunnerOutput> {
+        if self
+            .vm
+            .static_data
+            .player_ranges
+            .iter()
+            .any(PQLRange::is_weighted)
+        {
+            return Err((stmt.from.loc, VmError::WeightedRangeInExactMode).into());
+        }
+
+        let static_data = &self.vm.static_data;
+        let hands = VmEnumerator::list_player_hands(static_data)
+            .filter(|hands| {
+                VmEnumerator::new(static_data, hands, 0, 1).n_deals_bound()
+                    <= VmStaticData::MAX_EXACT_DEALS
+            })
+            .ok_or((stmt.from.loc, VmError::TooManyDeals))?;
+
+        let n_threads = clamp_threads(n_threads, usize::MAX);
+
+        let output = self.run_parallel(n_threads, |vm, i, n_threads, progs| {
+            enumerate_trials(vm, &hands, i, n_threads, progs, &stmt.selectors)
+        })?;
+
+        // deals the where clause rejects exist, so a clause that is never
+        // true is a result rather than an error
+        if output.n_succ + output.n_rejected == 0 {
+            return Err((stmt.from.loc, VmError::NoConsistentDeals).into());
+        }
+
+        Ok(output)
+    }
+
+    /// Runs in the mode selected by `opts`: exact, adaptive when a
+    /// precision is set, or a fixed number of trials.
+    pub(crate) fn run_with(
+        &self,
+        stmt: &ast::Stmt<'_>,
+        opts: &RunOptions,
+    ) -> PQLResult<RunnerOutput> {
+        if opts.exact {
+            self.enumerate(stmt, opts.n_threads)
+        } else if let Some(precision) = opts.precision {
+            self.sample_adaptive(stmt, precision, opts.max_trials, opts.n_threads, opts.seed)
+        } else {
+            self.sample(stmt, opts.max_trials, opts.n_threads, opts.seed)
+        }
+    }
+}
+
+impl PQLRunner {
+    // TODO: check max selectors
+    // TODO: remove
+    /// `seed` overrides the `seed` key of the `from` clause; runs with the
+    /// same seed and thread count give bit-identical results.
+    pub fn try_run_stmt(
+        stmt: &ast::Stmt<'_>,
+        max_trials: Option<usize>,
+        n_threads: Option<usize>,
+        seed: Option<u64>,
+    ) -> PQLResult<RunnerOutput> {
+        CompiledStmt::new(stmt)?.sample(stmt, max_trials, n_threads, seed)
+    }
+
+    /// Samples in batches until the standard error of every `avg`/`count`
+    /// selector is at most `precision`, or `max_trials` trials have been run
+    /// (10,000,000 in release builds and 10,000 in debug builds by default).
+    pub fn try_run_stmt_adaptive(
+        stmt: &ast::Stmt<'_>,
+        precision: PQLDouble,
+        max_trials: Option<usize>,
+        n_threads: Option<usize>,
+        seed: Option<u64>,
+    ) -> PQLResult<RunnerOutput> {
+        CompiledStmt::new(stmt)?.sample_adaptive(stmt, precision, max_trials, n_threads, seed)
+    }
+
+    /// Runs `stmt` on every deal consistent with its `from` clause instead
+    /// of sampling, so each selector reports an exact value.
+    ///
+    /// Functions that draw cards of their own (`hvrEquity` past its
+    /// enumeration bound, multiway or dead-card `hvhEquity` preflop,
+    /// `handsHaving` over later board cards) still run, with a seed of
+    /// their own per deal; the output then has [`RunnerOutput::is_exact`]
+    /// unset, so it reports sampling error.
+    pub fn try_run_stmt_exact(
+        stmt: &ast::Stmt<'_>,
+        n_threads: Option<usize>,
+    ) -> PQLResult<RunnerOutput> {
+        CompiledStmt::new(stmt)?.enumerate(stmt, n_threads)
+    }
+
+    /// Runs `stmt` in the mode selected by `opts`: exact, adaptive when
+    /// a precision is set, or a fixed number of trials.
+    pub fn try_run_stmt_with(stmt: &ast::Stmt<'_>, opts: &RunOptions) -> PQLResult<RunnerOutput> {
+        CompiledStmt::new(stmt)?.run_with(stmt, opts)
+    }
 
     // tmp function
     pub fn run<S: io::Write, T: io::Write>(
         src: &str,
         max_trials: Option<usize>,
         n_threads: Option<usize>,
+        seed: Option<u64>,
         stream_out: &mut S,
         stream_err: &mut T,
     ) -> io::Result<()> {
+        Self::run_formatted(src, OutputFormat::Text, stream_out, stream_err, |stmt| {
+            Self::try_run_stmt(stmt, max_trials, n_threads, seed)
+        })
+    }
+
+    /// Same as [`Self::run`] but stops sampling once every `avg`/`count`
+    /// selector reaches `precision`; see [`Self::try_run_stmt_adaptive`].
+    pub fn run_adaptive<S: io::Write, T: io::Write>(
+        src: &str,
+        precision: PQLDouble,
+        max_trials: Option<usize>,
+        n_threads: Option<usize>,
+        seed: Option<u64>,
+        stream_out: &mut S,
+        stream_err: &mut T,
+    ) -> io::Result<()> {
+        Self::run_formatted(src, OutputFormat::Text, stream_out, stream_err, |stmt| {
+            Self::try_run_stmt_adaptive(stmt, precision, max_trials, n_threads, seed)
+        })
+    }
+
+    /// Same as [`Self::run`] but enumerates every deal exactly.
+    pub fn run_exact<S: io::Write, T: io::Write>(
+        src: &str,
+        n_threads: Option<usize>,
+        stream_out: &mut S,
+        stream_err: &mut T,
+    ) -> io::Result<()> {
+        Self::run_formatted(src, OutputFormat::Text, stream_out, stream_err, |stmt| {
+            Self::try_run_stmt_exact(stmt, n_threads)
+        })
+    }
+
+    /// Runs every statement of `src` with `run_stmt` and writes the results
+    /// to `stream_out` in `format`; errors go to `stream_err`.
+    pub fn run_formatted<S: io::Write, T: io::Write>(
+        src: &str,
+        format: OutputFormat,
+        stream_out: &mut S,
+        stream_err: &mut T,
+        run_stmt: impl Fn(&ast::Stmt<'_>) -> PQLResult<RunnerOutput>,
+    ) -> io::Result<()> {
+        let mut results = vec![];
+
         match parse_pql(src) {
             Ok(stmts) => {
                 for (i, stmt) in stmts.iter().enumerate() {
-                    if i > 0 {
+                    if i > 0 && format == OutputFormat::Text {
                         writeln!(stream_out, "{:-<80}", "")?;
                     }
 
-                    match Self::try_run_stmt(stmt, max_trials, n_threads) {
+                    match run_stmt(stmt) {
                         Ok(output) => {
-                            output.report_to_stream(stmt, stream_out)?;
-                            writeln!(stream_out, "{} trials", output.n_succ)?;
+                            let result = StatementResult::new(&output, stmt, src);
+
+                            if format == OutputFormat::Text {
+                                write!(stream_out, "{result}")?;
+                            } else {
+                                results.push((i, result));
+                            }
                         }
                         Err(err) => {
                             writeln!(stream_err, "{err:?} {}", &src[err.loc.0..err.loc.1])?;
@@ -156,6 +509,7 @@ impl PQLRunner {
             }
             Err(err) => writeln!(stream_err, "{err:?}")?,
         }
-        Ok(())
+
+        format.write_all(&results, stream_out)
     }
 }
@@ -1,25 +1,138 @@
 // TODO: remove!; tmp implementation
 #![cfg_attr(coverage_nightly, coverage(off))]
 
+use std::hash::{Hash, Hasher};
+
 use super::*;
 
 #[derive(Clone, Debug)]
 pub struct RunnerOutput {
+    game: PQLGame,
     aggregators: Vec<OutputAggregator>,
+    /// deals the sampler failed to produce
     pub n_fail: usize,
+    /// deals rejected by the `where` clause
+    pub n_rejected: usize,
     pub n_succ: usize,
+    /// every consistent deal was enumerated and no function drew cards of
+    /// its own, so there is no sampling error
+    pub is_exact: bool,
+    /// outputs of the trials of each `group by` key, in order of appearance
+    groups: Vec<(VmStackValue, Self)>,
+    /// position of each key in `groups`
+    group_idx: FxHashMap<GroupKey, usize>,
+}
+
+/// `group by` key as a hash map key: doubles equal by value or when both
+/// are `NaN`, everything else by [`VmStackValue`]'s equality.
+#[derive(Clone, Copy, Debug)]
+struct GroupKey(VmStackValue);
+
+impl PartialEq for GroupKey {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.0, other.0) {
+            (VmStackValue::Double(l), VmStackValue::Double(r)) => {
+                l == r || (l.is_nan() && r.is_nan())
+            }
+            (l, r) => l == r,
+        }
+    }
+}
+
+impl Eq for GroupKey {}
+
+impl Hash for GroupKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // `0.0` and `-0.0` are equal, and so are all `NaN`s
+        fn double_bits(val: PQLDouble) -> u64 {
+            if val.is_nan() {
+                PQLDouble::NAN.to_bits()
+            } else {
+                (val + 0.0).to_bits()
+            }
+        }
+
+        mem::discriminant(&self.0).hash(state);
+
+        match self.0 {
+            VmStackValue::Ref(val) => val.hash(state),
+            VmStackValue::Bool(val) => val.hash(state),
+            VmStackValue::Count(val) => val.hash(state),
+            VmStackValue::Long(val) => val.hash(state),
+            VmStackValue::Frac(val) => double_bits(val.to_double()).hash(state),
+            VmStackValue::Double(val) => double_bits(val).hash(state),
+            VmStackValue::Card(val) => val.hash(state),
+            VmStackValue::Cards(val) => val.hash(state),
+            VmStackValue::Rank(val) => val.hash(state),
+            VmStackValue::Ranks(val) => val.hash(state),
+            VmStackValue::Street(val) => val.hash(state),
+            VmStackValue::FlopCategory(val) => val.hash(state),
+            VmStackValue::DrawType(val) => val.hash(state),
+            VmStackValue::HandType(val) => val.hash(state),
+            VmStackValue::Rating(val) => val.hash(state),
+            VmStackValue::LoRating(val) => val.hash(state),
+            VmStackValue::Player(val) => val.hash(state),
+        }
+    }
 }
 
 impl RunnerOutput {
     pub fn new(game: PQLGame, selectors: &[ast::Selector]) -> Self {
         Self {
+            game,
             aggregators: selectors
                 .iter()
                 .map(|s| OutputAggregator::new(game, s.kind))
                 .collect(),
             n_fail: 0,
+            n_rejected: 0,
             n_succ: 0,
+            is_exact: false,
+            groups: vec![],
+            group_idx: FxHashMap::default(),
+        }
+    }
+
+    pub fn aggregators(&self) -> &[OutputAggregator] {
+        &self.aggregators
+    }
+
+    /// Outputs of each `group by` key in ascending key order.
+    pub fn groups(&self) -> Vec<(VmStackValue, &Self)> {
+        let mut groups: Vec<_> = self.groups.iter().map(|(key, out)| (*key, out)).collect();
+
+        if self.game.is_shortdeck() {
+            groups.sort_by(|(l, _), (r, _)| total_order::<true>(*l, *r));
+        } else {
+            groups.sort_by(|(l, _), (r, _)| total_order::<false>(*l, *r));
         }
+
+        groups
+    }
+
+    /// Output of the trials with `group by` key `key`, created empty on
+    /// first use.
+    pub fn group_mut(&mut self, key: VmStackValue) -> &mut Self {
+        let idx = *self.group_idx.entry(GroupKey(key)).or_insert_with(|| {
+            let empty = Self {
+                game: self.game,
+                aggregators: self
+                    .aggregators
+                    .iter()
+                    .map(OutputAggregator::to_empty)
+                    .collect(),
+                n_fail: 0,
+                n_rejected: 0,
+                n_succ: 0,
+                is_exact: false,
+                groups: vec![],
+                group_idx: FxHashMap::default(),
+            };
+            self.groups.push((key, empty));
+            self.groups.len() - 1
+        });
+
+        &mut self.groups[idx].1
     }
 
     pub fn push_value(&mut self, idx: usize, val: VmStackValue) {
@@ -36,24 +149,28 @@ impl RunnerOutput {
         }
 
         self.n_fail += other.n_fail;
+        self.n_rejected += other.n_rejected;
         self.n_succ += other.n_succ;
-    }
+        self.is_exact &= other.is_exact;
 
-    pub fn report_to_stream<W: io::Write>(
-        &self,
-        stmt: &ast::Stmt,
-        stream: &mut W,
-    ) -> io::Result<()> {
-        for (i, aggregator) in self.aggregators.iter().enumerate() {
-            let sel = &stmt.selectors[i];
-            if let Some(id) = &sel.alias {
-                write!(stream, "{}", id.inner)?;
-            } else {
-                write!(stream, "{}", sel.kind)?;
-            }
-            writeln!(stream, " {i} = {aggregator}")?;
+        for (key, group) in other.groups {
+            self.group_mut(key).merge(group);
         }
+    }
 
-        Ok(())
+    /// Largest standard error over the `avg`/`count` selectors; `0` when
+    /// there are none and `NaN` while any of them has fewer than two values.
+    pub fn max_std_err(&self) -> PQLDouble {
+        self.aggregators
+            .iter()
+            .filter_map(OutputAggregator::stats)
+            .map(OutputStats::std_err)
+            .fold(0.0, |acc, se| {
+                if acc.is_nan() || se.is_nan() {
+                    PQLDouble::NAN
+                } else {
+                    acc.max(se)
+                }
+            })
     }
 }
@@ -0,0 +1,383 @@
+use super::*;
+
+/// Aggregated value of a selector.
+///
+/// `avg` yields [`Self::Float`], `count` [`Self::Int`] and `histogram`
+/// [`Self::Histogram`]; `max`/`min` keep the type of their expression.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SelectorValue {
+    Float(PQLDouble),
+    Int(u64),
+    Long(PQLLong),
+    Fraction(PQLFraction),
+    Bool(PQLBoolean),
+    Card(PQLCard),
+    Cards(PQLCardSet),
+    Rank(PQLRank),
+    Ranks(PQLRankSet),
+    Street(PQLStreet),
+    Player(PQLPlayer),
+    FlopCategory(PQLFlopHandCategory),
+    DrawType(PQLDrawType),
+    HandType(PQLHandType),
+    HiRating(PQLHiRating),
+    LoRating(PQLLoRating),
+    Histogram(Vec<HistogramBin>),
+    /// `max`/`min` selectors that saw no value.
+    None,
+}
+
+impl SelectorValue {
+    /// Returns numeric values as `f64`.
+    #[allow(clippy::cast_precision_loss)]
+    pub const fn to_f64(&self) -> Option<PQLDouble> {
+        match *self {
+            Self::Float(v) => Some(v),
+            Self::Int(v) => Some(v as PQLDouble),
+            Self::Long(v) => Some(v as PQLDouble),
+            Self::Fraction(v) => Some(v.to_double()),
+            _ => None,
+        }
+    }
+}
+
+impl From<VmStackValue> for SelectorValue {
+    fn from(value: VmStackValue) -> Self {
+        match value {
+            VmStackValue::Double(v) => Self::Float(v),
+            VmStackValue::Count(v) => Self::Int(v.into()),
+            VmStackValue::Long(v) => Self::Long(v),
+            VmStackValue::Frac(v) => Self::Fraction(v),
+            VmStackValue::Bool(v) => Self::Bool(v),
+            VmStackValue::Card(v) => Self::Card(v),
+            VmStackValue::Cards(v) => Self::Cards(v),
+            VmStackValue::Rank(v) => Self::Rank(v),
+            VmStackValue::Ranks(v) => Self::Ranks(v),
+            VmStackValue::Street(v) => Self::Street(v),
+            VmStackValue::Player(v) => Self::Player(v),
+            VmStackValue::FlopCategory(v) => Self::FlopCategory(v),
+            VmStackValue::DrawType(v) => Self::DrawType(v),
+            VmStackValue::HandType(v) => Self::HandType(v),
+            VmStackValue::Rating(v) => Self::HiRating(v),
+            VmStackValue::LoRating(v) => Self::LoRating(v),
+            // selectors over strings are rejected when compiling
+            VmStackValue::Ref(_) => unreachable!(), // LCOV_EXCL_LINE
+        }
+    }
+}
+
+/// Same rendering as the stack values of the text report.
+impl fmt::Display for SelectorValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Float(v) => write!(f, "{v}"),
+            Self::Int(v) => write!(f, "{v}"),
+            Self::Long(v) => write!(f, "{v}"),
+            Self::Fraction(v) => write!(f, "{v}"),
+            Self::Bool(v) => write!(f, "{v}"),
+            Self::Card(v) => write!(f, "{v}"),
+            Self::Cards(v) => write!(f, "{v}"),
+            Self::Rank(v) => write!(f, "{v}"),
+            Self::Ranks(v) => write!(f, "{v}"),
+            Self::Street(v) => write!(f, "{v}"),
+            Self::Player(v) => write!(f, "{v}"),
+            Self::FlopCategory(v) => write!(f, "{v}"),
+            Self::DrawType(v) => write!(f, "{v}"),
+            Self::HandType(v) => write!(f, "{v}"),
+            Self::HiRating(v) => write!(f, "{v:?}"),
+            Self::LoRating(v) => write!(f, "{v}"),
+            Self::Histogram(bins) => {
+                write!(f, "{{")?;
+                for (i, bin) in bins.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{bin}: {}", bin.count)?;
+                }
+                write!(f, "}}")
+            }
+            Self::None => write!(f, "None"),
+        }
+    }
+}
+
+/// Numbers and booleans serialize natively, other values as their text.
+#[cfg(feature = "serde")]
+impl serde::Serialize for SelectorValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Float(v) => serializer.serialize_f64(*v),
+            Self::Int(v) => serializer.serialize_u64(*v),
+            Self::Long(v) => serializer.serialize_i64(*v),
+            Self::Fraction(v) => serializer.serialize_f64(v.to_double()),
+            Self::Bool(v) => serializer.serialize_bool(*v),
+            Self::Histogram(bins) => serializer.collect_seq(bins),
+            Self::None => serializer.serialize_none(),
+            _ => serializer.collect_str(self),
+        }
+    }
+}
+
+/// Bin of a `histogram` selector.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistogramBin {
+    /// Value counted, or the lower bound of a numeric bin.
+    pub value: SelectorValue,
+    /// Upper bound of a numeric bin, exclusive unless it is `1`.
+    pub upper: Option<PQLDouble>,
+    pub count: u64,
+}
+
+/// Values as they are, numeric bins as `[lower, upper)`.
+impl fmt::Display for HistogramBin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.upper {
+            // the top bin of [0, 1] includes 1
+            Some(hi) if hi >= 1.0 && self.value.to_f64() < Some(1.0) => {
+                write!(f, "[{}, {hi}]", self.value)
+            }
+            Some(hi) => write!(f, "[{}, {hi})", self.value),
+            None => write!(f, "{}", self.value),
+        }
+    }
+}
+
+/// Sampling statistics of an `avg`/`count` selector; for `count` they
+/// describe the hit rate.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SelectorStats {
+    pub mean: PQLDouble,
+    pub variance: PQLDouble,
+    pub std_err: PQLDouble,
+    pub ci95: (PQLDouble, PQLDouble),
+}
+
+impl From<OutputStats> for SelectorStats {
+    fn from(stats: OutputStats) -> Self {
+        Self {
+            mean: stats.mean,
+            variance: stats.variance,
+            std_err: stats.std_err(),
+            ci95: stats.ci95(),
+        }
+    }
+}
+
+/// Result of a single selector.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectorResult {
+    /// Lowercase selector kind: `avg`, `count`, `max`, `min`, `sum`,
+    /// `stddev`, `variance`, `median`, `percentile` or `histogram`.
+    pub kind: String,
+    /// Fraction of a `percentile`, bins per unit of a `histogram`.
+    pub arg: Option<PQLDouble>,
+    pub alias: Option<String>,
+    /// Source text of the inner expression.
+    pub expr: String,
+    pub value: SelectorValue,
+    /// Sampling statistics of `avg` and `count`; `None` for the other
+    /// kinds and for exact runs.
+    pub stats: Option<SelectorStats>,
+}
+
+/// Results of the trials sharing one `group by` key.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupResult {
+    pub key: SelectorValue,
+    pub selectors: Vec<SelectorResult>,
+    pub n_trials: usize,
+}
+
+/// Result of a single statement.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatementResult {
+    /// Selectors over all trials, whether grouped or not.
+    pub selectors: Vec<SelectorResult>,
+    /// Source text of the `group by` expression.
+    pub group_by: Option<String>,
+    /// One entry per distinct `group by` key, in ascending key order.
+    pub groups: Vec<GroupResult>,
+    /// Successful trials, or enumerated deals for exact runs.
+    pub n_trials: usize,
+    /// Deals the sampler failed to produce.
+    pub n_failures: usize,
+    /// Trials rejected by the `where` clause.
+    pub n_rejected: usize,
+    pub exact: bool,
+}
+
+fn selector_results(
+    output: &RunnerOutput,
+    stmt: &ast::Stmt<'_>,
+    src: &str,
+    is_exact: bool,
+) -> Vec<SelectorResult> {
+    stmt.selectors
+        .iter()
+        .zip(output.aggregators())
+        .map(|(sel, agg)| {
+            let (start, end) = sel.expr.loc();
+
+            SelectorResult {
+                kind: format!("{:?}", sel.kind),
+                arg: match sel.kind {
+                    ast::SelectorKind::Percentile { per_mille } => {
+                        Some(ast::SelectorKind::quantile(per_mille))
+                    }
+                    ast::SelectorKind::Histogram { n_bins } => Some(n_bins.into()),
+                    _ => None,
+                },
+                alias: sel.alias.as_ref().map(|id| id.inner.to_string()),
+                expr: src[start..end].to_string(),
+                value: agg.value(),
+                stats: agg.stats().filter(|_| !is_exact).map(SelectorStats::from),
+            }
+        })
+        .collect()
+}
+
+impl StatementResult {
+    pub(crate) fn new(output: &RunnerOutput, stmt: &ast::Stmt<'_>, src: &str) -> Self {
+        let groups = output
+            .groups()
+            .into_iter()
+            .map(|(key, group)| GroupResult {
+                key: key.into(),
+                selectors: selector_results(group, stmt, src, output.is_exact),
+                n_trials: group.n_succ,
+            })
+            .collect();
+
+        Self {
+            selectors: selector_results(output, stmt, src, output.is_exact),
+            group_by: stmt.group_by.as_ref().map(|expr| {
+                let (start, end) = expr.loc();
+                src[start..end].to_string()
+            }),
+            groups,
+            n_trials: output.n_succ,
+            n_failures: output.n_fail,
+            n_rejected: output.n_rejected,
+            exact: output.is_exact,
+        }
+    }
+
+    /// Table of the groups: one row per key, one column per selector.
+    fn write_groups(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header = iter::once(self.group_by.clone().unwrap_or_default())
+            .chain(
+                self.selectors
+                    .iter()
+                    .enumerate()
+                    .map(|(i, sel)| sel.name(i)),
+            )
+            .chain(iter::once(
+                if self.exact { "deals" } else { "trials" }.to_string(),
+            ));
+
+        let rows: Vec<Vec<String>> = iter::once(header.collect())
+            .chain(self.groups.iter().map(|group| {
+                iter::once(group.key.to_string())
+                    .chain(group.selectors.iter().map(|sel| sel.value.to_string()))
+                    .chain(iter::once(group.n_trials.to_string()))
+                    .collect()
+            }))
+            .collect();
+
+        let widths: Vec<usize> = (0..rows[0].len())
+            .map(|i| {
+                rows.iter()
+                    .map(|row| row[i].len())
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        for row in &rows {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl SelectorResult {
+    /// Alias, or the uppercase kind followed by the position `i`.
+    fn name(&self, i: usize) -> String {
+        self.alias
+            .clone()
+            .unwrap_or_else(|| format!("{} {i}", self.kind.to_ascii_uppercase()))
+    }
+}
+
+/// Human-oriented report, one line per selector.
+impl fmt::Display for StatementResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, sel) in self.selectors.iter().enumerate() {
+            match &sel.alias {
+                Some(alias) => write!(f, "{alias}")?,
+                None => write!(f, "{}", sel.kind.to_ascii_uppercase())?,
+            }
+            match &sel.value {
+                SelectorValue::Histogram(bins) => write_histogram(f, i, bins)?,
+                value => writeln!(f, " {i} = {value}")?,
+            }
+
+            if let Some(stats) = &sel.stats {
+                write!(f, "  ")?;
+                if sel.kind == "count" {
+                    write!(f, "p = {}, ", stats.mean)?;
+                }
+                writeln!(
+                    f,
+                    "var = {}, se = {}, 95% ci = [{}, {}]",
+                    stats.variance, stats.std_err, stats.ci95.0, stats.ci95.1
+                )?;
+            }
+        }
+
+        if !self.groups.is_empty() {
+            self.write_groups(f)?;
+        }
+
+        if self.exact {
+            // a where clause that is never true still enumerated its deals
+            if self.n_rejected > 0 {
+                writeln!(f, "{} deals rejected by where", self.n_rejected)?;
+            }
+            writeln!(f, "{} deals (exact)", self.n_trials)
+        } else {
+            writeln!(f, "{} trials", self.n_trials)
+        }
+    }
+}
+
+/// One indented line per bin with its share of the values.
+#[allow(clippy::cast_precision_loss)]
+fn write_histogram(f: &mut fmt::Formatter<'_>, i: usize, bins: &[HistogramBin]) -> fmt::Result {
+    let total: u64 = bins.iter().map(|bin| bin.count).sum();
+
+    writeln!(f, " {i}:")?;
+    for bin in bins {
+        writeln!(
+            f,
+            "  {bin} = {} ({:.2}%)",
+            bin.count,
+            100.0 * bin.count as PQLDouble / total as PQLDouble
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 pub use openpql_core::{
-    PQLBoard, PQLCard, PQLCardCount, PQLCardSet, PQLDouble, PQLEquity, PQLFlopHandCategory,
-    PQLFraction, PQLGame, PQLHandType, PQLHiRating, PQLLoRating, PQLPlayer, PQLPlayerCount,
-    PQLRank, PQLRankSet, PQLStreet, PQLSuit, PQLSuitSet,
+    PQLBoard, PQLCard, PQLCardCount, PQLCardSet, PQLDouble, PQLDrawType, PQLEquity,
+    PQLFlopHandCategory, PQLFraction, PQLGame, PQLHandType, PQLHiRating, PQLLoRating, PQLPlayer,
+    PQLPlayerCount, PQLRank, PQLRankSet, PQLStreet, PQLSuit, PQLSuitSet,
 };
 use openpql_prelude as prelude;
 // Range Values:
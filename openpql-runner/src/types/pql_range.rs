@@ -1,6 +1,15 @@
+use std::sync::Arc;
+
 use super::*;
 
-pub struct PQLRange(pub(crate) FnCheckRange, RangeSrc, PQLGame);
+/// Hand range of a player; `AA@50`-style weights, if any, are kept in the
+/// last field.
+pub struct PQLRange(
+    pub(crate) FnCheckRange,
+    RangeSrc,
+    PQLGame,
+    Option<FnRangeWeight>,
+);
 
 impl fmt::Debug for PQLRange {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -16,6 +25,20 @@ impl PQLRange {
     pub fn is_satisfied(&self, cs: &[PQLCard]) -> bool {
         (self.0)(cs)
     }
+
+    /// Frequency at which the hand `cs` is dealt, between 0 and 1.
+    pub fn weight(&self, cs: &[PQLCard]) -> PQLDouble {
+        self.3.as_ref().map_or(1.0, |weight| weight(cs))
+    }
+
+    pub const fn is_weighted(&self) -> bool {
+        self.3.is_some()
+    }
+
+    /// Game the range was parsed for and its source text.
+    pub(crate) fn source(&self) -> (PQLGame, &str) {
+        (self.2, &self.1)
+    }
 }
 
 /// # Panics
@@ -46,10 +69,17 @@ impl TryFrom<(PQLGame, &str)> for PQLRange {
         where
             [u8; N]: smallvec::Array<Item = u8>,
         {
+            let checker = Arc::new(checker);
+            let weight = checker.is_weighted().then(|| {
+                let checker = Arc::clone(&checker);
+                Box::new(move |cs: &[PQLCard]| checker.weight(cs)) as FnRangeWeight
+            });
+
             PQLRange(
                 Box::new(move |cs: &[PQLCard]| checker.is_satisfied(cs)),
                 src.to_string(),
                 game,
+                weight,
             )
         }
 
@@ -59,16 +89,21 @@ impl TryFrom<(PQLGame, &str)> for PQLRange {
                 src,
                 game,
             )),
-            PQLGame::Omaha => Ok(create_range(
+            PQLGame::Omaha | PQLGame::Omaha8 => Ok(create_range(
                 RangeChecker::<4, false>::from_src(src)?,
                 src,
                 game,
             )),
-            PQLGame::Omaha5 => Ok(create_range(
+            PQLGame::Omaha5 | PQLGame::Omaha5HiLo => Ok(create_range(
                 RangeChecker::<5, false>::from_src(src)?,
                 src,
                 game,
             )),
+            PQLGame::Omaha6 => Ok(create_range(
+                RangeChecker::<6, false>::from_src(src)?,
+                src,
+                game,
+            )),
             PQLGame::ShortDeck => Ok(create_range(
                 RangeChecker::<2, true>::from_src(src)?,
                 src,
@@ -95,6 +130,22 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_weight() {
+        let range = PQLRange::try_from((PQLGame::Holdem, "AA@50,KK")).unwrap();
+
+        assert!(range.is_weighted());
+        assert!((range.weight(&cards!("As Ah")) - 0.5).abs() < 1e-9);
+        assert!((range.weight(&cards!("Ks Kh")) - 1.0).abs() < 1e-9);
+
+        let range = PQLRange::try_from((PQLGame::Omaha, "[0.2]AAKK")).unwrap();
+        assert!((range.weight(&cards!("As Ah Ks Kh")) - 0.2).abs() < 1e-9);
+
+        let range = PQLRange::try_from((PQLGame::Holdem, "AA,KK")).unwrap();
+        assert!(!range.is_weighted());
+        assert!((range.weight(&cards!("As Ah")) - 1.0).abs() < 1e-9);
+    }
+
     #[quickcheck]
     fn test_clone(cards: CardN<2, true>) {
         let res = PQLRange::try_from((PQLGame::default(), "BB")).unwrap();
@@ -32,8 +32,14 @@ bitflags! {
     const RANGE = 1 << 17;
     const RANK = 1 << 18;
     const RANKSET = 1 << 19;
+    const STACKVALUE = union!(
+        BOOLEAN, CARD, CARDCOUNT, CARDSET, DOUBLE, DRAWTYPE, FLOPHANDCATEGORY, FRACTION,
+        HANDTYPE, HIRATING, LONG, LORATING, PLAYER, RANK, RANKSET, STREET
+    );
     const STREET = 1 << 20;
     const STRING = 1 << 21;
+    const CARDSET = 1 << 22;
+    const DRAWTYPE = 1 << 23;
   }
 }
 
@@ -50,15 +56,23 @@ impl PQLType {
 impl From<ast::SelectorKind> for PQLType {
     fn from(sel: ast::SelectorKind) -> Self {
         match sel {
-            ast::SelectorKind::Avg => Self::NUMERIC,
+            ast::SelectorKind::Avg
+            | ast::SelectorKind::Sum
+            | ast::SelectorKind::Stddev
+            | ast::SelectorKind::Variance
+            | ast::SelectorKind::Median
+            | ast::SelectorKind::Percentile { .. } => Self::NUMERIC,
             ast::SelectorKind::Count => Self::BOOLEAN,
             ast::SelectorKind::Max | ast::SelectorKind::Min => {
                 Self::NUMERIC
+                    | Self::DRAWTYPE
                     | Self::FLOPHANDCATEGORY
                     | Self::HANDTYPE
                     | Self::HIRATING
+                    | Self::LORATING
                     | Self::RANK
             }
+            ast::SelectorKind::Histogram { .. } => Self::STACKVALUE,
         }
     }
 }
@@ -106,15 +120,29 @@ mod tests {
         assert_match(Avg, PQLType::FRACTION);
         assert_match(Avg, PQLType::DOUBLE);
 
+        for kind in [Sum, Stddev, Variance, Median, Percentile { per_mille: 900 }] {
+            assert_match(kind, PQLType::LONG);
+            assert_match(kind, PQLType::DOUBLE);
+            assert!(!PQLType::from(kind).contains(PQLType::BOOLEAN));
+        }
+
         assert_match(Count, PQLType::BOOLEAN);
 
         assert_match_max_min(PQLType::CARDCOUNT);
         assert_match_max_min(PQLType::LONG);
         assert_match_max_min(PQLType::FRACTION);
         assert_match_max_min(PQLType::DOUBLE);
+        assert_match_max_min(PQLType::DRAWTYPE);
         assert_match_max_min(PQLType::FLOPHANDCATEGORY);
         assert_match_max_min(PQLType::HANDTYPE);
         assert_match_max_min(PQLType::HIRATING);
+        assert_match_max_min(PQLType::LORATING);
         assert_match_max_min(PQLType::RANK);
+
+        let hist = Histogram { n_bins: 10 };
+        assert_match(hist, PQLType::DOUBLE);
+        assert_match(hist, PQLType::HANDTYPE);
+        assert_match(hist, PQLType::PLAYER);
+        assert!(!PQLType::from(hist).contains(PQLType::STRING));
     }
 }
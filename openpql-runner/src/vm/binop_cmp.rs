@@ -70,7 +70,14 @@ impl VmBinOpCmp {
             (Self::Eq, true) => {
                 ctx.stack.push(self.matches(compare_num(lhs, rhs)?).into());
             }
-            (Self::Eq, false) => ctx.stack.push((lhs == rhs).into()),
+            (Self::Eq, false) => {
+                let eq = match (lhs, rhs) {
+                    (VmStackValue::Ref(l), VmStackValue::Ref(r)) => ctx.heap.value_eq(l, r),
+                    _ => lhs == rhs,
+                };
+
+                ctx.stack.push(eq.into());
+            }
             _ => {
                 let order = Self::compare(ctx.fn_ctx.game, lhs, rhs)?;
 
@@ -90,7 +97,12 @@ impl VmBinOpCmp {
         let same = lhs_type == rhs_type;
         let lhs_cmp = matches!(
             lhs_type,
-            PQLType::FLOPHANDCATEGORY | PQLType::HANDTYPE | PQLType::HIRATING | PQLType::RANK
+            PQLType::DRAWTYPE
+                | PQLType::FLOPHANDCATEGORY
+                | PQLType::HANDTYPE
+                | PQLType::HIRATING
+                | PQLType::LORATING
+                | PQLType::RANK
         );
 
         if match self {
@@ -111,13 +123,17 @@ impl VmBinOpCmp {
         lhs: VmStackValue,
         rhs: VmStackValue,
     ) -> Result<Option<cmp::Ordering>, InternalError> {
-        use VmStackValue::{Count, Double, FlopCategory, Frac, HandType, Long, Rank, Rating};
+        use VmStackValue::{
+            Count, Double, DrawType, FlopCategory, Frac, HandType, LoRating, Long, Rank, Rating,
+        };
 
         match (lhs, rhs) {
             (Rank(lhs), Rank(rhs)) => Ok(Some(lhs.cmp(&rhs))),
             (Rating(lhs), Rating(rhs)) => Ok(Some(lhs.cmp(&rhs))),
+            (LoRating(lhs), LoRating(rhs)) => Ok(Some(lhs.cmp(&rhs))),
             (HandType(lhs), HandType(rhs)) => Ok(Some(compare_handtype(game, lhs, rhs))),
             (FlopCategory(lhs), FlopCategory(rhs)) => Ok(Some(compare_flop_cat(game, lhs, rhs))),
+            (DrawType(lhs), DrawType(rhs)) => Ok(Some(lhs.cmp(&rhs))),
             _ => compare_num(lhs, rhs),
         }
     }
@@ -226,6 +242,11 @@ mod tests {
             VmBinOpCmp::Lt,
             true,
         );
+        assert_cmp(
+            [mk_lo_rating("As2h3d4c5s"), mk_lo_rating("As2h3d4c8s")],
+            VmBinOpCmp::Lt,
+            true,
+        );
     }
 
     #[test]
@@ -233,6 +254,24 @@ mod tests {
         assert_cmp([PQLStreet::Flop, PQLStreet::Flop], VmBinOpCmp::Eq, true);
     }
 
+    #[test]
+    fn test_str_eq() {
+        let mut ctx = VmExecContext::default();
+        ctx.heap
+            .extend(["FLUSH", "FLUSH", "PAIR"].map(|s| s.to_string().into()));
+
+        for (rhs, expected) in [(1, true), (2, false)] {
+            ctx.stack.push(sval!(@ref 0));
+            ctx.stack.push(sval!(@ref rhs));
+
+            VmInstruction::BinOp(VmBinOpCmp::Eq.into())
+                .execute(&mut ctx)
+                .unwrap();
+
+            assert_eq!(ctx.stack.pop().unwrap(), expected.into());
+        }
+    }
+
     #[test]
     fn test_shortdeck() {
         assert_cmp_sd(
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::sync::{Arc, PoisonError, RwLock};
 
 use openpql_prelude::IsomorphicHand;
 
@@ -10,11 +10,81 @@ use sharded_map::ShardedMap;
 
 type RatingKey = (IsomorphicHand, IsomorphicHand);
 
+/// Range source text with its game and the dead cards its hands avoid.
+type RangeKey = (PQLGame, RangeSrc, PQLCardSet);
+
+/// Every relabeling of the four suits.
+const SUIT_PERMUTATIONS: [[PQLSuit; 4]; 24] = suit_permutations();
+
+const fn suit_permutations() -> [[PQLSuit; 4]; 24] {
+    let all = PQLSuit::ARR_ALL;
+    let mut res = [all; 24];
+    let mut n = 0;
+    let mut i = 0;
+
+    while i < 4 * 4 * 4 * 4 {
+        let idx = [i / 64, i / 16 % 4, i / 4 % 4, i % 4];
+
+        if idx[0] != idx[1]
+            && idx[0] != idx[2]
+            && idx[0] != idx[3]
+            && idx[1] != idx[2]
+            && idx[1] != idx[3]
+            && idx[2] != idx[3]
+        {
+            res[n] = [all[idx[0]], all[idx[1]], all[idx[2]], all[idx[3]]];
+            n += 1;
+        }
+
+        i += 1;
+    }
+
+    res
+}
+
+/// Hero and villain hands of a heads-up two-card matchup with the dead
+/// cards, relabeled to the smallest form over all suit permutations so
+/// isomorphic matchups share one key. The dead cards are relabeled along
+/// with the hands: a pair of [`IsomorphicHandN`](openpql_prelude::IsomorphicHandN)
+/// would lose how the suits of the two hands and the dead cards line up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MatchupKey([PQLCard; 4], PQLCardSet);
+
+impl MatchupKey {
+    pub fn new(hero: &[PQLCard], villain: &[PQLCard], dead: PQLCardSet) -> Self {
+        let relabel = |perm: &[PQLSuit; 4], cards: &[PQLCard]| {
+            let mut hand =
+                [cards[0], cards[1]].map(|c| PQLCard::new(c.rank, perm[c.suit as usize]));
+            hand.sort_unstable();
+            hand
+        };
+
+        SUIT_PERMUTATIONS
+            .iter()
+            .map(|perm| {
+                let [h0, h1] = relabel(perm, hero);
+                let [v0, v1] = relabel(perm, villain);
+                let dead = dead
+                    .iter()
+                    .map(|c| PQLCard::new(c.rank, perm[c.suit as usize]))
+                    .collect();
+
+                Self([h0, h1, v0, v1], dead)
+            })
+            .min()
+            .unwrap()
+    }
+}
+
 /// Per-statement cache of hand ratings keyed by (player hand, board),
-/// shared by cloned [`Vm`]s across threads.
+/// of preflop equities keyed by matchup and of the hands of ranges that
+/// are not listed with the [`Vm`], shared by cloned [`Vm`]s across threads.
 #[derive(Clone, Debug, Default)]
 pub struct VmCache {
     ratings: Arc<ShardedMap<RatingKey, PQLHiRating>>,
+    lo_ratings: Arc<ShardedMap<RatingKey, PQLLoRating>>,
+    preflop_equities: Arc<ShardedMap<MatchupKey, PQLEquity>>,
+    range_hands: Arc<RwLock<FxHashMap<RangeKey, Arc<Hands>>>>,
 }
 
 impl VmCache {
@@ -26,9 +96,7 @@ impl VmCache {
         player: &[PQLCard],
         board: PQLBoard,
     ) -> PQLHiRating {
-        let (iso_board, map) = board.to_isomorphic_ev();
-        let iso_player = IsomorphicHand::from_slice_and_map(player, map);
-        let key = (iso_board, iso_player);
+        let key = rating_key(player, board);
 
         self.ratings.get(&key).unwrap_or_else(|| {
             let rating = game.eval_rating(player.into(), board.to_card64());
@@ -38,6 +106,78 @@ impl VmCache {
             rating
         })
     }
+
+    /// Returns the 8-or-better low rating for `(player, board)`, computing
+    /// it via `eval_lo_rating` on a miss.
+    pub fn lo_rating_or_insert_with(
+        &self,
+        game: PQLGame,
+        player: &[PQLCard],
+        board: PQLBoard,
+    ) -> PQLLoRating {
+        let key = rating_key(player, board);
+
+        self.lo_ratings.get(&key).unwrap_or_else(|| {
+            let rating = game.eval_lo_rating(player.into(), board.to_card64());
+
+            self.lo_ratings.insert(key, rating);
+
+            rating
+        })
+    }
+
+    /// Returns hero's preflop equity in the matchup `key`, computing it via
+    /// `eval` on a miss; errors are returned and not cached.
+    pub fn preflop_equity_or_try_insert_with<F, E>(
+        &self,
+        key: MatchupKey,
+        eval: F,
+    ) -> Result<PQLEquity, E>
+    where
+        F: FnOnce() -> Result<PQLEquity, E>,
+    {
+        if let Some(equity) = self.preflop_equities.get(&key) {
+            return Ok(equity);
+        }
+
+        let equity = eval()?;
+
+        self.preflop_equities.insert(key, equity);
+
+        Ok(equity)
+    }
+
+    /// Returns the hands of `range` that avoid `dead`, listing them with
+    /// [`range_hands`] on a miss.
+    pub fn range_hands(&self, range: &PQLRange, dead: PQLCardSet) -> Arc<Hands> {
+        let (game, src) = range.source();
+        let key = (game, src.to_string(), dead);
+
+        if let Some(hands) = self
+            .range_hands
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+        {
+            return Arc::clone(hands);
+        }
+
+        let hands = Arc::new(range_hands(game, range, dead));
+
+        self.range_hands
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(key)
+            .or_insert(hands)
+            .clone()
+    }
+}
+
+fn rating_key(player: &[PQLCard], board: PQLBoard) -> RatingKey {
+    let (iso_board, map) = board.to_isomorphic_ev();
+    let iso_player = IsomorphicHand::from_slice_and_map(player, map);
+
+    (iso_board, iso_player)
 }
 
 #[cfg(test)]
@@ -47,7 +187,7 @@ mod tests {
         thread,
     };
 
-    use openpql_prelude::{board, cards};
+    use openpql_prelude::{board, c64, cards};
 
     use super::*;
 
@@ -71,4 +211,79 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn test_range_hands() {
+        let cache = VmCache::default();
+        let range = PQLRange::try_from((PQLGame::Holdem, "AA")).unwrap();
+        let hands = cache.range_hands(&range, c64!("As"));
+
+        assert_eq!(hands.len(), 3);
+        assert!(Arc::ptr_eq(&hands, &cache.range_hands(&range, c64!("As"))));
+        assert_eq!(cache.range_hands(&range, c64!("")).len(), 6);
+    }
+
+    #[quickcheck]
+    fn test_lo_rating(cards: CardN<9>) {
+        let cache = VmCache::default();
+        let game = PQLGame::Omaha8;
+        let player = &cards[0..4];
+        let board = PQLBoard::from_slice(&cards[4..]);
+        let rating = game.eval_lo_rating(player.into(), board.to_card64());
+
+        assert_eq!(cache.lo_rating_or_insert_with(game, player, board), rating);
+        assert_eq!(cache.lo_rating_or_insert_with(game, player, board), rating);
+        assert_eq!(
+            cache.rating_or_insert_with(game, player, board),
+            game.eval_rating(player.into(), board.to_card64())
+        );
+    }
+
+    #[test]
+    fn test_suit_permutations() {
+        let mut perms = SUIT_PERMUTATIONS.to_vec();
+        perms.sort_unstable();
+        perms.dedup();
+
+        assert_eq!(perms.len(), 24);
+    }
+
+    #[test]
+    fn test_matchup_key() {
+        let key = |hero: &str, villain: &str| {
+            MatchupKey::new(&cards!(hero), &cards!(villain), PQLCardSet::default())
+        };
+
+        assert_eq!(key("AhAs", "KhKs"), key("AdAc", "KcKd"));
+        assert_eq!(key("AhKh", "QsJs"), key("KcAc", "JdQd"));
+        assert_ne!(key("AhAs", "KhKs"), key("AhAs", "KdKc"));
+        assert_ne!(key("AhKh", "QhJh"), key("AhKh", "QsJs"));
+        assert_ne!(key("AhAs", "KhKs"), key("KhKs", "AhAs"));
+
+        let dead = |dead| MatchupKey::new(&cards!("AhAs"), &cards!("KhKs"), c64!(dead));
+
+        assert_eq!(dead("Ad"), dead("Ac"));
+        assert_ne!(dead("Ad"), dead("Ah"));
+        assert_ne!(dead("Ad"), key("AhAs", "KhKs"));
+    }
+
+    #[test]
+    fn test_preflop_equity() {
+        let cache = VmCache::default();
+        let key = MatchupKey::new(&cards!("AhAs"), &cards!("KhKs"), PQLCardSet::default());
+        let eval = |equity: PQLEquity| move || Ok::<_, ()>(equity);
+
+        assert_eq!(
+            cache.preflop_equity_or_try_insert_with(key, || Err(())),
+            Err(())
+        );
+        assert_eq!(
+            cache.preflop_equity_or_try_insert_with(key, eval(0.5)),
+            Ok(0.5)
+        );
+        assert_eq!(
+            cache.preflop_equity_or_try_insert_with(key, eval(0.0)),
+            Ok(0.5)
+        );
+    }
 }
@@ -5,12 +5,13 @@ pub fn push_expr(
     expr: &ast::Expr,
     expected_type: PQLType,
 ) -> PQLResult<PQLType> {
-    use ast::Expr::{BinOp, FnCall, Ident, Num, Str, UnaryOp};
+    use ast::Expr::{BinOp, FnCall, HandsHaving, Ident, Num, Str, UnaryOp};
 
     let rtn_type = match expr {
         Ident(ident) => push_ident(data, ident, expected_type),
         Str(s) => push_str(data, s, expected_type),
         FnCall(fncall) => push_fncall(data, fncall),
+        HandsHaving(hh) => push_hands_having(data, hh),
         Num(num) => push_num(data, num, expected_type),
         BinOp(op, l, r) => push_binop(data, *op, l, r),
         UnaryOp(op, start, e) => push_unary_op(data, *op, *start, e),
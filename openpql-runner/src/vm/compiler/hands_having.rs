@@ -0,0 +1,99 @@
+use super::*;
+
+pub fn push_hands_having(data: &mut CompilerData, hh: &ast::HandsHaving) -> PQLResult<PQLType> {
+    let player = with_loc(&hh.player, || {
+        data.static_data
+            .find_player(hh.player.inner)
+            .ok_or(PQLErrorKind::InvalidPlayer)
+    })?;
+
+    match &hh.street {
+        Some(street) => {
+            push_expr(data, street, PQLType::STREET)?;
+        }
+        None => data
+            .prog
+            .push((VmStackValue::from(PQLStreet::River).into(), hh.loc)),
+    }
+
+    let outer = mem::take(&mut data.prog);
+    let res = push_expr(data, &hh.expr, PQLType::BOOLEAN);
+    let prog = VmProgram(mem::replace(&mut data.prog, outer));
+    res?;
+
+    data.prog
+        .push((Box::new(VmHandsHaving { player, prog }).into(), hh.loc));
+
+    Ok(PQLType::FRACTION)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn compile(src: &str) -> PQLResult<(PQLType, CompilerData<'static>)> {
+        let mut data = CompilerData::default();
+        let static_data = VmStaticData {
+            player_names: vec!["hero".into()],
+            ..Default::default()
+        };
+        data.static_data = Box::leak(Box::new(static_data));
+
+        let expr = parse_expr(src).unwrap();
+
+        push_expr(&mut data, &expr, PQLType::NUMERIC).map(|tp| (tp, data))
+    }
+
+    #[test]
+    fn test_hands_having() {
+        let (tp, data) = compile("handsHaving(hero, pocketPair(hero))").unwrap();
+
+        assert_eq!(tp, PQLType::FRACTION);
+        assert_eq!(data.prog.len(), 2);
+        assert!(matches!(
+            data.prog[0].0,
+            VmInstruction::Push(VmStackValue::Street(PQLStreet::River))
+        ));
+        assert!(matches!(
+            &data.prog[1].0,
+            VmInstruction::HandsHaving(hh) if hh.prog.0.len() == 2
+        ));
+
+        let (_, data) = compile("handsHaving(hero, flop, pocketPair(hero))").unwrap();
+
+        assert!(matches!(
+            data.prog[0].0,
+            VmInstruction::Push(VmStackValue::Street(PQLStreet::Flop))
+        ));
+    }
+
+    #[test]
+    fn test_hands_having_err() {
+        let src = "handsHaving(villain, pocketPair(hero))";
+        assert_eq!(
+            compile(src).unwrap_err(),
+            ((12, 19), PQLErrorKind::InvalidPlayer).into()
+        );
+
+        let src = "handsHaving(hero, hero, true)";
+        assert_eq!(
+            compile(src).unwrap_err(),
+            mk_err(&(18, 22), ParseError::InvalidStreet("hero".into()))
+        );
+
+        let src = "handsHaving(hero, handType(hero, flop))";
+        assert_eq!(
+            compile(src).unwrap_err(),
+            (
+                (18, 38),
+                PQLErrorKind::TypeError {
+                    given: PQLType::HANDTYPE,
+                    expected: PQLType::BOOLEAN,
+                }
+            )
+                .into()
+        );
+    }
+}
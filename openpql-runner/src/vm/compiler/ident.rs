@@ -32,12 +32,20 @@ pub fn push_ident(
             PQLType::FLOPHANDCATEGORY,
         ),
         PQLType::HANDTYPE => (resolve_ident::<PQLHandType>(ident)?, PQLType::HANDTYPE),
+        PQLType::DRAWTYPE => (resolve_ident::<PQLDrawType>(ident)?, PQLType::DRAWTYPE),
         _ => {
             if let Ok(value) = resolve_ident::<PQLStreet>(ident)
                 .or_else(|_| resolve_ident::<PQLFlopHandCategory>(ident))
                 .or_else(|_| resolve_ident::<PQLHandType>(ident))
+                .or_else(|_| resolve_ident::<PQLDrawType>(ident))
             {
                 (value, PQLType::from(value))
+            } else if let Some(player) = data
+                .static_data
+                .find_player(ident.inner)
+                .filter(|_| expected_type.contains(PQLType::PLAYER))
+            {
+                (player.into(), PQLType::PLAYER)
             } else {
                 return Err(mk_err(ident, PQLErrorKind::UnrecognizedIdentifier));
             }
@@ -97,6 +105,13 @@ mod tests {
             PQLType::HANDTYPE,
         );
 
+        assert_ident(
+            PQLType::DRAWTYPE,
+            "gutshot",
+            PQLDrawType::Gutshot.into(),
+            PQLType::DRAWTYPE,
+        );
+
         assert_ident(
             PQLType::PLAYER,
             "p1",
@@ -127,6 +142,20 @@ mod tests {
             PQLHandType::Pair.into(),
             PQLType::HANDTYPE,
         );
+
+        assert_ident(
+            PQLType::all(),
+            "combodraw",
+            PQLDrawType::ComboDraw.into(),
+            PQLType::DRAWTYPE,
+        );
+
+        assert_ident(
+            PQLType::all(),
+            "p1",
+            PQLPlayer::from(1).into(),
+            PQLType::PLAYER,
+        );
     }
 
     fn assert_err<E>(expected_type: PQLType, src: &str, err: E)
@@ -165,6 +194,12 @@ mod tests {
             ParseError::InvalidHandType("invalid".into()),
         );
 
+        assert_err(
+            PQLType::DRAWTYPE,
+            "invalid",
+            ParseError::InvalidDrawType("invalid".into()),
+        );
+
         assert_err(PQLType::PLAYER, "invalid", PQLErrorKind::InvalidPlayer);
     }
 }
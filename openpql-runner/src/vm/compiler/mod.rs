@@ -3,6 +3,7 @@ use super::*;
 mod binop;
 mod expression;
 mod fncall;
+mod hands_having;
 mod ident;
 mod numeric;
 mod string;
@@ -11,6 +12,7 @@ mod unary_op;
 pub use binop::*;
 pub use expression::*;
 pub use fncall::*;
+pub use hands_having::*;
 pub use ident::*;
 pub use numeric::*;
 pub use string::*;
@@ -80,6 +82,18 @@ pub fn compile_where(vm: &mut Vm, expr: &ast::Expr) -> PQLResult<VmProgram> {
     Ok(VmProgram(prog))
 }
 
+pub fn compile_group_by(vm: &mut Vm, expr: &ast::Expr) -> PQLResult<VmProgram> {
+    let mut data = CompilerData::new(&vm.static_data);
+
+    push_expr(&mut data, expr, PQLType::STACKVALUE)?;
+
+    let (prog, heap) = (data.prog, data.heap);
+
+    vm.heap.extend(heap);
+
+    Ok(VmProgram(prog))
+}
+
 #[cfg(test)]
 impl CompilerData<'_> {
     // TODO: replace Box::leak
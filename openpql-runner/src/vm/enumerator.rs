@@ -0,0 +1,408 @@
+use super::*;
+
+/// Exhaustive enumeration of every deal consistent with the player ranges,
+/// the board range and the dead cards; each deal has weight one.
+///
+/// Work is split into `n_shards` disjoint shards over the flops dealt to
+/// each combination of hands, so a fixed hand against a narrow range still
+/// keeps every shard busy.
+#[derive(Debug)]
+pub struct VmEnumerator<'a> {
+    static_data: &'a VmStaticData,
+    /// hands of each player, listed once and shared by every shard
+    hands: &'a [Hands],
+    cards: Vec<PQLCard>,
+    shard: usize,
+    n_shards: usize,
+    /// flops dealt so far, over all shards
+    n_flops: usize,
+}
+
+impl<'a> VmEnumerator<'a> {
+    pub fn new(
+        static_data: &'a VmStaticData,
+        hands: &'a [Hands],
+        shard: usize,
+        n_shards: usize,
+    ) -> Self {
+        let n_total =
+            PQLFnContext::n_total_cards(static_data.n_players, static_data.game.player_cards_len());
+
+        Self {
+            static_data,
+            hands,
+            cards: vec![PQLCard::default(); n_total],
+            shard,
+            n_shards,
+            n_flops: 0,
+        }
+    }
+
+    /// Hands of each player's range that avoid the dead cards; `None`
+    /// when a range alone has too many hands for
+    /// [`VmStaticData::MAX_EXACT_DEALS`] deals with the boards the board
+    /// range allows, found before listing them all.
+    pub fn list_player_hands(static_data: &VmStaticData) -> Option<Vec<Hands>> {
+        let n = static_data.game.player_cards_len() as usize;
+        let max = VmStaticData::MAX_EXACT_DEALS / Self::n_boards_bound(static_data).max(1) + 1;
+
+        static_data
+            .player_ranges
+            .iter()
+            .map(|range| list_hands(static_data.game, &range.0, n, static_data.dead_card, max))
+            .collect()
+    }
+
+    /// Upper bound on the deals of the enumeration: every combination of
+    /// hands that do not collide times every board the board range allows,
+    /// ignoring the cards the hands block on the board.
+    pub fn n_deals_bound(&self) -> usize {
+        let n_boards = Self::n_boards_bound(self.static_data);
+        let cap = VmStaticData::MAX_EXACT_DEALS / n_boards.max(1) + 1;
+
+        self.count_hands(0, self.static_data.dead_card, cap)
+            .saturating_mul(n_boards)
+    }
+
+    /// Combinations of the hands of players `i..` that avoid `used`,
+    /// counted up to `cap`.
+    fn count_hands(&self, i: usize, used: PQLCardSet, cap: usize) -> usize {
+        if i == self.hands.len() {
+            return 1;
+        }
+
+        let mut n = 0;
+
+        for hand in &self.hands[i] {
+            let hand = PQLCardSet::from(hand.as_slice());
+
+            if (hand & used).is_empty() {
+                n += self.count_hands(i + 1, used | hand, cap - n);
+
+                if n >= cap {
+                    break;
+                }
+            }
+        }
+
+        n
+    }
+
+    /// Flops the board range allows, each with the turns it allows and
+    /// every river left; with many flops every turn is assumed allowed
+    /// rather than checked.
+    fn n_boards_bound(static_data: &VmStaticData) -> usize {
+        if static_data.game.is_shortdeck() {
+            Self::n_boards_bound_with::<true>(static_data)
+        } else {
+            Self::n_boards_bound_with::<false>(static_data)
+        }
+    }
+
+    fn n_boards_bound_with<const SD: bool>(static_data: &VmStaticData) -> usize {
+        const MAX_CHECKED_FLOPS: usize = 1000;
+
+        let dead = static_data.dead_card;
+        let range = &static_data.board_range;
+        let n_left = PQLCard::all::<SD>().len() - dead.count() as usize;
+
+        let flops = HandN::<3>::iter_all::<SD>()
+            .with_dead(dead)
+            .filter(|flop| range.is_satisfied(flop.as_slice()))
+            .collect::<Vec<_>>();
+
+        let n_turns = if flops.len() > MAX_CHECKED_FLOPS {
+            flops.len() * (n_left - 3)
+        } else {
+            let mut board = [PQLCard::default(); 4];
+
+            flops
+                .iter()
+                .map(|flop| {
+                    board[..3].copy_from_slice(flop.as_slice());
+                    let used = dead | PQLCardSet::from(flop.as_slice());
+
+                    PQLCard::all::<SD>()
+                        .iter()
+                        .filter(|&&turn| {
+                            board[3] = turn;
+                            !used.contains_card(turn) && range.is_satisfied(&board)
+                        })
+                        .count()
+                })
+                .sum()
+        };
+
+        n_turns * (n_left - 4)
+    }
+
+    /// Calls `f` with the cards of every deal in this shard, laid out like
+    /// [`VmSampledData`].
+    pub fn for_each_deal<F>(&mut self, f: &mut F) -> PQLResult<()>
+    where
+        F: FnMut(&[PQLCard]) -> PQLResult<()>,
+    {
+        self.n_flops = 0;
+        self.deal_player(0, self.static_data.dead_card, f)
+    }
+
+    fn deal_player<F>(&mut self, i: usize, used: PQLCardSet, f: &mut F) -> PQLResult<()>
+    where
+        F: FnMut(&[PQLCard]) -> PQLResult<()>,
+    {
+        if i == self.hands.len() {
+            return self.deal_board(used, f);
+        }
+
+        let n = self.static_data.game.player_cards_len() as usize;
+
+        for idx in 0..self.hands[i].len() {
+            let hand = PQLCardSet::from(self.hands[i][idx].as_slice());
+
+            if !(hand & used).is_empty() {
+                continue;
+            }
+
+            self.cards[i * n..(i + 1) * n].copy_from_slice(&self.hands[i][idx]);
+            self.deal_player(i + 1, used | hand, f)?;
+        }
+
+        Ok(())
+    }
+
+    fn deal_board<F>(&mut self, used: PQLCardSet, f: &mut F) -> PQLResult<()>
+    where
+        F: FnMut(&[PQLCard]) -> PQLResult<()>,
+    {
+        if self.static_data.game.is_shortdeck() {
+            self.deal_board_with::<true, F>(used, f)
+        } else {
+            self.deal_board_with::<false, F>(used, f)
+        }
+    }
+
+    fn deal_board_with<const SD: bool, F>(&mut self, used: PQLCardSet, f: &mut F) -> PQLResult<()>
+    where
+        F: FnMut(&[PQLCard]) -> PQLResult<()>,
+    {
+        let i = PQLFnContext::idx_board_start(
+            self.static_data.n_players,
+            self.static_data.game.player_cards_len(),
+        );
+        let range = &self.static_data.board_range;
+
+        for flop in HandN::<3>::iter_all::<SD>().with_dead(used) {
+            self.cards[i..i + 3].copy_from_slice(flop.as_slice());
+
+            if !range.is_satisfied(&self.cards[i..i + 3]) {
+                continue;
+            }
+
+            self.n_flops += 1;
+
+            if (self.n_flops - 1) % self.n_shards != self.shard {
+                continue;
+            }
+
+            let used = used | PQLCardSet::from(flop.as_slice());
+
+            for &turn in PQLCard::all::<SD>() {
+                if used.contains_card(turn) {
+                    continue;
+                }
+
+                self.cards[i + 3] = turn;
+
+                if !range.is_satisfied(&self.cards[i..i + 4]) {
+                    continue;
+                }
+
+                for &river in PQLCard::all::<SD>() {
+                    if used.contains_card(river) || river == turn {
+                        continue;
+                    }
+
+                    self.cards[i + 4] = river;
+
+                    if range.is_satisfied(&self.cards[i..i + 5]) {
+                        f(&self.cards)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::*;
+
+    fn count_deals(game: PQLGame, players: &[&str], board: &str, n_shards: usize) -> usize {
+        let (player_ranges, board_range) = mk_ranges(game, players, board);
+        let static_data = VmStaticData {
+            game,
+            player_ranges,
+            board_range,
+            n_players: PQLPlayerCount::try_from(players.len()).unwrap(),
+            ..Default::default()
+        };
+
+        let hands = VmEnumerator::list_player_hands(&static_data).unwrap();
+
+        (0..n_shards)
+            .map(|shard| {
+                let mut n = 0;
+                VmEnumerator::new(&static_data, &hands, shard, n_shards)
+                    .for_each_deal(&mut |_| {
+                        n += 1;
+                        Ok(())
+                    })
+                    .unwrap();
+                n
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_count_deals() {
+        // 6 AA combos x 6 KK combos, board fixed up to the order of the flop
+        assert_eq!(
+            count_deals(PQLGame::Holdem, &["AA", "KK"], "2s3h4d5c7s", 1),
+            36
+        );
+        // one hand each; 44 rivers left
+        assert_eq!(
+            count_deals(PQLGame::Holdem, &["AsAh", "KsKh"], "2s3h4d5c", 1),
+            44
+        );
+        // no players: every river for a fixed turn
+        assert_eq!(count_deals(PQLGame::Holdem, &[], "2s3h4d5c", 1), 48);
+    }
+
+    #[test]
+    fn test_shards_partition_deals() {
+        for players in [&["AA", "KK"][..], &[]] {
+            assert_eq!(
+                count_deals(PQLGame::Holdem, players, "2s3h4d5c", 1),
+                count_deals(PQLGame::Holdem, players, "2s3h4d5c", 3),
+            );
+        }
+    }
+
+    /// A fixed hand against six combos with a fixed flop still spreads
+    /// over more shards than the fixed hand has combos.
+    #[test]
+    fn test_shards_split_narrow_ranges() {
+        let (player_ranges, board_range) = mk_ranges(PQLGame::Holdem, &["AsAh", "KK"], "2s3h4d");
+        let static_data = VmStaticData {
+            game: PQLGame::Holdem,
+            player_ranges,
+            board_range,
+            n_players: 2,
+            ..Default::default()
+        };
+
+        let hands = VmEnumerator::list_player_hands(&static_data).unwrap();
+
+        for shard in 0..4 {
+            let mut n = 0;
+            VmEnumerator::new(&static_data, &hands, shard, 4)
+                .for_each_deal(&mut |_| {
+                    n += 1;
+                    Ok(())
+                })
+                .unwrap();
+
+            assert!(n > 0, "shard {shard} is empty");
+        }
+    }
+
+    #[test]
+    fn test_n_deals_bound() {
+        let bound = |players: &[&str], board: &str| {
+            let (player_ranges, board_range) = mk_ranges(PQLGame::Holdem, players, board);
+            let static_data = VmStaticData {
+                game: PQLGame::Holdem,
+                player_ranges,
+                board_range,
+                n_players: PQLPlayerCount::try_from(players.len()).unwrap(),
+                ..Default::default()
+            };
+
+            let hands = VmEnumerator::list_player_hands(&static_data).unwrap();
+
+            VmEnumerator::new(&static_data, &hands, 0, 1).n_deals_bound()
+        };
+
+        // the turn is fixed, so each of the 36 matchups has one flop, one
+        // turn and at most 48 rivers
+        assert_eq!(bound(&["AA", "KK"], "2s3h4d5c"), 36 * 48);
+        assert!(bound(&["AA", "KK"], "2s") > VmStaticData::MAX_EXACT_DEALS);
+        assert_eq!(bound(&["AsAh", "AsAh"], "*"), 0);
+    }
+
+    /// A range with more hands than the deal limit leaves room for is
+    /// refused after listing a few of them.
+    #[test]
+    fn test_list_player_hands_bound() {
+        let list = |game, players: &[&str], board| {
+            let (player_ranges, board_range) = mk_ranges(game, players, board);
+            let static_data = VmStaticData {
+                game,
+                player_ranges,
+                board_range,
+                n_players: PQLPlayerCount::try_from(players.len()).unwrap(),
+                ..Default::default()
+            };
+
+            VmEnumerator::list_player_hands(&static_data)
+        };
+
+        assert_eq!(
+            list(PQLGame::Holdem, &["AA", "KK"], "2s3h4d5c")
+                .map(|hands| hands.iter().map(Vec::len).collect::<Vec<_>>()),
+            Some(vec![6, 6])
+        );
+        assert!(list(PQLGame::Holdem, &["AA", "KK"], "*").is_none());
+        assert!(list(PQLGame::Omaha6, &["*", "*"], "2s3h4d5c7s").is_none());
+    }
+
+    #[test]
+    fn test_blocked_hands() {
+        assert_eq!(
+            count_deals(PQLGame::Holdem, &["AA", "AA", "AA"], "2s3h4d5c", 1),
+            0
+        );
+        assert_eq!(
+            count_deals(PQLGame::Holdem, &["AsAh", "AA"], "2s3h4d5c7s", 1),
+            1
+        );
+    }
+
+    #[test]
+    fn test_deals_satisfy_ranges() {
+        let game = PQLGame::Omaha;
+        let (player_ranges, board_range) = mk_ranges(game, &["AAKK", "QQJJ"], "AQ2c3d");
+        let static_data = VmStaticData {
+            game,
+            player_ranges,
+            board_range,
+            n_players: 2,
+            ..Default::default()
+        };
+
+        let hands = VmEnumerator::list_player_hands(&static_data).unwrap();
+
+        VmEnumerator::new(&static_data, &hands, 0, 1)
+            .for_each_deal(&mut |cards| {
+                assert!(static_data.player_ranges[0].is_satisfied(&cards[..4]));
+                assert!(static_data.player_ranges[1].is_satisfied(&cards[4..8]));
+                assert!(static_data.board_range.is_satisfied(&cards[8..]));
+                Ok(())
+            })
+            .unwrap();
+    }
+}
@@ -0,0 +1,151 @@
+use std::borrow::Cow;
+
+use rand::rngs::StdRng;
+
+use super::*;
+
+/// Range weights are whole millionths.
+const WEIGHT_SCALE: PQLDouble = 1_000_000.0;
+
+/// `handsHaving(player, street, expr)`: runs `prog` once per combo of the
+/// player's range that avoids the other hands and the board of the street
+/// popped from the stack, and pushes the fraction of combos for which it
+/// holds. Combos of a weighted range count in proportion to their weight.
+///
+/// A combo may hold cards of the board dealt after the street; `prog` then
+/// sees those board cards dealt again from the cards left, with
+/// [`PQLFnContext::trial_rng`], so it never sees a card twice.
+#[derive(Clone, Debug)]
+pub struct VmHandsHaving {
+    pub player: PQLPlayer,
+    pub prog: VmProgram,
+}
+
+impl VmHandsHaving {
+    pub fn execute(&self, ctx: &mut VmExecContext) -> Result<(), PQLErrorKind> {
+        let street = ctx.stack.downcast_pop::<PQLStreet>();
+        let fn_ctx = &ctx.fn_ctx;
+        let n = fn_ctx.game.player_cards_len() as usize;
+        let idx = usize::from(self.player);
+        let start = idx * n;
+
+        let range = &fn_ctx.player_ranges[idx];
+        let others = fn_ctx.get_c64_players() & !fn_ctx.get_c64_player(self.player);
+        let used = others | fn_ctx.get_c64_board(street);
+        let board = fn_ctx.get_c64_board(PQLStreet::River);
+        let later = board & !fn_ctx.get_c64_board(street);
+        let board_start =
+            PQLFnContext::idx_board_start(fn_ctx.n_players, fn_ctx.game.player_cards_len());
+        // only drawn from, and so seeded, once a combo holds a later card
+        let mut rng = None;
+
+        // ranges too large to list once are enumerated on every call
+        let hands = fn_ctx
+            .range_combos
+            .get(idx)
+            .and_then(RangeCombos::hands)
+            .map_or_else(
+                || Cow::Owned(range_hands(fn_ctx.game, range, used | fn_ctx.dead_cards)),
+                Cow::Borrowed,
+            );
+
+        let mut cards = fn_ctx.sampled_cards.to_vec();
+        let mut n_hands = 0;
+        let mut n_having = 0;
+        let (mut weight, mut weight_having) = (0_i64, 0_i64);
+
+        for hand in hands.iter() {
+            let hand_set = PQLCardSet::from(hand.as_slice());
+
+            if !(hand_set & used).is_empty() {
+                continue;
+            }
+
+            cards[start..start + n].copy_from_slice(hand);
+            cards[board_start..].copy_from_slice(fn_ctx.get_board_slice(PQLStreet::River));
+
+            if !(hand_set & later).is_empty() {
+                redeal_later_board(
+                    fn_ctx,
+                    &mut cards[board_start..],
+                    hand_set,
+                    used | board | fn_ctx.dead_cards,
+                    rng.get_or_insert_with(|| fn_ctx.trial_rng(0)),
+                )?;
+            }
+
+            #[allow(clippy::cast_possible_truncation)]
+            let w = (range.weight(hand) * WEIGHT_SCALE).round() as i64;
+            n_hands += 1;
+            weight += w;
+
+            let mut combo_ctx = VmExecContext {
+                stack: &mut *ctx.stack,
+                heap: &mut *ctx.heap,
+                fn_ctx: PQLFnContext {
+                    sampled_cards: &cards,
+                    ..ctx.fn_ctx.clone()
+                },
+            };
+
+            if self.prog.execute(&mut combo_ctx).map_err(|err| err.kind)? == true.into() {
+                n_having += 1;
+                weight_having += w;
+            }
+        }
+
+        if n_hands == 0 {
+            return Err(RuntimeError::NoConsistentHands.into());
+        }
+
+        let fraction = if range.is_weighted() {
+            weighted_fraction(weight_having, weight)
+        } else {
+            PQLFraction::new(n_having, n_hands)
+        };
+
+        ctx.stack.push(fraction.into());
+
+        Ok(())
+    }
+}
+
+/// Replaces the cards of `board` that `hand` holds with cards that neither
+/// `hand` nor `used` holds.
+fn redeal_later_board(
+    fn_ctx: &PQLFnContext,
+    board: &mut [PQLCard],
+    hand: PQLCardSet,
+    used: PQLCardSet,
+    rng: &mut StdRng,
+) -> Result<(), PQLErrorKind> {
+    let mut card_gen = if fn_ctx.game.is_shortdeck() {
+        CardGen::new::<true>(used | hand)
+    } else {
+        CardGen::new::<false>(used | hand)
+    };
+
+    for card in board.iter_mut().filter(|card| hand.contains_card(**card)) {
+        *card = card_gen.deal(rng).ok_or(RuntimeError::NoConsistentHands)?;
+    }
+
+    Ok(())
+}
+
+/// `num / den` in lowest terms, or to the nearest millionth if that does not
+/// fit a fraction.
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+fn weighted_fraction(num: i64, den: i64) -> PQLFraction {
+    let (mut a, mut b) = (num, den);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    match (i32::try_from(num / a), i32::try_from(den / a)) {
+        (Ok(num), Ok(den)) => PQLFraction::new(num, den),
+        _ => PQLFraction::new(
+            (num as PQLDouble / den as PQLDouble * WEIGHT_SCALE).round() as i32,
+            WEIGHT_SCALE as i32,
+        ),
+    }
+}
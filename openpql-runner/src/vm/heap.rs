@@ -2,15 +2,53 @@ use super::*;
 
 /// Virtual Heap
 /// stores non-copy values
-#[derive(Clone, Debug, Default, derive_more::DerefMut, derive_more::Deref, derive_more::From)]
+#[derive(Clone, Debug, Default, derive_more::DerefMut, derive_more::Deref)]
 pub struct VmHeap {
+    #[deref]
+    #[deref_mut]
     inner: Vec<VmHeapValue>,
+    /// strings created at runtime, so that repeated values share a slot
+    interned: FxHashMap<PQLString, HeapIdx>,
+    /// first slot of the strings created at runtime, once there are any
+    scratch_start: Option<HeapIdx>,
 }
 
 impl VmHeap {
     pub fn get_ref<T: 'static>(&self, i: HeapIdx) -> &T {
         self[i].as_any().downcast_ref::<T>().unwrap()
     }
+
+    /// Stores a string produced at runtime and returns its index; the slot
+    /// lives until the next [`Self::clear_scratch`].
+    pub fn intern_str(&mut self, s: PQLString) -> HeapIdx {
+        if let Some(&i) = self.interned.get(&s) {
+            return i;
+        }
+
+        let i = self.len();
+        self.scratch_start.get_or_insert(i);
+        self.interned.insert(s.clone(), i);
+        self.push(s.into());
+
+        i
+    }
+
+    /// Drops the strings produced at runtime, e.g. between trials, so the
+    /// heap does not grow with the number of distinct values.
+    pub fn clear_scratch(&mut self) {
+        if let Some(start) = self.scratch_start.take() {
+            self.inner.truncate(start);
+            self.interned.clear();
+        }
+    }
+
+    /// Strings compare by content, other values by slot.
+    pub fn value_eq(&self, lhs: HeapIdx, rhs: HeapIdx) -> bool {
+        match (&self[lhs], &self[rhs]) {
+            (VmHeapValue::Str(l), VmHeapValue::Str(r)) => l == r,
+            _ => lhs == rhs,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -29,10 +67,41 @@ mod tests {
                 range.clone().into(),
                 board.clone().into(),
             ],
+            ..Default::default()
         };
 
         assert_eq!(heap.get_ref::<PQLString>(0), "test");
         assert!(heap.get_ref::<PQLRange>(1).src_eq(&range));
         assert!(heap.get_ref::<PQLBoardRange>(2).src_eq(&board));
     }
+
+    #[test]
+    fn test_intern_str() {
+        let mut heap = VmHeap::default();
+        heap.push("FLUSH".to_string().into());
+
+        let i = heap.intern_str("FLUSH".to_string());
+        assert_eq!(i, 1);
+        assert_eq!(heap.intern_str("FLUSH".to_string()), i);
+        assert_eq!(heap.intern_str("PAIR".to_string()), 2);
+
+        assert!(heap.value_eq(0, i));
+        assert!(!heap.value_eq(0, 2));
+    }
+
+    #[test]
+    fn test_clear_scratch() {
+        let mut heap = VmHeap::default();
+        heap.push("FLUSH".to_string().into());
+        heap.clear_scratch();
+        assert_eq!(heap.len(), 1);
+
+        heap.intern_str("PAIR".to_string());
+        heap.intern_str("TRIPS".to_string());
+        heap.clear_scratch();
+        assert_eq!(heap.len(), 1);
+
+        assert_eq!(heap.intern_str("TRIPS".to_string()), 1);
+        assert_eq!(heap.get_ref::<PQLString>(1), "TRIPS");
+    }
 }
@@ -5,6 +5,7 @@ pub enum VmInstruction {
     #[debug("Push({_0})")]
     Push(VmStackValue),
     FnCall(&'static dyn PQLFn),
+    HandsHaving(Box<VmHandsHaving>),
     BinOp(VmBinOp),
     UnaryOp(VmUnaryOp),
     CastNum(PQLType),
@@ -17,6 +18,7 @@ impl VmInstruction {
             Self::FnCall(proc) => {
                 proc.execute(ctx).map(|val| ctx.stack.push(val))?;
             }
+            Self::HandsHaving(hh) => hh.execute(ctx)?,
             Self::BinOp(op) => op.execute(ctx)?,
             Self::UnaryOp(op) => op.execute(ctx)?,
             Self::CastNum(kind) => {
@@ -7,10 +7,13 @@ mod binop_logic;
 mod cache;
 mod compiler;
 mod context;
+mod enumerator;
+mod hands_having;
 mod heap;
 mod heap_value;
 mod instruction;
 mod program;
+mod range_hands;
 mod sampled_data;
 mod stack;
 mod stack_value;
@@ -27,10 +30,13 @@ pub use binop_logic::*;
 pub use cache::*;
 pub use compiler::*;
 pub use context::*;
+pub use enumerator::*;
+pub use hands_having::*;
 pub use heap::*;
 pub use heap_value::*;
 pub use instruction::*;
 pub use program::*;
+pub use range_hands::*;
 pub use sampled_data::*;
 pub use stack::*;
 pub use stack_value::*;
@@ -0,0 +1,109 @@
+use super::*;
+
+pub type Hands = Vec<Vec<PQLCard>>;
+
+/// Cards of the deck of `game` outside `dead`.
+fn live_cards(game: PQLGame, dead: PQLCardSet) -> Vec<PQLCard> {
+    let all = if game.is_shortdeck() {
+        PQLCard::all::<true>()
+    } else {
+        PQLCard::all::<false>()
+    };
+
+    all.iter()
+        .copied()
+        .filter(|&c| !dead.contains_card(c))
+        .collect()
+}
+
+/// Every `n`-card combo of the cards outside `dead` that satisfies
+/// `predicate`; `None` once there are more than `max`.
+///
+/// Cards are added one at a time and a partial combo is only extended
+/// while `predicate` still holds for it, so narrow ranges are listed
+/// without walking the whole deck.
+pub fn list_hands(
+    game: PQLGame,
+    predicate: &FnCheckRange,
+    n: usize,
+    dead: PQLCardSet,
+    max: usize,
+) -> Option<Hands> {
+    fn extend(
+        predicate: &FnCheckRange,
+        live: &[PQLCard],
+        n: usize,
+        max: usize,
+        hand: &mut Vec<PQLCard>,
+        hands: &mut Hands,
+    ) -> Option<()> {
+        if hand.len() == n {
+            if hands.len() == max {
+                return None;
+            }
+
+            hands.push(hand.clone());
+            return Some(());
+        }
+
+        for (i, &card) in live.iter().enumerate() {
+            hand.push(card);
+
+            if predicate(hand) {
+                extend(predicate, &live[i + 1..], n, max, hand, hands)?;
+            }
+
+            hand.pop();
+        }
+
+        Some(())
+    }
+
+    let mut hands = vec![];
+    extend(
+        predicate,
+        &live_cards(game, dead),
+        n,
+        max,
+        &mut vec![],
+        &mut hands,
+    )?;
+
+    Some(hands)
+}
+
+/// Every hand of `range` that avoids `dead`.
+pub fn range_hands(game: PQLGame, range: &PQLRange, dead: PQLCardSet) -> Hands {
+    let n = game.player_cards_len() as usize;
+
+    list_hands(game, &range.0, n, dead, usize::MAX).unwrap_or_default()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_range_hands() {
+        let (ranges, _) = mk_ranges(PQLGame::Holdem, &["AA", "AsK"], "*");
+        let dead = PQLCardSet::from(cards!("Ah").as_slice());
+
+        let aces = range_hands(PQLGame::Holdem, &ranges[0], dead);
+        assert_eq!(aces.len(), 3);
+        assert!(aces.iter().all(|hand| !hand.contains(&cards!("Ah")[0])));
+
+        let hands = range_hands(PQLGame::Holdem, &ranges[1], PQLCardSet::default());
+        assert_eq!(hands.len(), 4);
+        assert!(hands.iter().all(|hand| hand.contains(&cards!("As")[0])));
+    }
+
+    #[test]
+    fn test_list_hands_max() {
+        let (ranges, _) = mk_ranges(PQLGame::Holdem, &["AA"], "*");
+        let list = |max| list_hands(PQLGame::Holdem, &ranges[0].0, 2, PQLCardSet::default(), max);
+
+        assert_eq!(list(6).map(|hands| hands.len()), Some(6));
+        assert_eq!(list(5), None);
+    }
+}
@@ -1,13 +1,95 @@
+use std::sync::Arc;
+
+use rand::{RngExt, seq::SliceRandom};
+
 use super::*;
 
-/// Sampled Data
-/// all rng related values
-#[derive(Clone, Debug, Default, derive_more::From)]
-pub struct VmSampledData {
-    pub(crate) cards: Vec<PQLCard>,
-    card_gen: CardGen,
-    pub(crate) n_players: PQLCardCount,
-    pub(crate) n_holecards: PQLCardCount,
+/// Draws of a deal (or of one hand) before the sample is given up.
+const MAX_REDRAWS: usize = 1000;
+
+/// Ranges with more combos are not listed; their combos are drawn from the
+/// live cards until one falls in the range, which then succeeds often.
+const MAX_LISTED_COMBOS: usize = 1 << 18;
+
+/// Combos of a player's range that avoid the dead cards.
+#[derive(Clone, Debug)]
+pub enum RangeCombos {
+    /// `cum_weights` holds the running sums of the combo weights of a
+    /// weighted range and is empty otherwise.
+    Listed {
+        hands: Hands,
+        cum_weights: Vec<PQLDouble>,
+    },
+    Unlisted,
+}
+
+impl RangeCombos {
+    fn new(game: PQLGame, range: &PQLRange, dead: PQLCardSet) -> Self {
+        let n = game.player_cards_len() as usize;
+
+        list_hands(game, &range.0, n, dead, MAX_LISTED_COMBOS).map_or(Self::Unlisted, |hands| {
+            let cum_weights = if range.is_weighted() {
+                hands
+                    .iter()
+                    .scan(0.0, |sum, hand| {
+                        *sum += range.weight(hand);
+                        Some(*sum)
+                    })
+                    .collect()
+            } else {
+                vec![]
+            };
+
+            Self::Listed { hands, cum_weights }
+        })
+    }
+
+    /// Listed combos; `None` for ranges too large to list.
+    pub const fn hands(&self) -> Option<&Hands> {
+        match self {
+            Self::Listed { hands, .. } => Some(hands),
+            Self::Unlisted => None,
+        }
+    }
+
+    /// Writes a combo drawn from the range in proportion to its weight into
+    /// `hand`; `card_gen` holds the live cards for unlisted ranges.
+    fn draw(
+        &self,
+        rng: &mut impl rand::Rng,
+        range: &PQLRange,
+        card_gen: &CardGen,
+        hand: &mut [PQLCard],
+    ) -> Option<PQLCardSet> {
+        match self {
+            Self::Listed { hands, .. } if hands.is_empty() => None,
+            Self::Listed { hands, cum_weights } => {
+                let idx = match cum_weights.last() {
+                    Some(&total) if total > 0.0 => {
+                        let x = rng.random_range(0.0..total);
+                        cum_weights.partition_point(|&sum| sum <= x)
+                    }
+                    Some(_) => return None,
+                    None => rng.random_range(0..hands.len()),
+                };
+
+                hand.copy_from_slice(&hands[idx]);
+
+                Some(PQLCardSet::from(&*hand))
+            }
+            // a weighted combo is kept with the probability of its weight
+            Self::Unlisted => (0..MAX_REDRAWS).find_map(|_| {
+                let mut card_gen = card_gen.clone();
+                for card in &mut *hand {
+                    *card = card_gen.deal(rng)?;
+                }
+
+                (range.is_satisfied(hand)
+                    && (!range.is_weighted() || rng.random_bool(range.weight(hand))))
+                .then(|| PQLCardSet::from(&*hand))
+            }),
+        }
+    }
 }
 
 fn gen_card(
@@ -31,34 +113,75 @@ fn gen_card(
     }
 }
 
-fn gen_cards(
-    rng: &mut impl rand::Rng,
-    card_gen: &mut CardGen,
-    hand: &mut [PQLCard],
-    predicate: &FnCheckRange,
-) -> Option<()> {
-    for i in 0..hand.len() {
-        gen_card(rng, card_gen, hand, i, predicate)?;
-    }
-
-    Some(())
+/// Sampled Data
+/// all rng related values
+///
+/// Hands are drawn as whole combos, uniformly over every deal of the
+/// player ranges that avoids the dead cards; the flop is drawn from the
+/// listed flops of the board range. Only when whole deals keep colliding
+/// does [`Self::sample_player_cards_in_turn`] take over, and that fallback
+/// is not uniform.
+#[derive(Clone, Debug, Default)]
+pub struct VmSampledData {
+    pub(crate) cards: Vec<PQLCard>,
+    game: PQLGame,
+    dead_cards: PQLCardSet,
+    combos: Arc<Vec<RangeCombos>>,
+    flops: Arc<Hands>,
+    pub(crate) n_players: PQLCardCount,
+    pub(crate) n_holecards: PQLCardCount,
+    /// Seed for functions that draw cards of their own; see
+    /// [`PQLFnContext::trial_seed`].
+    pub(crate) seed: u64,
+    /// Set once a function drew cards of its own; see
+    /// [`PQLFnContext::trial_rng`]. Clones share it until given their own.
+    pub(crate) drew_cards: Arc<AtomicBool>,
 }
 
 impl VmSampledData {
-    pub fn new(game: PQLGame, n_players: PQLPlayerCount, dead_cards: PQLCardSet) -> Self {
-        let card_gen = if game.is_shortdeck() {
-            CardGen::new::<true>(dead_cards)
-        } else {
-            CardGen::new::<false>(dead_cards)
-        };
+    pub fn new(static_data: &VmStaticData) -> Self {
+        let game = static_data.game;
+        let dead_cards = static_data.dead_card;
         let n_holecards = game.player_cards_len();
-        let n_total = PQLFnContext::n_total_cards(n_players, n_holecards);
+        let n_total = PQLFnContext::n_total_cards(static_data.n_players, n_holecards);
+
+        let combos = static_data
+            .player_ranges
+            .iter()
+            .map(|range| RangeCombos::new(game, range, dead_cards))
+            .collect();
+        let flops = list_hands(
+            game,
+            &static_data.board_range.0,
+            PQLBoard::N_FLOP,
+            dead_cards,
+            usize::MAX,
+        )
+        .unwrap();
 
         Self {
             cards: vec![PQLCard::default(); n_total],
-            card_gen,
-            n_players,
+            game,
+            dead_cards,
+            combos: Arc::new(combos),
+            flops: Arc::new(flops),
+            n_players: static_data.n_players,
             n_holecards,
+            seed: 0,
+            drew_cards: Arc::default(),
+        }
+    }
+
+    /// Listed combos of each player's range, in seat order.
+    pub(crate) fn range_combos(&self) -> &[RangeCombos] {
+        &self.combos
+    }
+
+    fn card_gen(&self, used: PQLCardSet) -> CardGen {
+        if self.game.is_shortdeck() {
+            CardGen::new::<true>(used)
+        } else {
+            CardGen::new::<false>(used)
         }
     }
 
@@ -68,39 +191,97 @@ impl VmSampledData {
         player_ranges: &[PQLRange],
         board_range: &PQLBoardRange,
     ) -> Option<()> {
-        self.card_gen.reset();
+        let used = (0..MAX_REDRAWS)
+            .find_map(|_| self.sample_player_cards(rng, player_ranges))
+            .or_else(|| self.sample_player_cards_in_turn(rng, player_ranges))?;
 
-        self.sample_player_cards(rng, player_ranges)?;
-        self.sample_board_cards(rng, board_range)?;
+        self.sample_board_cards(rng, board_range, used | self.dead_cards)?;
+        self.seed = rng.random();
 
         Some(())
     }
 
+    /// Draws every hand on its own; `None` when two of them collide.
     fn sample_player_cards(
         &mut self,
         rng: &mut impl rand::Rng,
         player_ranges: &[PQLRange],
-    ) -> Option<()> {
+    ) -> Option<PQLCardSet> {
         let n = self.n_holecards as usize;
+        let card_gen = self.card_gen(self.dead_cards);
+        let mut used = PQLCardSet::default();
 
         for (i, range) in player_ranges.iter().enumerate() {
             let hand = &mut self.cards[i * n..(i + 1) * n];
+            let hand_set = self.combos[i].draw(rng, range, &card_gen, hand)?;
+
+            if !(hand_set & used).is_empty() {
+                return None;
+            }
 
-            gen_cards(rng, &mut self.card_gen, hand, &range.0)?;
+            used |= hand_set;
         }
 
-        Some(())
+        Some(used)
+    }
+
+    /// Fallback for crowded tables, where whole deals rarely avoid each
+    /// other: deals the hands one at a time in random order, each uniformly
+    /// among the combos that avoid the hands before it.
+    ///
+    /// Each hand is uniform given the ones before it, but the deal as a
+    /// whole is not: a combo that blocks many combos of the players after
+    /// it is dealt as often as one that blocks none. This holds for listed
+    /// and unlisted ranges alike.
+    fn sample_player_cards_in_turn(
+        &mut self,
+        rng: &mut impl rand::Rng,
+        player_ranges: &[PQLRange],
+    ) -> Option<PQLCardSet> {
+        let n = self.n_holecards as usize;
+        let card_gen = self.card_gen(self.dead_cards);
+        let mut order: Vec<_> = (0..player_ranges.len()).collect();
+        order.shuffle(rng);
+
+        order
+            .into_iter()
+            .try_fold(PQLCardSet::default(), |used, i| {
+                let hand = &mut self.cards[i * n..(i + 1) * n];
+
+                (0..MAX_REDRAWS).find_map(|_| {
+                    let hand_set = self.combos[i].draw(rng, &player_ranges[i], &card_gen, hand)?;
+
+                    (hand_set & used).is_empty().then_some(used | hand_set)
+                })
+            })
     }
 
     fn sample_board_cards(
         &mut self,
         rng: &mut impl rand::Rng,
         board_range: &PQLBoardRange,
+        used: PQLCardSet,
     ) -> Option<()> {
+        if self.flops.is_empty() {
+            return None;
+        }
+
+        let (flop, flop_set) = (0..MAX_REDRAWS).find_map(|_| {
+            let flop = &self.flops[rng.random_range(0..self.flops.len())];
+            let flop_set = PQLCardSet::from(flop.as_slice());
+
+            (flop_set & used).is_empty().then_some((flop, flop_set))
+        })?;
+
+        let mut card_gen = self.card_gen(used | flop_set);
         let i = PQLFnContext::idx_board_start(self.n_players, self.n_holecards);
         let board = &mut self.cards[i..i + PQLBoard::N_RIVER];
 
-        gen_cards(rng, &mut self.card_gen, board, &board_range.0)?;
+        board[..PQLBoard::N_FLOP].copy_from_slice(flop);
+
+        for idx in PQLBoard::N_FLOP..PQLBoard::N_RIVER {
+            gen_card(rng, &mut card_gen, board, idx, &board_range.0)?;
+        }
 
         Some(())
     }
@@ -108,24 +289,43 @@ impl VmSampledData {
 
 #[cfg(test)]
 pub mod tests {
+    use std::hash::Hash;
+
+    use rand::{SeedableRng, rngs::StdRng};
+
     use super::*;
     use crate::*;
 
+    fn mk_static_data(game: PQLGame, player: &[&str], board: &str) -> VmStaticData {
+        let (player_ranges, board_range) = mk_ranges(game, player, board);
+
+        VmStaticData {
+            game,
+            player_ranges,
+            board_range,
+            n_players: PQLPlayerCount::try_from(player.len()).unwrap(),
+            ..Default::default()
+        }
+    }
+
     fn mk_sample_fn(
         game: PQLGame,
         player: &[&str],
         board: &str,
     ) -> impl FnMut() -> Option<Vec<PQLCard>> {
-        let (ps, b) = mk_ranges(game, player, board);
+        let static_data = mk_static_data(game, player, board);
 
         move || {
-            let mut sampler = VmSampledData::new(
-                game,
-                PQLCardCount::try_from(player.len()).unwrap(),
-                PQLCardSet::default(),
-            );
-
-            if sampler.sample(&mut rand::rng(), &ps, &b).is_some() {
+            let mut sampler = VmSampledData::new(&static_data);
+
+            if sampler
+                .sample(
+                    &mut rand::rng(),
+                    &static_data.player_ranges,
+                    &static_data.board_range,
+                )
+                .is_some()
+            {
                 Some(sampler.cards)
             } else {
                 None
@@ -144,6 +344,7 @@ pub mod tests {
 
         let i = ps.len() * n;
         assert!(b.is_satisfied(&res[i..i + 5]));
+        assert_eq!(PQLCardSet::from(res.as_slice()).count() as usize, res.len());
     }
 
     #[test]
@@ -154,6 +355,7 @@ pub mod tests {
     #[test]
     fn test_omaha() {
         assert_sample(PQLGame::Omaha, &["AAAA", "KKKK"], "***JT");
+        assert_sample(PQLGame::Omaha, &["*", "AK"], "*");
     }
 
     #[test]
@@ -161,6 +363,11 @@ pub mod tests {
         assert_sample(PQLGame::ShortDeck, &["*", "*"], "***JT");
     }
 
+    #[test]
+    fn test_crowded_table() {
+        assert_sample(PQLGame::Omaha, &["*"; 10], "*");
+    }
+
     fn assert_none(game: PQLGame, player: &[&str], board: &str) {
         assert!(mk_sample_fn(game, player, board)().is_none());
     }
@@ -172,4 +379,255 @@ pub mod tests {
         assert_none(game, &["AA"], "AAA");
         assert_none(game, &["AA", "AA", "AA"], "*");
     }
+
+    fn sorted(mut hands: Hands) -> Hands {
+        for hand in &mut hands {
+            hand.sort_unstable();
+        }
+        hands.sort_unstable();
+        hands
+    }
+
+    #[test]
+    fn test_listed_combos() {
+        for (game, src) in [
+            (PQLGame::Holdem, "AA,AKs,T9"),
+            (PQLGame::ShortDeck, "*"),
+            (PQLGame::Omaha, "AAKK,AsKsQsJs"),
+        ] {
+            let (ranges, _) = mk_ranges(game, &[src], "*");
+            let dead = c64!("Ac");
+
+            let RangeCombos::Listed { hands: combos, .. } =
+                RangeCombos::new(game, &ranges[0], dead)
+            else {
+                panic!("{src} should be listed");
+            };
+
+            assert_eq!(
+                sorted(combos),
+                sorted(range_hands(game, &ranges[0], dead)),
+                "{src}"
+            );
+        }
+
+        let (ranges, _) = mk_ranges(PQLGame::Omaha6, &["AhKh2c3d9s4c"], "*");
+        let RangeCombos::Listed { hands: combos, .. } =
+            RangeCombos::new(PQLGame::Omaha6, &ranges[0], PQLCardSet::default())
+        else {
+            panic!("a single hand should be listed");
+        };
+        assert_eq!(sorted(combos), [cards!("2c 3d 4c 9s Kh Ah")]);
+
+        let (ranges, _) = mk_ranges(PQLGame::Omaha5, &["*"], "*");
+        assert!(matches!(
+            RangeCombos::new(PQLGame::Omaha5, &ranges[0], PQLCardSet::default()),
+            RangeCombos::Unlisted
+        ));
+    }
+
+    const N_SAMPLES: usize = 20_000;
+
+    /// Frequencies of the cards of player `idx` over `N_SAMPLES` seeded deals.
+    fn hand_counts(
+        game: PQLGame,
+        player: &[&str],
+        board: &str,
+        idx: usize,
+    ) -> FxHashMap<PQLCardSet, usize> {
+        let static_data = mk_static_data(game, player, board);
+        let mut sampler = VmSampledData::new(&static_data);
+        let mut rng = StdRng::seed_from_u64(0);
+        let n = game.player_cards_len() as usize;
+        let mut counts = FxHashMap::default();
+
+        for _ in 0..N_SAMPLES {
+            sampler
+                .sample(
+                    &mut rng,
+                    &static_data.player_ranges,
+                    &static_data.board_range,
+                )
+                .unwrap();
+
+            let hand = PQLCardSet::from(&sampler.cards[idx * n..(idx + 1) * n]);
+            *counts.entry(hand).or_default() += 1;
+        }
+
+        counts
+    }
+
+    /// Pearson's statistic of `counts` against `n_combos` equally likely
+    /// combos.
+    #[allow(clippy::cast_precision_loss)]
+    fn chi_squared(counts: &FxHashMap<PQLCardSet, usize>, n_combos: usize) -> f64 {
+        assert_eq!(counts.len(), n_combos);
+
+        let expected = N_SAMPLES as f64 / n_combos as f64;
+
+        counts
+            .values()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum()
+    }
+
+    // upper 0.1% points of the chi-squared distribution
+    const CHI_SQUARED_1: f64 = 10.828;
+    const CHI_SQUARED_2: f64 = 13.816;
+    const CHI_SQUARED_7: f64 = 24.322;
+    const CHI_SQUARED_9: f64 = 27.877;
+    const CHI_SQUARED_14: f64 = 36.123;
+
+    #[test]
+    fn test_uniform_over_pairs_and_unpaired() {
+        // 6 pairs and 4 suited combos used to get different weights
+        let counts = hand_counts(PQLGame::Holdem, &["AA,AwKw"], "*", 0);
+
+        assert!(chi_squared(&counts, 10) < CHI_SQUARED_9, "{counts:?}");
+    }
+
+    #[test]
+    fn test_uniform_with_dead_cards_of_others() {
+        // 8 of the 16 AK combos share an ace with villain's AhAd
+        let counts = hand_counts(PQLGame::Holdem, &["AK", "AhAd"], "*", 0);
+
+        assert!(chi_squared(&counts, 8) < CHI_SQUARED_7, "{counts:?}");
+    }
+
+    #[test]
+    fn test_uniform_with_exclusion() {
+        // AhKs and AsKh start like the excluded AsKs
+        let counts = hand_counts(PQLGame::Holdem, &["AK!AsKs"], "*", 0);
+
+        assert!(chi_squared(&counts, 15) < CHI_SQUARED_14, "{counts:?}");
+    }
+
+    /// Statistic of the counts of `keys` against their probabilities.
+    #[allow(clippy::cast_precision_loss)]
+    fn chi_squared_of<K: Eq + Hash>(counts: &FxHashMap<K, usize>, probs: &[(K, f64)]) -> f64 {
+        probs
+            .iter()
+            .map(|(key, p)| {
+                let expected = N_SAMPLES as f64 * p;
+                (counts.get(key).copied().unwrap_or_default() as f64 - expected).powi(2) / expected
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_no_priority_in_from_order() {
+        // the three deals (AA, QQ), (KK, AA) and (KK, QQ) are equally likely
+        let counts = hand_counts(PQLGame::Holdem, &["AsAh,KsKh", "AsAh,QsQh"], "*", 0);
+        let probs = [(c64!("AsAh"), 1.0 / 3.0), (c64!("KsKh"), 2.0 / 3.0)];
+
+        assert!(
+            chi_squared_of(&counts, &probs) < CHI_SQUARED_2,
+            "{counts:?}"
+        );
+    }
+
+    #[test]
+    fn test_weighted() {
+        let counts = hand_counts(PQLGame::Holdem, &["AsAh@25,KsKh"], "*", 0);
+        let probs = [(c64!("AsAh"), 0.2), (c64!("KsKh"), 0.8)];
+
+        assert!(
+            chi_squared_of(&counts, &probs) < CHI_SQUARED_1,
+            "{counts:?}"
+        );
+    }
+
+    #[test]
+    fn test_in_turn_unlisted() {
+        // with villain's hand fixed, 571341 of the live hands hold an ace:
+        // 493640 one, 74046 two and 3655 more
+        let static_data = mk_static_data(PQLGame::Omaha5, &["A****", "KsKhKdKc2s"], "*");
+        let mut sampler = VmSampledData::new(&static_data);
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut by_aces = FxHashMap::default();
+
+        assert!(matches!(sampler.combos[0], RangeCombos::Unlisted));
+
+        let mut n_dealt = 0;
+
+        while n_dealt < N_SAMPLES {
+            // hero dealt first may block villain's only combo
+            if sampler
+                .sample_player_cards_in_turn(&mut rng, &static_data.player_ranges)
+                .is_none()
+            {
+                continue;
+            }
+            n_dealt += 1;
+
+            let hero = PQLCardSet::from(&sampler.cards[..5]);
+            let villain = PQLCardSet::from(&sampler.cards[5..10]);
+            assert!(static_data.player_ranges[0].is_satisfied(&sampler.cards[..5]));
+            assert!((hero & villain).is_empty());
+
+            let n_aces = (hero & c64!("As Ah Ad Ac")).count().min(3);
+            *by_aces.entry(n_aces).or_default() += 1;
+        }
+
+        let probs = [
+            (1, 493_640.0 / 571_341.0),
+            (2, 74_046.0 / 571_341.0),
+            (3, 3655.0 / 571_341.0),
+        ];
+
+        assert!(
+            chi_squared_of(&by_aces, &probs) < CHI_SQUARED_2,
+            "{by_aces:?}"
+        );
+    }
+
+    #[test]
+    fn test_weighted_unlisted() {
+        // 626296 hands with an ace and no king, as many the other way
+        // round and 260360 with both, which take the larger weight
+        let counts = hand_counts(PQLGame::Omaha5, &["[0.25]A****,K****"], "*", 0);
+        let by_class = counts
+            .iter()
+            .fold(FxHashMap::default(), |mut acc, (hand, &count)| {
+                let has_ace = (*hand & c64!("As Ah Ad Ac")).count() > 0;
+                let has_king = (*hand & c64!("Ks Kh Kd Kc")).count() > 0;
+                *acc.entry((has_ace, has_king)).or_default() += count;
+                acc
+            });
+        let total = 0.25f64.mul_add(626_296.0, 626_296.0 + 260_360.0);
+        let probs = [
+            ((true, false), 0.25 * 626_296.0 / total),
+            ((false, true), 626_296.0 / total),
+            ((true, true), 260_360.0 / total),
+        ];
+
+        assert!(
+            chi_squared_of(&by_class, &probs) < CHI_SQUARED_2,
+            "{by_class:?}"
+        );
+    }
+
+    #[test]
+    fn test_uniform_unlisted() {
+        // 886656 hands with an ace: 778320 with one, 103776 with two and
+        // 4560 with more
+        let counts = hand_counts(PQLGame::Omaha5, &["A****"], "*", 0);
+        let by_aces = counts
+            .iter()
+            .fold(FxHashMap::default(), |mut acc, (hand, &count)| {
+                let n_aces = (*hand & c64!("As Ah Ad Ac")).count().min(3);
+                *acc.entry(n_aces).or_default() += count;
+                acc
+            });
+        let probs = [
+            (1, 778_320.0 / 886_656.0),
+            (2, 103_776.0 / 886_656.0),
+            (3, 4560.0 / 886_656.0),
+        ];
+
+        assert!(
+            chi_squared_of(&by_aces, &probs) < CHI_SQUARED_2,
+            "{by_aces:?}"
+        );
+    }
 }
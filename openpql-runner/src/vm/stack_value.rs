@@ -45,6 +45,12 @@ macro_rules! sval {
     (@ranks $val:expr) => {
         VmStackValue::Ranks(r16!($val))
     };
+    (@cards $val:expr) => {
+        VmStackValue::Cards(c64!($val))
+    };
+    (@drawtype $val:expr) => {
+        VmStackValue::DrawType($val)
+    };
     (@flopcat $val:expr) => {
         VmStackValue::FlopCategory($val)
     };
@@ -54,6 +60,9 @@ macro_rules! sval {
     (@rating $val:expr) => {
         VmStackValue::Rating(mk_rating($val))
     };
+    (@lorating $val:expr) => {
+        VmStackValue::LoRating(mk_lo_rating($val))
+    };
 }
 
 /// `StackValue`
@@ -70,14 +79,17 @@ pub enum VmStackValue {
     Frac(PQLFraction),
     Double(PQLDouble),
     Card(PQLCard),
+    Cards(PQLCardSet),
     Rank(PQLRank),
     Ranks(PQLRankSet),
     Street(PQLStreet),
     #[display("{_0}")]
     FlopCategory(PQLFlopHandCategory),
+    DrawType(PQLDrawType),
     HandType(PQLHandType),
     #[display("{_0:?}")]
     Rating(PQLHiRating),
+    LoRating(PQLLoRating),
     Player(PQLPlayer),
 }
 
@@ -116,11 +128,14 @@ impl From<VmStackValue> for PQLType {
             VmStackValue::Player(_) => Self::PLAYER,
             VmStackValue::Street(_) => Self::STREET,
             VmStackValue::Card(_) => Self::CARD,
+            VmStackValue::Cards(_) => Self::CARDSET,
             VmStackValue::Rank(_) => Self::RANK,
             VmStackValue::Ranks(_) => Self::RANKSET,
             VmStackValue::HandType(_) => Self::HANDTYPE,
             VmStackValue::Rating(_) => Self::HIRATING,
+            VmStackValue::LoRating(_) => Self::LORATING,
             VmStackValue::FlopCategory(_) => Self::FLOPHANDCATEGORY,
+            VmStackValue::DrawType(_) => Self::DRAWTYPE,
         }
     }
 }
@@ -150,6 +165,7 @@ mod tests {
         assert_type(sval!(@frac 0, 1), PQLType::FRACTION);
         assert_type(sval!(@float 1.0), PQLType::DOUBLE);
         assert_type(sval!(@card "As"), PQLType::CARD);
+        assert_type(sval!(@cards "As Kh"), PQLType::CARDSET);
         assert_type(sval!(@rank PQLRank::RA), PQLType::RANK);
         assert_type(sval!(@ranks "AK"), PQLType::RANKSET);
         assert_type(sval!(@street river), PQLType::STREET);
@@ -157,8 +173,10 @@ mod tests {
             sval!(@flopcat PQLFlopHandCategory::Nothing),
             PQLType::FLOPHANDCATEGORY,
         );
+        assert_type(sval!(@drawtype PQLDrawType::Gutshot), PQLType::DRAWTYPE);
         assert_type(sval!(@handtype PQLHandType::Trips), PQLType::HANDTYPE);
         assert_type(sval!(@rating "2s2h2cAsAh"), PQLType::HIRATING);
+        assert_type(sval!(@lorating "As2h3c4d8s"), PQLType::LORATING);
         assert_type(sval!(@player 1), PQLType::PLAYER);
     }
 }
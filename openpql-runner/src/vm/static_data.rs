@@ -13,6 +13,7 @@ pub struct VmStaticData {
     pub dead_card: PQLCardSet,
     pub n_players: PQLPlayerCount,
     pub n_trails: Trials,
+    pub seed: Option<u64>,
 }
 
 impl VmStaticData {
@@ -22,6 +23,24 @@ impl VmStaticData {
     #[cfg(debug_assertions)]
     pub const DEFAULT_N_TRIALS: Trials = 100;
 
+    /// hard cap of adaptive runs without an explicit trial limit
+    #[cfg(not(debug_assertions))]
+    pub const MAX_ADAPTIVE_TRIALS: Trials = 10_000_000;
+
+    #[cfg(debug_assertions)]
+    pub const MAX_ADAPTIVE_TRIALS: Trials = 10_000;
+
+    /// deals past which an exact run is refused; see
+    /// [`VmEnumerator::n_deals_bound`]
+    pub const MAX_EXACT_DEALS: usize = 20_000_000;
+
+    /// trials per thread between two precision checks of adaptive runs
+    #[cfg(not(debug_assertions))]
+    pub const N_BATCH_TRIALS: Trials = 10_000;
+
+    #[cfg(debug_assertions)]
+    pub const N_BATCH_TRIALS: Trials = 100;
+
     #[allow(clippy::cast_possible_truncation)]
     pub(crate) fn find_player(&self, name: &str) -> Option<PQLPlayer> {
         self.player_names
@@ -30,39 +49,109 @@ impl VmStaticData {
             .map(|idx| PQLPlayer::from(idx as PlayerIdx))
     }
 
+    /// Reads the `from` clause with its `$name` placeholders bound to
+    /// `params`; with `None` they keep their defaults, which is enough to
+    /// compile the statement before any value is bound.
+    pub(crate) fn bind(fc: &ast::FromClause<'_>, params: Option<&Params>) -> PQLResult<Self> {
+        let game = Self::get_game(fc)?;
+        let (player_names, player_ranges) = Self::get_players(game, fc, params)?;
+        let board_range = Self::get_board(game, fc, params)?;
+        let dead_card = Self::get_deadcard(fc, params)?;
+        let seed = Self::get_seed(fc, params)?;
+        let n_usize = player_names.len();
+        let n_players = if n_usize <= 10 {
+            PQLPlayerCount::try_from(n_usize).unwrap()
+        } else {
+            return Err((fc.loc, PQLErrorKind::ExceededMaximumPlayers(n_usize)).into());
+        };
+
+        Ok(Self {
+            game,
+            player_names,
+            player_ranges,
+            board_range,
+            dead_card,
+            n_players,
+            seed,
+            ..Default::default()
+        })
+    }
+
+    /// Returns the text of `value`, or `None` for an absent value or a
+    /// placeholder of a statement that is not bound yet.
+    fn lookup<'a>(
+        value: Option<&'a ast::FromValue<'a>>,
+        params: Option<&'a Params>,
+    ) -> PQLResult<Option<&'a str>> {
+        match (value, params) {
+            (Some(ast::FromValue::Str(s)), _) => Ok(Some(s.inner)),
+            (Some(ast::FromValue::Param(p)), Some(params)) => params
+                .get(p.name)
+                .map(Some)
+                .ok_or_else(|| (p.loc, PQLErrorKind::UnboundParameter).into()),
+            _ => Ok(None),
+        }
+    }
+
+    // the game decides how range literals compile, so it cannot be bound later
     fn get_game(fc: &ast::FromClause<'_>) -> PQLResult<PQLGame> {
-        fc.get_game().map_or_else(
-            || Ok(PQLGame::default()),
-            |game| with_loc(game, || game.inner.parse::<PQLGame>()),
-        )
+        match fc.get_game() {
+            None => Ok(PQLGame::default()),
+            Some(ast::FromValue::Str(game)) => with_loc(game, || game.inner.parse::<PQLGame>()),
+            Some(ast::FromValue::Param(p)) => Err((p.loc, PQLErrorKind::InvalidParameter).into()),
+        }
     }
 
-    fn get_board(game: PQLGame, fc: &ast::FromClause<'_>) -> PQLResult<PQLBoardRange> {
-        fc.get_board_range().map_or_else(
+    fn get_board(
+        game: PQLGame,
+        fc: &ast::FromClause<'_>,
+        params: Option<&Params>,
+    ) -> PQLResult<PQLBoardRange> {
+        let value = fc.get_board_range();
+
+        Self::lookup(value, params)?.map_or_else(
             || Ok((game, "*").try_into().unwrap()),
-            |e| with_loc(e, || (game, e.inner).try_into()),
+            |s| with_loc(value.unwrap(), || (game, s).try_into()),
         )
     }
 
     fn get_players(
         game: PQLGame,
         fc: &ast::FromClause<'_>,
+        params: Option<&Params>,
     ) -> PQLResult<(Vec<PlayerName>, Vec<PQLRange>)> {
         fc.get_players()
             .into_iter()
             .map(|(name, range)| {
-                with_loc(range, || (game, range.inner).try_into())
+                let src = Self::lookup(Some(range), params)?.unwrap_or("*");
+
+                with_loc(range, || (game, src).try_into())
                     .map(|pql_range| (name.inner.to_string(), pql_range))
             })
             .collect()
     }
 
-    fn get_deadcard(fc: &ast::FromClause<'_>) -> PQLResult<PQLCardSet> {
-        fc.get_dead().map_or_else(
+    fn get_seed(fc: &ast::FromClause<'_>, params: Option<&Params>) -> PQLResult<Option<u64>> {
+        let value = fc.get_seed();
+
+        Self::lookup(value, params)?.map_or(Ok(None), |s| {
+            with_loc(value.unwrap(), || {
+                s.trim()
+                    .parse()
+                    .map(Some)
+                    .map_err(|_| PQLErrorKind::InvalidSeed)
+            })
+        })
+    }
+
+    fn get_deadcard(fc: &ast::FromClause<'_>, params: Option<&Params>) -> PQLResult<PQLCardSet> {
+        let value = fc.get_dead();
+
+        Self::lookup(value, params)?.map_or_else(
             || Ok(PQLCardSet::default()),
-            |e| {
-                with_loc(e, || {
-                    parse_cards(e.inner).ok_or(PQLErrorKind::InvalidDeadcards)
+            |s| {
+                with_loc(value.unwrap(), || {
+                    parse_cards(s).ok_or(PQLErrorKind::InvalidDeadcards)
                 })
             },
         )
@@ -79,34 +168,17 @@ impl Default for VmStaticData {
             dead_card: PQLCardSet::default(),
             n_trails: Self::DEFAULT_N_TRIALS,
             n_players: PQLPlayerCount::default(),
+            seed: None,
         }
     }
 }
 
+/// Placeholders are rejected as unbound.
 impl TryFrom<&ast::FromClause<'_>> for VmStaticData {
     type Error = PQLError;
 
     fn try_from(expr: &ast::FromClause) -> Result<Self, Self::Error> {
-        let game = Self::get_game(expr)?;
-        let (player_names, player_ranges) = Self::get_players(game, expr)?;
-        let board_range = Self::get_board(game, expr)?;
-        let dead_card = Self::get_deadcard(expr)?;
-        let n_usize = player_names.len();
-        let n_players = if n_usize <= 10 {
-            PQLPlayerCount::try_from(n_usize).unwrap()
-        } else {
-            return Err((expr.loc, PQLErrorKind::ExceededMaximumPlayers(n_usize)).into());
-        };
-
-        Ok(Self {
-            game,
-            player_names,
-            player_ranges,
-            board_range,
-            dead_card,
-            n_players,
-            ..Default::default()
-        })
+        Self::bind(expr, Some(&Params::default()))
     }
 }
 
@@ -136,8 +208,8 @@ pub mod tests {
     #[quickcheck]
     fn test_board_default(cards: CardN<5>) {
         let fc = &parse_from_clause("from game='holdem'").unwrap();
-        let board_range = VmStaticData::get_board(PQLGame::Holdem, fc).unwrap();
-        let board_range_sd = VmStaticData::get_board(PQLGame::ShortDeck, fc).unwrap();
+        let board_range = VmStaticData::get_board(PQLGame::Holdem, fc, None).unwrap();
+        let board_range_sd = VmStaticData::get_board(PQLGame::ShortDeck, fc, None).unwrap();
 
         if cards.as_slice().iter().all(|c| c.rank >= PQLRank::R6) {
             assert!(board_range_sd.is_satisfied(cards.as_slice()));
@@ -149,7 +221,7 @@ pub mod tests {
     #[test]
     fn test_board() {
         let fc = &parse_from_clause("from game='holdem', board='AKQJT'").unwrap();
-        let board_range = VmStaticData::get_board(PQLGame::Holdem, fc).unwrap();
+        let board_range = VmStaticData::get_board(PQLGame::Holdem, fc, None).unwrap();
 
         assert!(board_range.is_satisfied(&cards!("As Ks Qs Js Ts")));
         assert!(board_range.is_satisfied(&cards!("Qs Ks As Js Ts")));
@@ -159,9 +231,12 @@ pub mod tests {
     #[test]
     fn test_players() {
         let game = PQLGame::Holdem;
-        let res =
-            VmStaticData::get_players(game, &parse_from_clause("from p1='AA', p2='KK'").unwrap())
-                .unwrap();
+        let res = VmStaticData::get_players(
+            game,
+            &parse_from_clause("from p1='AA', p2='KK'").unwrap(),
+            None,
+        )
+        .unwrap();
 
         assert!(res.0.contains(&"p1".into()));
         assert!(res.0.contains(&"p2".into()));
@@ -178,7 +253,7 @@ pub mod tests {
     fn test_deadcards() {
         fn assert_invalid(src: &str) {
             assert_eq!(
-                VmStaticData::get_deadcard(&parse_from_clause(src).unwrap(),)
+                VmStaticData::get_deadcard(&parse_from_clause(src).unwrap(), None)
                     .unwrap_err()
                     .kind,
                 PQLErrorKind::InvalidDeadcards
@@ -189,21 +264,94 @@ pub mod tests {
         assert_invalid("from dead='BS'");
 
         assert_eq!(
-            VmStaticData::get_deadcard(&parse_from_clause("from dead='AA'").unwrap(),),
+            VmStaticData::get_deadcard(&parse_from_clause("from dead='AA'").unwrap(), None),
             Err(((10, 14), PQLErrorKind::InvalidDeadcards).into())
         );
 
         assert_eq!(
-            VmStaticData::get_deadcard(&parse_from_clause("from game='holdem'").unwrap(),),
+            VmStaticData::get_deadcard(&parse_from_clause("from game='holdem'").unwrap(), None),
             Ok(PQLCardSet::default())
         );
 
         assert_eq!(
-            VmStaticData::get_deadcard(&parse_from_clause("from dead='As aH'").unwrap(),),
+            VmStaticData::get_deadcard(&parse_from_clause("from dead='As aH'").unwrap(), None),
             Ok(c64!("As Ah"))
         );
     }
 
+    #[test]
+    fn test_seed() {
+        assert_eq!(
+            VmStaticData::get_seed(&parse_from_clause("from seed=' 42 '").unwrap(), None),
+            Ok(Some(42))
+        );
+
+        assert_eq!(
+            VmStaticData::get_seed(&parse_from_clause("from game='holdem'").unwrap(), None),
+            Ok(None)
+        );
+
+        assert_eq!(
+            VmStaticData::get_seed(&parse_from_clause("from seed='-1'").unwrap(), None),
+            Err(((10, 14), PQLErrorKind::InvalidSeed).into())
+        );
+    }
+
+    fn span(src: &str, sub: &str) -> LocInfo {
+        let start = src.find(sub).unwrap();
+        (start, start + sub.len())
+    }
+
+    #[test]
+    fn test_bind() {
+        let src = "from game='omaha', hero=$1, villain='KK', board=$board, dead=$d, seed=$s";
+        let fc = parse_from_clause(src).unwrap();
+
+        let unbound = VmStaticData::bind(&fc, None).unwrap();
+        assert_eq!(unbound.game, PQLGame::Omaha);
+        assert_eq!(unbound.n_players, 2);
+        assert_eq!(unbound.dead_card, PQLCardSet::default());
+        assert_eq!(unbound.seed, None);
+
+        let params = Params::new()
+            .bind("1", "AAKK")
+            .bind("board", "AQ2")
+            .bind("d", "3c")
+            .bind("s", "7");
+        let bound = VmStaticData::bind(&fc, Some(&params)).unwrap();
+        let hero = bound.player_names.iter().position(|n| n == "hero").unwrap();
+        let (ranges, board_range) = mk_ranges(PQLGame::Omaha, &["AAKK"], "AQ2");
+
+        assert!(bound.player_ranges[hero].src_eq(&ranges[0]));
+        assert!(bound.board_range.src_eq(&board_range));
+        assert_eq!(bound.dead_card, c64!("3c"));
+        assert_eq!(bound.seed, Some(7));
+
+        assert_eq!(
+            VmStaticData::bind(&fc, Some(&params.bind("1", "AAKKQ"))).unwrap_err(),
+            (span(src, "$1"), RangeError::TooManyCardsInRange((0, 5))).into()
+        );
+    }
+
+    #[test]
+    fn test_bind_err() {
+        let src = "from hero=$1, board=$board";
+        let fc = parse_from_clause(src).unwrap();
+
+        assert_eq!(
+            VmStaticData::bind(&fc, Some(&Params::new().bind("1", "AA"))).unwrap_err(),
+            (span(src, "$board"), PQLErrorKind::UnboundParameter).into()
+        );
+
+        let src = "from game=$g, hero='AA'";
+        let fc = parse_from_clause(src).unwrap();
+
+        assert_eq!(
+            VmStaticData::bind(&fc, None).unwrap_err(),
+            (span(src, "$g"), PQLErrorKind::InvalidParameter).into()
+        );
+    }
+
     #[test]
     fn test_default() {
         let obj = VmStaticData::default();
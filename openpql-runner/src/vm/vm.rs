@@ -17,21 +17,24 @@ pub struct Vm {
     pub cache: VmCache,
 }
 
+impl From<VmStaticData> for Vm {
+    fn from(static_data: VmStaticData) -> Self {
+        let mut vm = Self::default();
+        vm.rebind(static_data);
+        vm
+    }
+}
+
 impl Vm {
     pub(crate) fn from_stmt(stmt: &ast::Stmt<'_>) -> PQLResult<Self> {
-        let static_data = VmStaticData::try_from(&stmt.from)?;
-
-        let sampled_data = VmSampledData::new(
-            static_data.game,
-            static_data.n_players,
-            static_data.dead_card,
-        );
+        VmStaticData::try_from(&stmt.from).map(Self::from)
+    }
 
-        Ok(Self {
-            static_data,
-            sampled_data,
-            ..Default::default()
-        })
+    /// Swaps in new static data, e.g. with other ranges bound to the same
+    /// statement; compiled programs and the heap stay valid.
+    pub(crate) fn rebind(&mut self, static_data: VmStaticData) {
+        self.sampled_data = VmSampledData::new(&static_data);
+        self.static_data = static_data;
     }
 
     pub(crate) fn as_context(&mut self) -> VmExecContext<'_> {
@@ -43,6 +46,11 @@ impl Vm {
                 sampled_cards: &self.sampled_data.cards,
                 n_players: self.static_data.n_players,
                 cache: &self.cache,
+                player_ranges: &self.static_data.player_ranges,
+                dead_cards: self.static_data.dead_card,
+                range_combos: self.sampled_data.range_combos(),
+                trial_seed: self.sampled_data.seed,
+                drew_cards: &self.sampled_data.drew_cards,
             },
         }
     }
@@ -0,0 +1,49 @@
+mod common;
+
+use common::run_adaptive_ok;
+
+fn n_trials(out: &str) -> usize {
+    out.lines()
+        .find_map(|l| l.strip_suffix(" trials"))
+        .and_then(|s| s.trim().parse::<usize>().ok())
+        .unwrap_or_else(|| panic!("no '<n> trials' line:\n{out}"))
+}
+
+fn std_errs(out: &str) -> Vec<f64> {
+    out.lines()
+        .filter_map(|l| l.split("se = ").nth(1))
+        .map(|s| s.split(',').next().unwrap().parse().unwrap())
+        .collect()
+}
+
+const NOISY: &str = "select avg(riverEquity(hero)), count(winshi(hero)) \
+                     from game='holdem', hero='AhKh', villain='QQ', board='2h7h9c'";
+
+#[test]
+fn deterministic_query_stops_after_first_batch() {
+    let out = run_adaptive_ok(
+        "select count(winshi(hero)) \
+         from game='holdem', hero='AhAs', villain='KhKs', board='2c3d4s7h8c'",
+        0.001,
+        Some(50_000),
+    );
+
+    assert!(n_trials(&out) < 50_000, "{out}");
+}
+
+#[test]
+fn stops_at_target_precision() {
+    let out = run_adaptive_ok(NOISY, 0.04, Some(1_000_000));
+    let errs = std_errs(&out);
+
+    assert_eq!(errs.len(), 2, "{out}");
+    assert!(errs.iter().all(|&se| se <= 0.04), "{out}");
+    assert!(n_trials(&out) < 1_000_000, "{out}");
+}
+
+#[test]
+fn hard_cap_is_respected() {
+    let out = run_adaptive_ok(NOISY, 1e-9, Some(300));
+
+    assert_eq!(n_trials(&out), 300, "{out}");
+}
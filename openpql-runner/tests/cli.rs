@@ -0,0 +1,131 @@
+//! Tests of the `opql` binary and its `'@file'` range literals.
+
+use std::{
+    fs,
+    path::PathBuf,
+    process::{Command, Output},
+};
+
+/// Writes `contents` to a file named `name` in the test scratch directory.
+fn range_file(name: &str, contents: &str) -> PathBuf {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    fs::write(&path, contents).unwrap();
+
+    path
+}
+
+fn opql(pql: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_opql"))
+        .args(["--mt", "50", "--run", pql])
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(output.status.success(), "{output:?}");
+
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    assert!(!output.status.success(), "{output:?}");
+    assert!(output.stdout.is_empty(), "{output:?}");
+
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn pio_file_through_run() {
+    let path = range_file("cli_pio.txt", "AA,KK:0.5,\nAhKh:0.25\n");
+    let out = stdout(&opql(&format!(
+        "select count(inRange(hero, 'AA,KK,AhKh')) \
+         from game='holdem', hero='@{}', villain='QQ'",
+        path.display()
+    )));
+
+    assert!(out.contains("COUNT 0 = 50"), "stdout: {out}");
+}
+
+/// The grid starts at `AA` like the ones Pio, GTO+ and Equilab export.
+#[test]
+fn matrix_csv_file_through_run() {
+    let mut rows = vec![vec!["0"; 13]; 13];
+    rows[0][0] = "1";
+    rows[0][1] = "0.5";
+    let csv = rows
+        .iter()
+        .map(|row| row.join(",") + "\n")
+        .collect::<String>();
+
+    let path = range_file("cli_matrix.csv", &csv);
+    let out = stdout(&opql(&format!(
+        "select count(inRange(hero, 'AA,AwKw')) \
+         from game='holdem', hero='@{}', villain='QQ'",
+        path.display()
+    )));
+
+    assert!(out.contains("COUNT 0 = 50"), "stdout: {out}");
+}
+
+#[test]
+fn shortdeck_file_uses_the_statement_game() {
+    let path = range_file("cli_shortdeck.txt", "66,A6s");
+    let out = stdout(&opql(&format!(
+        "select count(inRange(hero, '66,Aw6w')) \
+         from game='shortdeck', hero='@{}', villain='QQ'",
+        path.display()
+    )));
+    assert!(out.contains("COUNT 0 = 50"), "stdout: {out}");
+
+    let path = range_file("cli_shortdeck_22.txt", "AA,22");
+    for game in ["game='shortdeck'", "Game = 'ShortDeck'"] {
+        let err = stderr(&opql(&format!(
+            "select count(inRange(hero, 'AA')) \
+             from {game}, hero='@{}', villain='QQ'",
+            path.display()
+        )));
+        assert!(err.contains("cli_shortdeck_22.txt:1:4"), "stderr: {err}");
+        assert!(err.contains("'22'"), "stderr: {err}");
+    }
+}
+
+#[test]
+fn omaha_rejects_range_files() {
+    let path = range_file("cli_omaha.txt", "22+");
+
+    for game in ["game='omaha'", "GAME='omaha'", "Game = 'Omaha'"] {
+        let err = stderr(&opql(&format!(
+            "select count(inRange(hero, 'AA')) \
+             from {game}, hero='@{}', villain='KK'",
+            path.display()
+        )));
+
+        assert!(err.contains("cli_omaha.txt"), "stderr: {err}");
+        assert!(err.contains("Omaha"), "stderr: {err}");
+    }
+}
+
+#[test]
+fn missing_file_fails() {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("cli_missing.txt");
+    let err = stderr(&opql(&format!(
+        "select count(inRange(hero, 'AA')) \
+         from game='holdem', hero='@{}', villain='KK'",
+        path.display()
+    )));
+
+    assert!(err.contains("cli_missing.txt"), "stderr: {err}");
+}
+
+#[test]
+fn invalid_file_reports_line_and_column() {
+    let path = range_file("cli_invalid.txt", "AA,\nKK,AKx");
+    let err = stderr(&opql(&format!(
+        "select count(inRange(hero, 'AA')) \
+         from game='holdem', hero='@{}', villain='QQ'",
+        path.display()
+    )));
+
+    assert!(err.contains("cli_invalid.txt:2:4"), "stderr: {err}");
+    assert!(err.contains("'AKx'"), "stderr: {err}");
+}
@@ -37,13 +37,71 @@ pub fn run_ok_st(src: &str) -> String {
 fn run_with_threads(src: &str, n_threads: Option<usize>) -> (String, String) {
     let mut out = Vec::new();
     let mut err = Vec::new();
-    PQLRunner::run(src, None, n_threads, &mut out, &mut err).unwrap();
+    PQLRunner::run(src, None, n_threads, None, &mut out, &mut err).unwrap();
     (
         String::from_utf8(out).unwrap(),
         String::from_utf8(err).unwrap(),
     )
 }
 
+/// Run `src` with `seed` on `n_threads`, assert stderr is empty, return stdout.
+pub fn run_seeded_ok(src: &str, n_threads: usize, seed: u64) -> String {
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+    PQLRunner::run(src, None, Some(n_threads), Some(seed), &mut out, &mut err).unwrap();
+    let (out, err) = (
+        String::from_utf8(out).unwrap(),
+        String::from_utf8(err).unwrap(),
+    );
+    assert!(err.is_empty(), "unexpected stderr: {err}");
+    out
+}
+
+/// Run `src` in exact mode on `n_threads`, return `(stdout, stderr)`.
+pub fn run_exact(src: &str, n_threads: Option<usize>) -> (String, String) {
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+    PQLRunner::run_exact(src, n_threads, &mut out, &mut err).unwrap();
+    (
+        String::from_utf8(out).unwrap(),
+        String::from_utf8(err).unwrap(),
+    )
+}
+
+/// Run `src` in exact mode on `n_threads`, assert stderr is empty, return
+/// stdout.
+pub fn run_exact_ok(src: &str, n_threads: Option<usize>) -> String {
+    let (out, err) = run_exact(src, n_threads);
+    assert!(err.is_empty(), "unexpected stderr: {err}");
+    out
+}
+
+/// Heads-up on the turn: villain only wins on one of the two remaining
+/// kings, so hero wins 42 of the 44 rivers.
+pub const TURN_AA_VS_KK: &str = "from game='holdem', hero='AhAs', villain='KhKs', board='2c3d4s7h'";
+
+/// Run `src` adaptively on one thread, assert stderr is empty, return stdout.
+pub fn run_adaptive_ok(src: &str, precision: f64, max_trials: Option<usize>) -> String {
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+    PQLRunner::run_adaptive(
+        src,
+        precision,
+        max_trials,
+        Some(1),
+        None,
+        &mut out,
+        &mut err,
+    )
+    .unwrap();
+    let (out, err) = (
+        String::from_utf8(out).unwrap(),
+        String::from_utf8(err).unwrap(),
+    );
+    assert!(err.is_empty(), "unexpected stderr: {err}");
+    out
+}
+
 /// Run `src`, assert stderr is empty, return stdout.
 pub fn run_ok(src: &str) -> String {
     let (out, err) = run(src);
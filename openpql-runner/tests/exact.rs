@@ -0,0 +1,131 @@
+mod common;
+
+use common::{TURN_AA_VS_KK, run_exact, run_exact_ok};
+
+fn n_deals(out: &str) -> usize {
+    out.lines()
+        .find_map(|l| l.strip_suffix(" deals (exact)"))
+        .and_then(|s| s.trim().parse::<usize>().ok())
+        .unwrap_or_else(|| panic!("no '<n> deals (exact)' line:\n{out}"))
+}
+
+#[test]
+fn turn_heads_up_counts_every_river() {
+    let out = run_exact_ok(&format!("select count(winshi(hero)) {TURN_AA_VS_KK}"), None);
+
+    assert_eq!(n_deals(&out), 44, "{out}");
+    assert!(out.contains("COUNT 0 = 42"), "{out}");
+}
+
+/// A fixed hand against a narrow range on the flop: every `KK` combo times
+/// every ordered turn and river.
+#[test]
+fn flop_hand_vs_range() {
+    let out = run_exact_ok(
+        "select count(winshi(hero)) \
+         from game='holdem', hero='AhAs', villain='KK', board='2c3d4s'",
+        None,
+    );
+
+    assert_eq!(n_deals(&out), 6 * 45 * 44, "{out}");
+}
+
+#[test]
+fn where_clause_filters_deals() {
+    let out = run_exact_ok(
+        &format!("select count(winshi(hero)) {TURN_AA_VS_KK} where winshi(hero)"),
+        None,
+    );
+
+    assert_eq!(n_deals(&out), 42, "{out}");
+}
+
+#[test]
+fn thread_count_does_not_change_results() {
+    let src = "select avg(riverEquity(hero)), count(tieshi(hero)) \
+               from game='holdem', hero='AhKh', villain='QQ', board='2h7h9c'";
+
+    let (single, err) = run_exact(src, Some(1));
+    assert!(err.is_empty(), "unexpected stderr: {err}");
+
+    for n_threads in [2, 5] {
+        let (out, err) = run_exact(src, Some(n_threads));
+        assert!(err.is_empty(), "unexpected stderr: {err}");
+        assert_eq!(n_deals(&out), n_deals(&single));
+        assert_eq!(
+            out.lines().find(|l| l.starts_with("COUNT 1")),
+            single.lines().find(|l| l.starts_with("COUNT 1")),
+        );
+    }
+}
+
+#[test]
+fn no_consistent_deals() {
+    let (out, err) = run_exact(
+        "select count(winshi(hero)) from game='holdem', hero='AhAs', villain='AhAs'",
+        None,
+    );
+
+    assert!(out.is_empty(), "unexpected stdout: {out}");
+    assert!(err.contains("NoConsistentDeals"), "stderr: {err}");
+}
+
+/// Every river is dealt and rejected, which is a count of zero rather than
+/// a lack of deals.
+#[test]
+fn where_clause_never_true() {
+    let out = run_exact_ok(
+        &format!("select count(winshi(hero)) {TURN_AA_VS_KK} where tieshi(hero)"),
+        None,
+    );
+
+    assert_eq!(n_deals(&out), 0, "{out}");
+    assert!(out.contains("COUNT 0 = 0"), "{out}");
+    assert!(out.contains("44 deals rejected by where"), "{out}");
+}
+
+#[test]
+fn refuses_too_many_deals() {
+    let (out, err) = run_exact(
+        "select count(winshi(hero)) from game='holdem', hero='AA', villain='KK'",
+        None,
+    );
+
+    assert!(out.is_empty(), "unexpected stdout: {out}");
+    assert!(err.contains("TooManyDeals"), "stderr: {err}");
+}
+
+#[test]
+fn rejects_weighted_ranges() {
+    let (out, err) = run_exact(
+        "select count(winshi(hero)) from game='holdem', hero='AA@50', villain='KK'",
+        None,
+    );
+
+    assert!(out.is_empty(), "unexpected stdout: {out}");
+    assert!(err.contains("WeightedRangeInExactMode"), "stderr: {err}");
+}
+
+#[test]
+fn exact_mode_reports_no_sampling_error() {
+    let out = run_exact_ok(
+        &format!("select avg(riverEquity(hero)) {TURN_AA_VS_KK}"),
+        None,
+    );
+
+    assert!(!out.contains("95% ci"), "{out}");
+}
+
+/// Multiway preflop equity draws boards of its own on every deal, so the
+/// enumeration is not exact.
+#[test]
+fn functions_that_sample_are_not_exact() {
+    let out = run_exact_ok(
+        "select avg(hvhEquity(hero, preflop)) from game='holdem', hero='AsAh', \
+         villain='KhKs', villain2='QhQs', board='2s3h4d5c'",
+        None,
+    );
+
+    assert!(out.contains("95% ci"), "{out}");
+    assert!(out.contains("42 trials"), "{out}");
+}
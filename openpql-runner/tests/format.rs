@@ -0,0 +1,85 @@
+use opql::{OutputFormat, PQLRunner};
+
+fn run_csv(src: &str) -> String {
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+    PQLRunner::run_formatted(src, OutputFormat::Csv, &mut out, &mut err, |stmt| {
+        PQLRunner::try_run_stmt(stmt, Some(10), Some(1), Some(0))
+    })
+    .unwrap();
+
+    assert!(
+        err.is_empty(),
+        "unexpected stderr: {}",
+        String::from_utf8(err).unwrap()
+    );
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn csv_has_one_row_per_selector() {
+    let out = run_csv(
+        "select count(winshi(hero)) as won, max(handType(hero, river)) \
+         from game='holdem', hero='AhAs', villain='KhKs', board='2c3d4s7h8c'; \
+         select avg(boardSuitCount(river)) from board='2c3d4s7h8c'",
+    );
+    let rows: Vec<Vec<&str>> = out.lines().map(|l| l.split(',').collect()).collect();
+
+    assert_eq!(rows.len(), 4, "{out}");
+    assert_eq!(
+        rows[0][..7],
+        [
+            "statement",
+            "selector",
+            "kind",
+            "arg",
+            "alias",
+            "expr",
+            "value"
+        ]
+    );
+    assert_eq!(
+        rows[1][..7],
+        ["0", "0", "count", "", "won", "winshi(hero)", "10"]
+    );
+    assert_eq!(rows[1][12..], ["10", "0", "0", "false", ""]);
+    assert_eq!(
+        rows[2][..7],
+        ["0", "1", "max", "", "", "\"handType(hero", " river)\""]
+    );
+    assert_eq!(
+        rows[3][..7],
+        ["1", "0", "avg", "", "", "boardSuitCount(river)", "4"]
+    );
+}
+
+#[test]
+fn csv_skips_failed_statements() {
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+    PQLRunner::run_formatted(
+        "select count(winshi(hero)) from hero='AhAs', villain='AhAs'",
+        OutputFormat::Csv,
+        &mut out,
+        &mut err,
+        |stmt| PQLRunner::try_run_stmt_exact(stmt, Some(1)),
+    )
+    .unwrap();
+
+    assert_eq!(String::from_utf8(out).unwrap().lines().count(), 1);
+    assert!(!err.is_empty());
+}
+
+#[test]
+fn csv_tells_selector_arguments_apart() {
+    let out = run_csv(
+        "select percentile(riverEquity(hero), 0.9), percentile(riverEquity(hero), 0.1), \
+                histogram(riverEquity(hero), 4) \
+         from game='holdem', hero='AhAs', villain='KhKs', board='2c3d4s7h8c'",
+    );
+    let rows: Vec<Vec<&str>> = out.lines().map(|l| l.split(',').collect()).collect();
+
+    assert_eq!(rows[1][2..4], ["percentile", "0.9"], "{out}");
+    assert_eq!(rows[2][2..4], ["percentile", "0.1"], "{out}");
+    assert_eq!(rows[3][2..4], ["histogram", "4"], "{out}");
+}
@@ -0,0 +1,33 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn lower_low_is_best() {
+    assert_count_all(
+        "select count(bestlorating(hero, river)) \
+         from game='holdem', hero='As2h', villain='6c7d', board='3d4c8sKhKd'",
+    );
+}
+
+#[test]
+fn higher_low_is_not_best() {
+    assert_count_none(
+        "select count(bestlorating(villain, river)) \
+         from game='holdem', hero='As2h', villain='6c7d', board='3d4c8sKhKd'",
+    );
+}
+
+#[test]
+fn tied_low_is_best() {
+    assert_count_all(
+        "select count(bestlorating(villain, river)) \
+         from game='holdem', hero='As2h', villain='Ac2d', board='3d4c8sKhKd'",
+    );
+}
+
+#[test]
+fn no_low_is_never_best() {
+    assert_count_none(
+        "select count(bestlorating(hero, river)) \
+         from game='holdem', hero='KsQs', villain='KcQd', board='3d4c9sKhJd'",
+    );
+}
@@ -0,0 +1,25 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn three_low_ranks_allow_low() {
+    assert_count_all(
+        "select count(boardallowsmadelo(flop)) \
+         from game='holdem', board='Ac2d8h'",
+    );
+}
+
+#[test]
+fn paired_low_flop_does_not_allow_low() {
+    assert_count_none(
+        "select count(boardallowsmadelo(flop)) \
+         from game='holdem', board='AcAd8h'",
+    );
+}
+
+#[test]
+fn high_river_does_not_allow_low() {
+    assert_count_none(
+        "select count(boardallowsmadelo(river)) \
+         from game='holdem', board='Ac2d9hTsKd'",
+    );
+}
@@ -0,0 +1,17 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn single_low_rank_on_flop() {
+    assert_count_all(
+        "select count(boardhasonedistinctlocard(flop)) \
+         from game='holdem', board='AcAdKh'",
+    );
+}
+
+#[test]
+fn two_low_ranks_on_flop() {
+    assert_count_none(
+        "select count(boardhasonedistinctlocard(flop)) \
+         from game='holdem', board='Ac2dKh'",
+    );
+}
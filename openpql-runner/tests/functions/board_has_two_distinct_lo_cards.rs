@@ -0,0 +1,17 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn two_low_ranks_on_flop() {
+    assert_count_all(
+        "select count(boardhastwodistinctlocards(flop)) \
+         from game='holdem', board='Ac2dKh'",
+    );
+}
+
+#[test]
+fn three_low_ranks_on_flop() {
+    assert_count_none(
+        "select count(boardhastwodistinctlocards(flop)) \
+         from game='holdem', board='Ac2d3h'",
+    );
+}
@@ -0,0 +1,21 @@
+use crate::common::run_ok;
+
+#[test]
+fn counts_distinct_low_ranks() {
+    let out = run_ok(
+        "select avg(boardlocardcount(river)) \
+         from game='holdem', board='Ac2d2h8sKd'",
+    );
+
+    assert!(out.contains("AVG 0 = 3"), "{out}");
+}
+
+#[test]
+fn nine_is_not_low() {
+    let out = run_ok(
+        "select avg(boardlocardcount(flop)) \
+         from game='holdem', board='9cTdJh'",
+    );
+
+    assert!(out.contains("AVG 0 = 0"), "{out}");
+}
@@ -0,0 +1,9 @@
+use crate::common::assert_count_all;
+
+#[test]
+fn no_outs_on_the_river() {
+    assert_count_all(
+        "select count(cardCount(outs(hero, river)) = 0) \
+         from game='holdem', hero='AsAh', villain='KsKh'",
+    );
+}
@@ -0,0 +1,55 @@
+use crate::common::{assert_count_all, run_err};
+
+#[test]
+fn board_plays() {
+    assert_count_all(
+        "select count(cardsplay(hero, river) = 0) \
+         from game='holdem', hero='2c3d', board='AhKhQhJhTh'",
+    );
+}
+
+#[test]
+fn one_card_straight() {
+    assert_count_all(
+        "select count(cardsplay(hero, river) = 1) \
+         from game='holdem', hero='9s3d', board='KhQhJcTc2d'",
+    );
+}
+
+#[test]
+fn pocket_pair_plays_both() {
+    assert_count_all(
+        "select count(cardsplay(hero, flop) = 2) \
+         from game='holdem', hero='AsAd', board='Kh7c4s'",
+    );
+}
+
+#[test]
+fn omaha_always_plays_two() {
+    assert_count_all(
+        "select count(cardsplay(hero, river) = 2) \
+         from game='omaha', hero='2c3d4s5s', board='AhKhQhJhTh'",
+    );
+}
+
+#[test]
+fn five_and_six_card_omaha_play_two() {
+    assert_count_all(
+        "select count(cardsplay(hero, river) = 2) \
+         from game='omaha5', hero='2c3d4s5s6c', board='AhKhQhJhTh'",
+    );
+    assert_count_all(
+        "select count(cardsplay(hero, river) = 2) \
+         from game='omaha6', hero='2c3d4s5s6c7d', board='AhKhQhJhTh'",
+    );
+}
+
+#[test]
+fn preflop_is_an_error() {
+    let err = run_err(
+        "select count(cardsplay(hero, preflop) = 2) \
+         from game='holdem', hero='AsAd'",
+    );
+
+    assert!(err.contains("RequiresFiveCards"), "stderr: {err}");
+}
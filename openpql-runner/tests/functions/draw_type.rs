@@ -0,0 +1,35 @@
+use crate::common::{assert_count_all, run_ok};
+
+#[test]
+fn flush_and_straight_draw_is_combo_draw() {
+    assert_count_all(
+        "select count(drawtype(hero, flop) = combodraw) \
+         from game='holdem', hero='9h8h', board='7h6s2h'",
+    );
+}
+
+#[test]
+fn nothing_to_draw_to_on_the_river() {
+    assert_count_all(
+        "select count(drawtype(hero, river) = nodraw) \
+         from game='holdem', hero='9h8h', board='7h6s2hKdAc'",
+    );
+}
+
+#[test]
+fn omaha_wrap_is_open_ended() {
+    assert_count_all(
+        "select count(drawtype(hero, flop) = openended) \
+         from game='omaha', hero='JcTd8s7h', board='9h6s2c'",
+    );
+}
+
+#[test]
+fn draw_types_are_ordered() {
+    let out = run_ok(
+        "select max(drawtype(hero, flop)) \
+         from game='holdem', hero='Ah5h', board='4h3hKc'",
+    );
+
+    assert!(out.contains("= COMBODRAW"), "{out}");
+}
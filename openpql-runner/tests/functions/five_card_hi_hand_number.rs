@@ -0,0 +1,25 @@
+use crate::common::assert_count_all;
+
+#[test]
+fn royal_flush_is_one() {
+    assert_count_all(
+        "select count(fivecardhihandnumber(hero, river) = 1) \
+         from game='holdem', hero='AhKh', board='QhJhTh2c3d'",
+    );
+}
+
+#[test]
+fn quad_aces_king_kicker() {
+    assert_count_all(
+        "select count(fivecardhihandnumber(hero, river) = 11) \
+         from game='holdem', hero='AsAd', board='AhAcKs2c3d'",
+    );
+}
+
+#[test]
+fn weaker_hands_have_larger_numbers() {
+    assert_count_all(
+        "select count(fivecardhihandnumber(hero, river) < fivecardhihandnumber(villain, river)) \
+         from game='holdem', hero='AsAd', villain='KsKd', board='Ah7c4s2d9h'",
+    );
+}
@@ -0,0 +1,25 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn four_hearts_on_the_turn() {
+    assert_count_all(
+        "select count(fourflush(turn)) \
+         from game='holdem', board='AhKhQh2h'",
+    );
+}
+
+#[test]
+fn three_hearts_is_not_four_flush() {
+    assert_count_none(
+        "select count(fourflush(river)) \
+         from game='holdem', board='AhKhQh2s3d'",
+    );
+}
+
+#[test]
+fn five_hearts_is_not_four_flush() {
+    assert_count_none(
+        "select count(fourflush(river)) \
+         from game='holdem', board='AhKhQh2h3h'",
+    );
+}
@@ -0,0 +1,45 @@
+use crate::common::{assert_count_all, run_err};
+
+#[test]
+fn nuts_beat_every_other_holding() {
+    assert_count_all(
+        "select count(handranking(hero, flop) > 0.999) \
+         from game='holdem', hero='AhKh', board='QhJhTh'",
+    );
+}
+
+#[test]
+fn board_plays_ties_everyone() {
+    assert_count_all(
+        "select count(handranking(hero, river) = 0.5) \
+         from game='holdem', hero='2c3d', board='AhKhQhJhTh'",
+    );
+}
+
+#[test]
+fn stronger_hand_ranks_higher() {
+    assert_count_all(
+        "select count(handranking(hero, flop) > handranking(villain, flop)) \
+         from game='holdem', hero='AsAd', villain='KsKd', board='Ah7c4s'",
+    );
+}
+
+#[test]
+fn preflop_is_an_error() {
+    let err = run_err(
+        "select count(handranking(hero, preflop) > 0.5) \
+         from game='holdem', hero='AsAd'",
+    );
+
+    assert!(err.contains("RequiresFiveCards"), "stderr: {err}");
+}
+
+#[test]
+fn omaha_is_an_error() {
+    let err = run_err(
+        "select count(handranking(hero, flop) > 0.5) \
+         from game='omaha', hero='AsAdKsKd', board='Ah7c4s'",
+    );
+
+    assert!(err.contains("UnsupportedGame(Omaha)"), "stderr: {err}");
+}
@@ -0,0 +1,45 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn nut_flush_draw_is_a_flush_draw() {
+    assert_count_all(
+        "select count(hasdraw(hero, flop, flushdraw) and hasdraw(hero, flop, nutflushdraw)) \
+         from game='holdem', hero='Ah2h', board='Kh7h3c'",
+    );
+}
+
+#[test]
+fn backdoor_only_on_the_flop() {
+    assert_count_all(
+        "select count(hasdraw(hero, flop, backdoorflushdraw)) \
+         from game='holdem', hero='Qh2h', board='Kh7c3c'",
+    );
+    assert_count_none(
+        "select count(hasdraw(hero, turn, backdoorflushdraw)) \
+         from game='holdem', hero='Qh2h', board='Kh7c3c9d'",
+    );
+}
+
+#[test]
+fn gutshot_is_not_open_ended() {
+    assert_count_none(
+        "select count(hasdraw(hero, flop, openended)) \
+         from game='holdem', hero='9c8d', board='Th6s2c'",
+    );
+}
+
+#[test]
+fn omaha_needs_two_suited_hole_cards() {
+    assert_count_none(
+        "select count(hasdraw(hero, flop, flushdraw)) \
+         from game='omaha', hero='Ah2cKcQd', board='Kh7h3h'",
+    );
+}
+
+#[test]
+fn short_deck_ace_plays_low() {
+    assert_count_all(
+        "select count(hasdraw(hero, flop, gutshot)) \
+         from game='shortdeck', hero='Ac9d', board='7h6sKc'",
+    );
+}
@@ -0,0 +1,20 @@
+use crate::common::run_ok_st;
+
+#[test]
+fn perceived_range_replaces_dealt_hand() {
+    // villain holds KhKs, but is perceived on AA or KK
+    let out = run_ok_st(
+        "select avg(hvPerceivedRangeEquity(hero, river, 'AA,KK')) \
+         from game='holdem', hero='AhAs', villain='KhKs', board='2c3d4s7h8c'",
+    );
+    assert!(out.contains("AVG 0 = 0.92857142857"), "stdout: {out}");
+}
+
+#[test]
+fn perceived_range_applies_to_every_opponent() {
+    let out = run_ok_st(
+        "select avg(hvPerceivedRangeEquity(hero, river, 'KK')) \
+         from game='holdem', hero='AhAs', p2='*', p3='*', board='2c3d4s7h8c'",
+    );
+    assert!(out.contains("AVG 0 = 1"), "stdout: {out}");
+}
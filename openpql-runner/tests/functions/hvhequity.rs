@@ -1,4 +1,4 @@
-use crate::common::{run_ok, run_ok_st};
+use crate::common::{run_ok, run_ok_st, run_seeded_ok};
 
 #[test]
 fn aces_win_on_river_equity_is_one() {
@@ -44,3 +44,85 @@ fn turn_equity_enumerates_rivers_deterministically() {
     );
     assert!(out.contains("AVG 0 = 0.9545454545454536"), "stdout: {out}");
 }
+
+#[test]
+fn preflop_equity_is_exact_heads_up() {
+    let out = run_ok_st(
+        "select avg(equity(hero, preflop)) \
+         from game='holdem', hero='AhAs', villain='KhKs'",
+    );
+    assert!(out.contains("AVG 0 = 0.82636611255945"), "stdout: {out}");
+}
+
+#[test]
+fn preflop_equity_samples_boards_per_trial_in_omaha() {
+    let query = "select avg(equity(hero, preflop)), avg(equity(villain, preflop)) \
+                 from game='omaha', hero='AhAsKdQd', villain='7c8c9hTh'";
+    let out = run_seeded_ok(query, 1, 7);
+
+    assert_eq!(out, run_seeded_ok(query, 1, 7));
+
+    let avgs: Vec<f64> = out
+        .lines()
+        .filter_map(|l| l.split_once(" = ").filter(|(k, _)| k.starts_with("AVG")))
+        .map(|(_, v)| v.parse().unwrap())
+        .collect();
+    assert!(avgs[0] > 0.5, "stdout: {out}");
+    assert!((avgs[0] + avgs[1] - 1.0).abs() < 1e-9, "stdout: {out}");
+
+    // the hands are fixed, so all of the error comes from the boards
+    let std_errs: Vec<f64> = out
+        .lines()
+        .filter_map(|l| l.split("se = ").nth(1))
+        .map(|s| s.split(',').next().unwrap().parse().unwrap())
+        .collect();
+    assert!(std_errs.iter().all(|&se| se > 0.0), "stdout: {out}");
+}
+
+#[test]
+fn preflop_equity_excludes_dead_cards_from_boards() {
+    let out = run_ok_st(
+        "select avg(equity(hero, preflop)) \
+         from game='holdem', hero='AhAs', villain='KhKs', dead='KdKc'",
+    );
+    let avg: f64 = out
+        .lines()
+        .find_map(|l| l.strip_prefix("AVG 0 = "))
+        .unwrap()
+        .parse()
+        .unwrap();
+
+    assert!(avg > 0.83, "stdout: {out}");
+}
+
+#[test]
+fn preflop_equity_excludes_dead_cards_in_omaha() {
+    let query = |dead: &str| {
+        run_ok_st(&format!(
+            "select avg(equity(hero, preflop)) \
+             from game='omaha', hero='AhAsKdQd', villain='7c8c9hTh', dead='{dead}'"
+        ))
+    };
+
+    assert_ne!(query("2c"), query("JcJdJh"));
+}
+
+/// Dead cards leave too many matchups to enumerate each one, so heads-up
+/// hold'em samples its boards like Omaha does.
+#[test]
+fn preflop_equity_samples_boards_with_dead_cards() {
+    let query = "select avg(equity(hero, preflop)) \
+                 from game='holdem', hero='AhAs', villain='KhKs', dead='2c'";
+    let out = run_seeded_ok(query, 1, 7);
+
+    assert_eq!(out, run_seeded_ok(query, 1, 7));
+
+    let se: f64 = out
+        .lines()
+        .find_map(|l| l.split("se = ").nth(1))
+        .and_then(|s| s.split(',').next())
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(se > 0.0, "stdout: {out}");
+}
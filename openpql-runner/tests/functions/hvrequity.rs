@@ -0,0 +1,104 @@
+use crate::common::{run, run_ok, run_ok_st, run_seeded_ok};
+
+#[test]
+fn aces_beat_every_hand_of_range_on_river() {
+    let out = run_ok(
+        "select avg(hvrequity(hero, river)) \
+         from game='holdem', hero='AhAs', villain='KK,QQ', board='2c3d4s7h8c'",
+    );
+    assert!(out.contains("AVG 0 = 1"), "stdout: {out}");
+}
+
+#[test]
+fn equity_averages_over_unblocked_combos() {
+    // AdAc ties, the six KK combos lose: (0.5 + 6) / 7
+    let out = run_ok_st(
+        "select avg(hvrequity(hero, river)) \
+         from game='holdem', hero='AhAs', villain='AA,KK', board='2c3d4s7h8c'",
+    );
+    assert!(out.contains("AVG 0 = 0.92857142857"), "stdout: {out}");
+}
+
+#[test]
+fn equity_weights_combos_by_range_weight() {
+    // AdAc counts half: (0.5 * 0.5 + 6) / 6.5
+    let weighted = run_ok_st(
+        "select avg(hvrequity(hero, river)) \
+         from game='holdem', hero='AhAs', villain='AA@50,KK', board='2c3d4s7h8c'",
+    );
+    let unweighted = run_ok_st(
+        "select avg(hvrequity(hero, river)) \
+         from game='holdem', hero='AhAs', villain='AA,KK', board='2c3d4s7h8c'",
+    );
+
+    assert!(
+        weighted.contains("AVG 0 = 0.96153846153"),
+        "stdout: {weighted}"
+    );
+    assert_ne!(weighted, unweighted);
+}
+
+/// Every combination is enumerated, so the seed changes nothing.
+#[test]
+fn flop_equity_is_exact() {
+    let query = "select avg(hvrequity(hero, flop)) \
+                 from game='holdem', hero='AhAs', villain='KK', board='2c3d4s'";
+
+    assert_eq!(run_seeded_ok(query, 1, 1), run_seeded_ok(query, 1, 2));
+}
+
+/// Two opponents with any hand are far too many combinations to walk on
+/// the flop, so each trial samples them.
+#[test]
+fn multiway_flop_equity_is_sampled() {
+    let query = "select avg(hvrequity(hero, flop)) \
+                 from game='holdem', hero='AhAs', villain='*', v2='*', board='2c3d4s'";
+    let out = run_seeded_ok(query, 2, 7);
+    let equity: f64 = out
+        .lines()
+        .find_map(|l| l.strip_prefix("AVG 0 = "))
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or_else(|| panic!("stdout: {out}"));
+
+    assert!((0.64..0.72).contains(&equity), "stdout: {out}");
+    assert_ne!(out, run_seeded_ok(query, 2, 8));
+}
+
+/// Heads-up hold'em averages the shipped table over the six KK combos.
+#[test]
+fn preflop_equity_is_exact_heads_up() {
+    let out = run_ok(
+        "select min(hvrequity(hero, preflop)), max(hvrequity(hero, preflop)) \
+         from game='holdem', hero='AhAs', villain='KK'",
+    );
+
+    assert!(out.contains("MIN 0 = 0.81946050467"), "stdout: {out}");
+    assert!(out.contains("MAX 1 = 0.81946050467"), "stdout: {out}");
+}
+
+#[test]
+fn multiway_preflop_equity_is_sampled_per_trial() {
+    let out = run_ok(
+        "select min(hvrequity(hero, preflop)), max(hvrequity(hero, preflop)) \
+         from game='holdem', hero='AhAs', villain='KK', v2='QQ'",
+    );
+    let equities: Vec<f64> = out
+        .lines()
+        .filter_map(|l| l.split(" = ").nth(1))
+        .filter_map(|v| v.trim().parse().ok())
+        .collect();
+
+    assert_eq!(equities.len(), 2, "stdout: {out}");
+    assert!(equities[0] < equities[1], "stdout: {out}");
+    assert!(
+        equities.iter().all(|e| (0.6..0.8).contains(e)),
+        "stdout: {out}"
+    );
+}
+
+#[test]
+fn fully_blocked_range_is_an_error() {
+    let (_, err) = run("select avg(hvrequity(hero, flop)) \
+         from game='holdem', hero='AhAs', villain='AA', board='AdKd3d'");
+    assert!(!err.is_empty());
+}
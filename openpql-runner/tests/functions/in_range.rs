@@ -1,4 +1,4 @@
-use crate::common::{assert_count_all, assert_count_none};
+use crate::common::{assert_count_all, assert_count_none, run};
 
 #[test]
 fn aces_in_aa_range() {
@@ -39,3 +39,46 @@ fn pair_in_pair_plus_range() {
          from game='holdem', hero='QhQc', board='2c3d4s'",
     );
 }
+
+#[test]
+fn strong_pair_in_top_range() {
+    assert_count_all(
+        "select count(inrange(hero, 'top5%')) \
+         from game='holdem', hero='KhKc', board='2c3d4s'",
+    );
+    assert_count_none(
+        "select count(inrange(hero, 'top5%')) \
+         from game='holdem', hero='7h2d', board='2c3d4s'",
+    );
+}
+
+#[test]
+fn top_range_minus_aces() {
+    assert_count_all(
+        "select count(inrange(hero, 'top20%')) \
+         from game='holdem', hero='top20%!AA', board='2c3d4s'",
+    );
+    assert_count_none(
+        "select count(inrange(hero, 'AA')) \
+         from game='holdem', hero='top20%!AA', board='2c3d4s'",
+    );
+}
+
+#[test]
+fn omaha_top_range() {
+    assert_count_all(
+        "select count(inrange(hero, 'top1%')) \
+         from game='omaha', hero='AsAhKsKh', board='2c3d4s'",
+    );
+}
+
+/// A hundredth of a percent of a hundredth of a percent rounds to no weight
+/// at all, so the range is empty rather than a panic in the sampler.
+#[test]
+fn weight_below_a_millionth_leaves_no_combo() {
+    let (out, err) = run("select avg(equity(hero, river)) \
+         from game='holdem', hero='(AA@0.01)@0.01', villain='KK'");
+
+    assert!(out.is_empty(), "unexpected stdout: {out}");
+    assert!(err.contains("SamplingFailed"), "stderr: {err}");
+}
@@ -0,0 +1,33 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn river_wheel_rating_equals_rate_lo_hand() {
+    assert_count_all(
+        "select count(lorating(hero, river) = ratelohand('As2h3d4c5s')) \
+         from game='holdem', hero='As2h', board='3d4c5sKhKd'",
+    );
+}
+
+#[test]
+fn better_low_has_lower_rating() {
+    assert_count_all(
+        "select count(lorating(hero, river) < lorating(villain, river)) \
+         from game='holdem', hero='As2h', villain='6c8d', board='3d4c7sKhKd'",
+    );
+}
+
+#[test]
+fn omaha_uses_exactly_two_hole_cards() {
+    assert_count_all(
+        "select count(lorating(hero, river) = ratelohand('As2h3d7c8s')) \
+         from game='omaha', hero='As2h3c4c', board='3d7c8sKhKd'",
+    );
+}
+
+#[test]
+fn no_low_is_worse_than_any_low() {
+    assert_count_none(
+        "select count(lorating(hero, river) < ratelohand('4s5h6d7c8s')) \
+         from game='holdem', hero='KsKh', board='3d4c7sQhJd'",
+    );
+}
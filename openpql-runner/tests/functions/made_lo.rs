@@ -0,0 +1,41 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn five_low_ranks_make_low() {
+    assert_count_all(
+        "select count(madelo(hero, river)) \
+         from game='holdem', hero='As2h', board='3d4c8sKhKd'",
+    );
+}
+
+#[test]
+fn paired_low_cards_do_not_make_low() {
+    assert_count_none(
+        "select count(madelo(hero, river)) \
+         from game='holdem', hero='AsAh', board='3d4c8sKhKd'",
+    );
+}
+
+#[test]
+fn flop_low_with_two_hole_cards() {
+    assert_count_all(
+        "select count(madelo(hero, flop)) \
+         from game='holdem', hero='As2h', board='3d4c8s'",
+    );
+}
+
+#[test]
+fn omaha_single_low_hole_card_does_not_make_low() {
+    assert_count_none(
+        "select count(madelo(hero, river)) \
+         from game='omaha', hero='AsKhKdQc', board='2d3c4s5hKs'",
+    );
+}
+
+#[test]
+fn shortdeck_never_makes_low() {
+    assert_count_none(
+        "select count(madelo(hero, river)) \
+         from game='shortdeck', hero='As6h', board='7d8cKsQhJd'",
+    );
+}
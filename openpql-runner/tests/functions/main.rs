@@ -15,31 +15,51 @@
 mod common;
 
 mod best_hi_rating;
+mod best_lo_rating;
+mod board_allows_made_lo;
+mod board_has_one_distinct_lo_card;
+mod board_has_two_distinct_lo_cards;
 mod board_in_range;
+mod board_lo_card_count;
 mod board_ranks;
 mod board_suit_count;
+mod card_count;
+mod cards_play;
+mod draw_type;
 mod duplicated_board_ranks;
 mod duplicated_hand_ranks;
 mod exact_flop_hand_category;
 mod exact_hand_type;
+mod five_card_hi_hand_number;
 mod flop_hand_category;
 mod flushing_board;
+mod four_flush;
 mod fractional_river_equity;
 mod hand_board_intersections;
+mod hand_ranking;
 mod hand_ranks;
 mod hand_type;
+mod has_draw;
 mod has_second_board_rank;
 mod has_top_board_rank;
 mod hi_rating;
+mod hv_perceived_range_equity;
 mod hvhequity;
+mod hvrequity;
 mod in_range;
 mod intersecting_hand_ranks;
+mod lo_rating;
+mod made_lo;
 mod max_hi_rating;
 mod max_rank;
 mod min_flop_hand_category;
 mod min_hand_type;
 mod min_hi_rating;
+mod min_hv_perceived_range_equity;
 mod min_hvhequity;
+mod min_hvrequity;
+mod min_lo_rating;
+mod min_outs_to_hand_type;
 mod min_rank;
 mod monotone_board;
 mod nonintersecting_hand_ranks;
@@ -47,22 +67,34 @@ mod nth_rank;
 mod nut_hi;
 mod nut_hi_for_hand_type;
 mod nut_hi_outs;
+mod nut_lo;
+mod nut_lo_outs;
 mod omaha5;
+mod omaha5hilo;
+mod omaha6;
+mod omaha8;
+mod outs;
+mod outs_to_hand_type;
 mod overpair;
 mod paired_board;
 mod pocket_pair;
 mod rainbow_board;
 mod rank_count;
 mod rate_hi_hand;
+mod rate_lo_hand;
 mod river_card;
 mod river_equity;
 mod scoops;
 mod straight_board;
+mod three_flush;
 mod ties_hi;
+mod ties_lo;
 mod to_card;
 mod to_rank;
+mod to_string;
 mod turn_card;
 mod twotone_board;
 mod where_clause;
 mod winning_hand_type;
 mod wins_hi;
+mod wins_lo;
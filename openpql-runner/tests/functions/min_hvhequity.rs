@@ -39,3 +39,15 @@ fn tied_equity_of_half_fails_threshold_above_half() {
          from game='holdem', hero='AhAd', villain='AcAs', board='2c3d4s7h8c'",
     );
 }
+
+#[test]
+fn preflop_equity_meets_threshold() {
+    assert_count_all(
+        "select count(min_equity(hero, preflop, 0.7)) \
+         from game='shortdeck', hero='AhAs', villain='KhKs'",
+    );
+    assert_count_none(
+        "select count(min_equity(villain, preflop, 0.3)) \
+         from game='shortdeck', hero='AhAs', villain='KhKs'",
+    );
+}
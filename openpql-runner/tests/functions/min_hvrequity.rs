@@ -0,0 +1,17 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn equity_against_range_meets_threshold() {
+    assert_count_all(
+        "select count(minHvREquity(hero, river, 0.9)) \
+         from game='holdem', hero='AhAs', villain='AA,KK', board='2c3d4s7h8c'",
+    );
+}
+
+#[test]
+fn equity_against_range_fails_threshold() {
+    assert_count_none(
+        "select count(minHvREquity(hero, river, 0.95)) \
+         from game='holdem', hero='AhAs', villain='AA,KK', board='2c3d4s7h8c'",
+    );
+}
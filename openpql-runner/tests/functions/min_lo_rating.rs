@@ -0,0 +1,33 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn wheel_meets_min_eight_low() {
+    assert_count_all(
+        "select count(minlorating(hero, river, ratelohand('8s7h6d5c4s'))) \
+         from game='holdem', hero='As2h', board='3d4c5sKhKd'",
+    );
+}
+
+#[test]
+fn low_meets_own_rating() {
+    assert_count_all(
+        "select count(minlorating(hero, river, ratelohand('As2h3d4c8s'))) \
+         from game='holdem', hero='As2h', board='3d4c8sKhKd'",
+    );
+}
+
+#[test]
+fn eight_low_does_not_meet_min_wheel() {
+    assert_count_none(
+        "select count(minlorating(hero, river, ratelohand('As2h3d4c5s'))) \
+         from game='holdem', hero='As2h', board='3d4c8sKhKd'",
+    );
+}
+
+#[test]
+fn no_low_does_not_meet_any_rating() {
+    assert_count_none(
+        "select count(minlorating(hero, river, ratelohand('As2h3d4c9s'))) \
+         from game='holdem', hero='KsKh', board='3d4c8sQhJd'",
+    );
+}
@@ -0,0 +1,17 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn open_ender_has_at_least_eight_straight_outs() {
+    assert_count_all(
+        "select count(minOutsToHandType(hero, flop, straight, 8)) \
+         from game='holdem', hero='JsTh', board='9h8s2c'",
+    );
+}
+
+#[test]
+fn open_ender_has_fewer_than_nine_straight_outs() {
+    assert_count_none(
+        "select count(minOutsToHandType(hero, flop, straight, 9)) \
+         from game='holdem', hero='JsTh', board='9h8s2c'",
+    );
+}
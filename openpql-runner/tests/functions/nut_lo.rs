@@ -0,0 +1,33 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn wheel_is_nut_low() {
+    assert_count_all(
+        "select count(nutlo(hero, river)) \
+         from game='holdem', hero='As2h', villain='KcKd', board='3d4c5sQhJd'",
+    );
+}
+
+#[test]
+fn a2_is_nut_low_on_flop() {
+    assert_count_all(
+        "select count(nutlo(hero, flop)) \
+         from game='omaha', hero='As2hKdKc', villain='QcQdJcJd', board='3d7c8s'",
+    );
+}
+
+#[test]
+fn counterfeited_low_is_not_nut() {
+    assert_count_none(
+        "select count(nutlo(hero, flop)) \
+         from game='omaha', hero='As2hKdKc', villain='QcQdJcJd', board='Ad7c8s'",
+    );
+}
+
+#[test]
+fn no_low_is_not_nut() {
+    assert_count_none(
+        "select count(nutlo(hero, river)) \
+         from game='holdem', hero='KsKh', villain='QcQd', board='3d4c9sJhTd'",
+    );
+}
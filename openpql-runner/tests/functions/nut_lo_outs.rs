@@ -0,0 +1,21 @@
+use crate::common::run_ok;
+
+#[test]
+fn a2_has_sixteen_nut_low_outs_on_omaha_flop() {
+    let out = run_ok(
+        "select avg(nutloouts(hero, flop)) \
+         from game='omaha', hero='As2hKdKc', villain='QcQdJcJd', board='3d7cKs'",
+    );
+
+    assert!(out.contains("AVG 0 = 16"), "{out}");
+}
+
+#[test]
+fn no_outs_without_low_cards() {
+    let out = run_ok(
+        "select avg(nutloouts(hero, flop)) \
+         from game='holdem', hero='KsKh', villain='QcQd', board='9dTcJs'",
+    );
+
+    assert!(out.contains("AVG 0 = 0"), "{out}");
+}
@@ -0,0 +1,26 @@
+use crate::common::{assert_count_all, run_ok};
+
+#[test]
+fn omaha5hilo_hero_range_with_five_cards() {
+    assert_count_all(
+        "select count(handtype(hero, flop) = pair) \
+         from game='omaha5hilo', hero='AAKKQ', board='2c7d9s'",
+    );
+}
+
+#[test]
+fn omaha5hilo_fifth_hole_card_makes_low() {
+    assert_count_all(
+        "select count(madelo(hero, river)) \
+         from game='omaha5hilo', hero='AsKhKdKc2h', board='3h4d5s9cTc'",
+    );
+}
+
+#[test]
+fn omaha5hilo_split_hi_and_lo() {
+    let out = run_ok(
+        "select avg(riverequity(hero)) \
+         from game='omaha5hilo', hero='As3h9cTsJc', villain='KhKs8d8c9h', board='2c4d7hKdQs'",
+    );
+    assert!(out.contains("AVG 0 = 0.5"), "stdout: {out}");
+}
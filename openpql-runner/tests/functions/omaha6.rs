@@ -0,0 +1,42 @@
+use crate::common::{assert_count_all, assert_count_none, run_ok};
+
+#[test]
+fn omaha6_uses_exactly_two_hole_cards_for_straight_flush() {
+    assert_count_all(
+        "select count(handtype(hero, flop) = straightflush) \
+         from game='omaha6', hero='AhKh2c3d9s4c', board='QhJhTh'",
+    );
+}
+
+#[test]
+fn omaha6_cannot_flush_with_five_suited_hole_cards_on_two_suit_board() {
+    assert_count_none(
+        "select count(handtype(hero, flop) = flush) \
+         from game='omaha6', hero='AsKsQsJs8s2h', board='Ts9s4h'",
+    );
+}
+
+#[test]
+fn omaha6_sixth_hole_card_completes_full_house() {
+    assert_count_all(
+        "select count(handtype(hero, flop) = fullhouse) \
+         from game='omaha6', hero='Ah2s3d4c7h9c', board='9h9d7c'",
+    );
+}
+
+#[test]
+fn omaha6_hero_range_with_six_cards() {
+    assert_count_all(
+        "select count(handtype(hero, flop) = pair) \
+         from game='omaha6', hero='AAKKQQ', board='2c7d9s'",
+    );
+}
+
+#[test]
+fn omaha6_river_equity() {
+    let out = run_ok(
+        "select avg(riverequity(hero)) \
+         from game='omaha6', hero='AhAsKhKs2c3d', villain='QhQsJhJs2d3c', board='Ad7c8h9s4d'",
+    );
+    assert!(out.contains("AVG 0 = 1"), "stdout: {out}");
+}
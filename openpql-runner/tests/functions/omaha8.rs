@@ -0,0 +1,46 @@
+use crate::common::{assert_count_all, assert_count_none, run_ok};
+
+#[test]
+fn omaha8_in_from_clause() {
+    assert_count_all(
+        "select count(handtype(hero, flop) = trips) \
+         from game='omaha8', hero='KhKs8d8c', board='2c4dKd'",
+    );
+}
+
+#[test]
+fn omaha8_low_uses_exactly_two_hole_cards() {
+    assert_count_none(
+        "select count(madelo(hero, river)) \
+         from game='omaha8', hero='AsKhKdKc', board='2h3h4d5s8c'",
+    );
+}
+
+#[test]
+fn omaha8_split_hi_and_lo() {
+    let out = run_ok(
+        "select avg(riverequity(hero)), avg(riverequity(villain)) \
+         from game='omaha8', hero='As3h9cTs', villain='KhKs8d8c', board='2c4d7hKdQs'",
+    );
+    assert!(out.contains("AVG 0 = 0.5"), "stdout: {out}");
+    assert!(out.contains("AVG 1 = 0.5"), "stdout: {out}");
+}
+
+#[test]
+fn omaha8_quartered_low() {
+    let out = run_ok(
+        "select avg(riverequity(hero)), avg(riverequity(villain)) \
+         from game='omaha8', hero='As3h9cTs', villain='Ad3dKhKs', board='2c4d7hKdQs'",
+    );
+    assert!(out.contains("AVG 0 = 0.25"), "stdout: {out}");
+    assert!(out.contains("AVG 1 = 0.75"), "stdout: {out}");
+}
+
+#[test]
+fn omaha8_no_low_hi_takes_pot() {
+    let out = run_ok(
+        "select avg(riverequity(villain)) \
+         from game='omaha8', hero='As3h9cTs', villain='KhKs8d8c', board='2c9dThKdQs'",
+    );
+    assert!(out.contains("AVG 0 = 1"), "stdout: {out}");
+}
@@ -0,0 +1,31 @@
+use crate::common::{assert_count_all, run_ok};
+
+/// Behind an overpair with a flush draw and a gutshot: 9 hearts, the 9c,
+/// and 3 jacks and 3 tens pairing over the nines.
+#[test]
+fn draw_against_overpair_has_sixteen_outs() {
+    let out = run_ok(
+        "select avg(cardCount(outs(hero, turn))) \
+         from game='holdem', hero='JhTh', villain='9d9s', board='8h7d2h3c'",
+    );
+    assert!(out.contains("AVG 0 = 16"), "stdout: {out}");
+}
+
+/// Dead hearts and jacks cannot come on the river.
+#[test]
+fn dead_cards_are_not_outs() {
+    let out = run_ok(
+        "select avg(cardCount(outs(hero, turn))) \
+         from game='holdem', hero='JhTh', villain='9d9s', board='8h7d2h3c', dead='QhKhJs'",
+    );
+    assert!(out.contains("AVG 0 = 13"), "stdout: {out}");
+}
+
+/// Already ahead, so no card is an out.
+#[test]
+fn made_hand_has_no_outs() {
+    assert_count_all(
+        "select count(cardCount(outs(hero, turn)) = 0) \
+         from game='holdem', hero='AsAh', villain='9d9s', board='Kc7d2h3c'",
+    );
+}
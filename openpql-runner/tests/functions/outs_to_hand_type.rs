@@ -0,0 +1,30 @@
+use crate::common::run_ok;
+
+/// Open-ended straight draw: four sevens and four queens.
+#[test]
+fn open_ender_has_eight_straight_outs() {
+    let out = run_ok(
+        "select avg(outsToHandType(hero, flop, straight)) \
+         from game='holdem', hero='JsTh', board='9h8s2c'",
+    );
+    assert!(out.contains("AVG 0 = 8"), "stdout: {out}");
+}
+
+/// The straight flush cards are outs to a different hand type.
+#[test]
+fn straight_flush_cards_are_not_straight_outs() {
+    let out = run_ok(
+        "select avg(outsToHandType(hero, turn, straight)) \
+         from game='holdem', hero='Th9h', board='8h7h2c3c'",
+    );
+    assert!(out.contains("AVG 0 = 6"), "stdout: {out}");
+}
+
+#[test]
+fn river_has_no_outs() {
+    let out = run_ok(
+        "select avg(outsToHandType(hero, river, straight)) \
+         from game='holdem', hero='JsTh', board='9h8s2c3d4d'",
+    );
+    assert!(out.contains("AVG 0 = 0"), "stdout: {out}");
+}
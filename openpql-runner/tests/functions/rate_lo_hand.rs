@@ -0,0 +1,35 @@
+use crate::common::{assert_count_all, assert_count_none, run_ok};
+
+#[test]
+fn wheel_beats_eight_low() {
+    assert_count_all(
+        "select count(ratelohand('As2h3d4c5s') < ratelohand('As2h3d4c8s')) \
+         from game='holdem', hero='AhAc', board='2c3d4s'",
+    );
+}
+
+#[test]
+fn suits_do_not_matter() {
+    assert_count_all(
+        "select count(ratelohand('As2s3s4s5s') = ratelohand('Ah2c3d4s5h')) \
+         from game='holdem', hero='AhAc', board='2c3d4s'",
+    );
+}
+
+#[test]
+fn nine_high_does_not_qualify() {
+    assert_count_none(
+        "select count(ratelohand('As2h3d4c9s') <= ratelohand('4s5h6d7c8s')) \
+         from game='holdem', hero='AhAc', board='2c3d4s'",
+    );
+}
+
+#[test]
+fn displays_ranks_of_low() {
+    let out = run_ok(
+        "select max(ratelohand('8s2h3d4c5s')) \
+         from game='holdem', hero='AhAc', board='2c3d4s'",
+    );
+
+    assert!(out.contains("LO(23458)"), "{out}");
+}
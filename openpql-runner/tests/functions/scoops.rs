@@ -39,3 +39,27 @@ fn three_way_tie_no_one_scoops() {
          from game='holdem', hero='2c3c', villain='4d5d', v2='6h7h', board='AsKsQsJsTs'",
     );
 }
+
+#[test]
+fn hilo_split_no_one_scoops() {
+    assert_count_none(
+        "select count(scoops(villain)) \
+         from game='omaha8', hero='As3h9cTs', villain='KhKs8d8c', board='2c4d7hKdQs'",
+    );
+}
+
+#[test]
+fn hilo_hi_and_lo_scoops() {
+    assert_count_all(
+        "select count(scoops(hero)) \
+         from game='omaha8', hero='As3hKhKs', villain='9c9dTcTd', board='2c4d7hKdQs'",
+    );
+}
+
+#[test]
+fn hilo_no_low_hi_winner_scoops() {
+    assert_count_all(
+        "select count(scoops(villain)) \
+         from game='omaha8', hero='As3h9cTs', villain='KhKs8d8c', board='2c9dThKdQs'",
+    );
+}
@@ -0,0 +1,25 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn monotone_flop_is_three_flush() {
+    assert_count_all(
+        "select count(threeflush(flop)) \
+         from game='holdem', board='AhKhQh'",
+    );
+}
+
+#[test]
+fn fourth_heart_is_not_three_flush() {
+    assert_count_none(
+        "select count(threeflush(turn)) \
+         from game='holdem', board='AhKhQh2h'",
+    );
+}
+
+#[test]
+fn two_tone_flop_is_not_three_flush() {
+    assert_count_none(
+        "select count(threeflush(flop)) \
+         from game='holdem', board='AhKhQs'",
+    );
+}
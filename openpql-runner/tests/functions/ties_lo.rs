@@ -0,0 +1,33 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn outright_low_does_not_tie() {
+    assert_count_none(
+        "select count(tieslo(hero)) \
+         from game='omaha', hero='As2hKdKc', villain='3s4hQdQc', board='5d6c8sKhJd'",
+    );
+}
+
+#[test]
+fn shared_low_ties() {
+    assert_count_all(
+        "select count(tieslo(hero)) \
+         from game='omaha', hero='As2hKdKc', villain='Ac2dQdQc', board='5d6c8sKhJd'",
+    );
+}
+
+#[test]
+fn losing_low_does_not_tie() {
+    assert_count_none(
+        "select count(tieslo(villain)) \
+         from game='holdem', hero='As2h', villain='6c7d', board='3d4c8sKhKd'",
+    );
+}
+
+#[test]
+fn no_low_does_not_tie() {
+    assert_count_none(
+        "select count(tieslo(hero)) \
+         from game='holdem', hero='KsQs', villain='KcQd', board='3d4c9sKhJd'",
+    );
+}
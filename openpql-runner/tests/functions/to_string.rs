@@ -0,0 +1,25 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn hand_type_text() {
+    assert_count_all(
+        "select count(tostring(handtype(hero, river)) = 'STRAIGHT_FLUSH') \
+         from game='holdem', hero='AhKh', board='QhJhTh2c3d'",
+    );
+}
+
+#[test]
+fn different_values_differ() {
+    assert_count_none(
+        "select count(tostring(handtype(hero, river)) = tostring(handtype(villain, river))) \
+         from game='holdem', hero='AhKh', villain='2c2d', board='QhJhTh3c4d'",
+    );
+}
+
+#[test]
+fn card_text() {
+    assert_count_all(
+        "select count(tostring(rivercard()) = '3d') \
+         from game='holdem', board='QhJhTh2c3d'",
+    );
+}
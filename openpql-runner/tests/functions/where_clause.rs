@@ -28,7 +28,7 @@ fn where_filters_out_on_contradictory_board() {
     let (_, err) = run("select count(pairedboard(flop)) \
          from game='holdem', board='AhAcKd' \
          where not pairedboard(flop)");
-    assert!(err.contains("SamplingFailed"), "stderr: {err}");
+    assert!(err.contains("WhereClauseRejected"), "stderr: {err}");
 }
 
 #[test]
@@ -66,3 +66,14 @@ fn where_keyword_is_case_insensitive() {
     );
     assert!(out.contains("COUNT 0"));
 }
+
+/// Most deals fail the condition, but rejections have their own budget and
+/// the statement still gathers every trial.
+#[test]
+fn rare_where_condition_is_not_a_sampling_failure() {
+    assert_count_all(
+        "select count(pocketpair(hero)) \
+         from game='holdem', hero='*' \
+         where pocketpair(hero)",
+    );
+}
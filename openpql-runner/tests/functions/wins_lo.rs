@@ -0,0 +1,33 @@
+use crate::common::{assert_count_all, assert_count_none};
+
+#[test]
+fn best_low_wins() {
+    assert_count_all(
+        "select count(winslo(hero)) \
+         from game='omaha', hero='As2hKdKc', villain='3s4hQdQc', board='5d6c8sKhJd'",
+    );
+}
+
+#[test]
+fn worse_low_does_not_win() {
+    assert_count_none(
+        "select count(winslo(villain)) \
+         from game='omaha', hero='As2hKdKc', villain='3s4hQdQc', board='5d6c8sKhJd'",
+    );
+}
+
+#[test]
+fn shared_low_wins() {
+    assert_count_all(
+        "select count(winslo(villain)) \
+         from game='omaha', hero='As2hKdKc', villain='Ac2dQdQc', board='5d6c8sKhJd'",
+    );
+}
+
+#[test]
+fn nobody_wins_without_low() {
+    assert_count_none(
+        "select count(winslo(hero)) \
+         from game='omaha', hero='As2hKdKc', villain='3s4hQdQc', board='5d9cTsKhJd'",
+    );
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use common::{TURN_AA_VS_KK, run_err, run_exact_ok, run_seeded_ok};
+
+/// Rows of the group table, split into cells.
+fn table(out: &str) -> Vec<Vec<String>> {
+    out.lines()
+        .skip_while(|l| !l.starts_with("winshi(hero)") && !l.starts_with("handType("))
+        .take_while(|l| !l.starts_with(|c: char| c.is_ascii_digit()))
+        .map(|l| {
+            l.split("  ")
+                .filter(|c| !c.is_empty())
+                .map(|c| c.trim().to_string())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn one_row_per_key() {
+    let out = run_exact_ok(
+        &format!(
+            "select count(winshi(hero)), avg(riverEquity(hero)) as eq \
+             {TURN_AA_VS_KK} \
+             group by winshi(hero)"
+        ),
+        None,
+    );
+
+    assert!(out.contains("COUNT 0 = 42\n"), "{out}");
+    assert_eq!(
+        table(&out),
+        [
+            ["winshi(hero)", "COUNT 0", "eq", "deals"],
+            ["false", "0", "0", "2"],
+            ["true", "42", "1", "42"],
+        ],
+        "{out}"
+    );
+    assert!(out.ends_with("44 deals (exact)\n"), "{out}");
+}
+
+#[test]
+fn keys_are_ordered() {
+    let out = run_exact_ok(
+        "select count(winshi(hero)) \
+         from game='holdem', hero='AhAs', villain='KhKs', board='Ac2d7h9s' \
+         group by handType(hero, river)",
+        None,
+    );
+
+    let keys: Vec<_> = table(&out)
+        .into_iter()
+        .skip(1)
+        .map(|row| row[0].clone())
+        .collect();
+
+    assert_eq!(keys, ["TRIPS", "FULL_HOUSE", "QUADS"], "{out}");
+}
+
+#[test]
+fn thread_count_does_not_change_results() {
+    let src = "select count(winshi(hero)), histogram(handType(hero, river)) \
+               from game='holdem', hero='AhKh', villain='QQ', board='2h7h9c' \
+               group by handType(hero, turn)";
+
+    assert_eq!(run_exact_ok(src, Some(1)), run_exact_ok(src, Some(3)));
+}
+
+#[test]
+fn groups_partition_the_trials() {
+    let out = run_seeded_ok(
+        "select count(winshi(hero)) \
+         from game='holdem', hero='AK', villain='*', board='2h7h9c' \
+         group by handType(hero, river)",
+        2,
+        7,
+    );
+
+    let rows = table(&out);
+    let trials: usize = out
+        .lines()
+        .last()
+        .and_then(|l| l.strip_suffix(" trials"))
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_else(|| panic!("no '<n> trials' line:\n{out}"));
+
+    assert_eq!(
+        rows.iter()
+            .skip(1)
+            .map(|row| row[2].parse::<usize>().unwrap())
+            .sum::<usize>(),
+        trials,
+        "{out}"
+    );
+}
+
+#[test]
+fn rejects_strings() {
+    let err = run_err(
+        "select count(winshi(hero)) \
+         from game='holdem', hero='AK', villain='*' \
+         group by toString(hero)",
+    );
+
+    assert!(err.contains("TypeError"), "{err}");
+}
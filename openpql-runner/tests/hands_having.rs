@@ -0,0 +1,99 @@
+mod common;
+
+use common::{assert_count_all, assert_count_none, run_err, run_exact, run_ok_st};
+
+#[test]
+fn whole_range_has_pocket_pair() {
+    let out = run_ok_st(
+        "select avg(handsHaving(villain, pocketPair(villain))) \
+         from game='holdem', hero='AhAs', villain='AA,KK', board='Kc7d2h3c4s'",
+    );
+    assert!(out.contains("AVG 0 = 1"), "stdout: {out}");
+}
+
+/// Hero and the board leave the six combos of `Ad`/`Ac` with `Ks`/`Kh`/`Kd`;
+/// only `AdKd` is suited.
+#[test]
+fn fraction_counts_unblocked_combos() {
+    let out = run_ok_st(
+        "select avg(handsHaving(villain, inRange(villain, 'AwKw'))) \
+         from game='holdem', hero='AhAs', villain='AK', board='Kc7d2h3c4s'",
+    );
+    assert!(out.contains("AVG 0 = 0.1666666666"), "stdout: {out}");
+}
+
+/// The one `AdAc` counts half against the six `AK` combos: 0.5 / 6.5.
+#[test]
+fn weighted_combos_count_by_weight() {
+    let out = run_ok_st(
+        "select avg(handsHaving(villain, pocketPair(villain))) \
+         from game='holdem', hero='AhAs', villain='AA@50,AK', board='Kc7d2h3c4s'",
+    );
+    assert!(out.contains("AVG 0 = 0.0769230769"), "stdout: {out}");
+}
+
+/// On the flop the river `Kd` is still live, so `AdKd` is one of the six
+/// combos left; on the river it is blocked and no suited combo remains.
+#[test]
+fn street_limits_the_board_combos_avoid() {
+    let query = |street: &str| {
+        run_exact(
+            &format!(
+                "select avg(handsHaving(villain, {street}, inRange(villain, 'AwKw'))) \
+                 from game='holdem', hero='AhAs', villain='AK', board='Kc7d2hKd4s'"
+            ),
+            Some(1),
+        )
+        .0
+    };
+
+    let flop = query("flop");
+    let river = query("river");
+
+    assert!(flop.contains("AVG 0 = 0.1666666666"), "stdout: {flop}");
+    assert!(river.contains("AVG 0 = 0\n"), "stdout: {river}");
+}
+
+/// `AcKd` and `AdKd` hold the turn `Kd`, so they see another turn in its
+/// place; the other four combos see the board as dealt and make trips.
+#[test]
+fn combos_never_see_their_own_cards_on_the_board() {
+    let query = |pred: &str| {
+        run_exact(
+            &format!(
+                "select avg(handsHaving(villain, flop, {pred})) \
+                 from game='holdem', hero='AhAs', villain='AK', board='Kc7d2hKd4s'"
+            ),
+            Some(1),
+        )
+        .0
+    };
+
+    let dealt = query("boardInRange('Kc7d2hKd')");
+    let trips = query("handType(villain, river) = trips");
+
+    assert!(dealt.contains("AVG 0 = 0.6666666666"), "stdout: {dealt}");
+    assert!(!trips.contains("AVG 0 = 1\n"), "stdout: {trips}");
+}
+
+#[test]
+fn fraction_compares_in_where_clause() {
+    assert_count_all(
+        "select count(winsHi(hero)) \
+         from game='holdem', hero='AhAs', villain='AK,QQ', board='Kc7d2h3c4s' \
+         where handsHaving(villain, handType(villain, river) >= pair) > 0.5",
+    );
+    assert_count_none(
+        "select count(handsHaving(villain, handType(villain, river) = trips) > 0) \
+         from game='holdem', hero='AhAs', villain='AK,QQ', board='Kc7d2h3c4s'",
+    );
+}
+
+#[test]
+fn unknown_player_is_an_error() {
+    let err = run_err(
+        "select avg(handsHaving(p3, pocketPair(p3))) \
+         from game='holdem', hero='AhAs', villain='KK'",
+    );
+    assert!(!err.is_empty());
+}
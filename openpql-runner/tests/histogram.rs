@@ -0,0 +1,95 @@
+mod common;
+
+use common::{TURN_AA_VS_KK, run_err, run_exact_ok, run_ok};
+
+#[test]
+fn counts_each_value() {
+    let out = run_exact_ok(
+        &format!("select histogram(winshi(hero)) {TURN_AA_VS_KK}"),
+        None,
+    );
+
+    assert!(out.starts_with("HISTOGRAM 0:\n"), "{out}");
+    assert!(out.contains("  false = 2 (4.55%)\n"), "{out}");
+    assert!(out.contains("  true = 42 (95.45%)\n"), "{out}");
+}
+
+/// Set of aces on the turn: the river pairs the board 9 times and hits
+/// the last ace once.
+#[test]
+fn values_are_ordered() {
+    let out = run_exact_ok(
+        "select dist(handType(hero, river)) as ht \
+         from game='holdem', hero='AhAs', villain='KhKs', board='Ac2d7h9s'",
+        None,
+    );
+
+    let lines: Vec<_> = out.lines().skip(1).take(3).collect();
+
+    assert_eq!(
+        lines,
+        [
+            "  TRIPS = 34 (77.27%)",
+            "  FULL_HOUSE = 9 (20.45%)",
+            "  QUADS = 1 (2.27%)"
+        ],
+        "{out}"
+    );
+}
+
+#[test]
+fn doubles_fall_into_bins() {
+    let out = run_exact_ok(
+        &format!("select histogram(riverEquity(hero), 4) {TURN_AA_VS_KK}"),
+        None,
+    );
+
+    assert!(out.contains("  [0, 0.25) = 2 "), "{out}");
+    assert!(out.contains("  [0.75, 1] = 42 "), "{out}");
+}
+
+#[test]
+fn thread_count_does_not_change_results() {
+    let src = "select histogram(handType(hero, river)), histogram(riverEquity(hero), 5) \
+               from game='holdem', hero='AhKh', villain='QQ', board='2h7h9c'";
+
+    assert_eq!(run_exact_ok(src, Some(1)), run_exact_ok(src, Some(3)));
+}
+
+#[test]
+fn sampled_counts_add_up_to_trials() {
+    let out = run_ok(
+        "select histogram(flopHandCategory(hero)) \
+         from game='holdem', hero='AK', villain='*'",
+    );
+
+    let trials: u64 = out
+        .lines()
+        .find_map(|l| l.strip_suffix(" trials"))
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_else(|| panic!("no '<n> trials' line:\n{out}"));
+
+    let total: u64 = out
+        .lines()
+        .filter_map(|l| {
+            l.split(" = ")
+                .nth(1)?
+                .split(' ')
+                .next()?
+                .parse::<u64>()
+                .ok()
+        })
+        .sum();
+
+    assert_eq!(total, trials, "{out}");
+}
+
+#[test]
+fn rejects_strings() {
+    let err = run_err(
+        "select histogram(toString(hero)) \
+         from game='holdem', hero='AK', villain='*'",
+    );
+
+    assert!(err.contains("TypeError"), "{err}");
+}
@@ -57,3 +57,17 @@ fn third_selector_receives_trials() {
 
     assert_eq!(count_2, trials, "third selector lost trials:\n{out}");
 }
+
+#[test]
+fn avg_and_count_report_sampling_error() {
+    let out = run_ok(
+        "select avg(riverEquity(hero)), count(winshi(hero)), max(handType(hero, river)) \
+         from game='holdem', hero='AhKh', villain='QQ', board='2h7h9c'",
+    );
+
+    let stats: Vec<_> = out.lines().filter(|l| l.starts_with("  ")).collect();
+
+    assert_eq!(stats.len(), 2, "only avg and count report stats:\n{out}");
+    assert!(stats.iter().all(|l| l.contains("se = ")), "{out}");
+    assert!(stats[1].trim_start().starts_with("p = "), "{out}");
+}
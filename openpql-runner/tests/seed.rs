@@ -0,0 +1,46 @@
+mod common;
+
+use common::{run_err, run_ok_st, run_seeded_ok};
+
+const SRC: &str = "select avg(riverEquity(hero)), count(winshi(hero)) \
+                   from game='holdem', hero='AhKh', villain='QQ+', board='2h7h9c'";
+
+#[test]
+fn same_seed_and_threads_are_bit_identical() {
+    for n_threads in [1, 3] {
+        assert_eq!(
+            run_seeded_ok(SRC, n_threads, 42),
+            run_seeded_ok(SRC, n_threads, 42),
+            "{n_threads} threads"
+        );
+    }
+}
+
+#[test]
+fn different_seeds_give_different_samples() {
+    let outs: Vec<_> = (0..4).map(|seed| run_seeded_ok(SRC, 2, seed)).collect();
+
+    assert!(outs.iter().any(|out| out != &outs[0]), "{outs:?}");
+}
+
+#[test]
+fn from_clause_seed() {
+    let src = format!("{SRC}, seed='42'");
+
+    assert_eq!(run_ok_st(&src), run_ok_st(&src));
+    assert_eq!(run_ok_st(&src), run_seeded_ok(SRC, 1, 42));
+}
+
+#[test]
+fn explicit_seed_overrides_from_clause() {
+    let src = format!("{SRC}, seed='1'");
+
+    assert_eq!(run_seeded_ok(&src, 1, 42), run_seeded_ok(SRC, 1, 42));
+}
+
+#[test]
+fn invalid_seed() {
+    let err = run_err(&format!("{SRC}, seed='abc'"));
+
+    assert!(err.contains("InvalidSeed"), "stderr: {err}");
+}
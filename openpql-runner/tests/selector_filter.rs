@@ -0,0 +1,65 @@
+mod common;
+
+use common::{TURN_AA_VS_KK, run_err, run_exact_ok};
+use opql::{Query, RunOptions, SelectorValue};
+
+/// Set of aces on the turn: the river pairs the board 9 times and hits
+/// the last ace once; hero wins every deal.
+#[test]
+fn filter_applies_to_its_selector_only() {
+    let out = run_exact_ok(
+        "select count(winshi(hero)) filter (where handType(hero, river) = fullhouse) as fh, \
+                count(winshi(hero)), \
+                count(handType(hero, river) = quads) filter (where winshi(hero)) \
+         from game='holdem', hero='AhAs', villain='KhKs', board='Ac2d7h9s'",
+        None,
+    );
+
+    assert!(out.contains("fh 0 = 9\n"), "{out}");
+    assert!(out.contains("COUNT 1 = 44\n"), "{out}");
+    assert!(out.contains("COUNT 2 = 1\n"), "{out}");
+    assert!(out.ends_with("44 deals (exact)\n"), "{out}");
+}
+
+#[test]
+fn filter_keeps_only_matching_trials() {
+    let results = Query::prepare(&format!(
+        "select count(winshi(hero)) filter (where riverEquity(hero) < 1) {TURN_AA_VS_KK}"
+    ))
+    .unwrap()
+    .run(&RunOptions {
+        exact: true,
+        ..Default::default()
+    })
+    .unwrap();
+
+    assert_eq!(results[0].selectors[0].value, SelectorValue::Int(0));
+    assert_eq!(results[0].n_trials, 44);
+}
+
+#[test]
+fn where_rejections_are_counted_apart() {
+    let results = Query::prepare(&format!(
+        "select count(winshi(hero)) {TURN_AA_VS_KK} where winshi(hero)"
+    ))
+    .unwrap()
+    .run(&RunOptions {
+        exact: true,
+        ..Default::default()
+    })
+    .unwrap();
+
+    assert_eq!(results[0].n_trials, 42);
+    assert_eq!(results[0].n_rejected, 2);
+    assert_eq!(results[0].n_failures, 0);
+}
+
+#[test]
+fn filter_must_be_boolean() {
+    let err = run_err(
+        "select avg(riverEquity(hero)) filter (where riverEquity(hero)) \
+         from game='holdem', hero='AK', villain='*'",
+    );
+
+    assert!(err.contains("TypeError"), "{err}");
+}
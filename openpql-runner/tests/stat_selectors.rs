@@ -0,0 +1,92 @@
+mod common;
+
+use common::{TURN_AA_VS_KK, run_err};
+use opql::{Query, RunOptions, SelectorValue};
+
+fn exact_values(src: &str, n_threads: Option<usize>) -> Vec<f64> {
+    let results = Query::prepare(src)
+        .unwrap()
+        .run(&RunOptions {
+            exact: true,
+            n_threads,
+            ..Default::default()
+        })
+        .unwrap();
+
+    results[0]
+        .selectors
+        .iter()
+        .map(|s| match s.value {
+            SelectorValue::Float(v) => v,
+            ref v => panic!("expected a double, got {v:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn sum_stddev_variance() {
+    let vals = exact_values(
+        &format!(
+            "select sum(riverEquity(hero)), variance(riverEquity(hero)), \
+                    stddev(riverEquity(hero)) \
+             {TURN_AA_VS_KK}"
+        ),
+        None,
+    );
+
+    let variance = 42.0 * 2.0 / (44.0 * 43.0);
+
+    assert!((vals[0] - 42.0).abs() < 1e-9, "{vals:?}");
+    assert!((vals[1] - variance).abs() < 1e-9, "{vals:?}");
+    assert!((vals[2] - variance.sqrt()).abs() < 1e-9, "{vals:?}");
+}
+
+#[test]
+fn median_and_percentile() {
+    let vals = exact_values(
+        &format!(
+            "select median(riverEquity(hero)), percentile(riverEquity(hero), 0.01), \
+                    percentile(riverEquity(hero), 1) \
+             {TURN_AA_VS_KK}"
+        ),
+        None,
+    );
+
+    assert!((vals[0] - 1.0).abs() <= 0.005, "{vals:?}");
+    assert!(vals[1].abs() < 1e-9, "{vals:?}");
+    assert!((vals[2] - 1.0).abs() <= 0.005, "{vals:?}");
+}
+
+/// Merging the workers' moments may change the last bits of `stddev`.
+#[test]
+fn thread_count_does_not_change_results() {
+    let src = "select sum(riverEquity(hero)), stddev(riverEquity(hero)), \
+                      median(riverEquity(hero)), percentile(riverEquity(hero), 0.25) \
+               from game='holdem', hero='AhKh', villain='QQ', board='2h7h9c'";
+
+    let single = exact_values(src, Some(1));
+    let multi = exact_values(src, Some(3));
+
+    for (l, r) in single.iter().zip(&multi) {
+        assert!((l - r).abs() < 1e-12, "{single:?} {multi:?}");
+    }
+}
+
+#[test]
+fn percentile_needs_a_fraction() {
+    for src in [
+        "select percentile(riverEquity(hero)) from game='holdem', hero='AK', villain='*'",
+        "select percentile(riverEquity(hero), 1.5) from game='holdem', hero='AK', villain='*'",
+    ] {
+        let err = run_err(src);
+
+        assert!(err.contains("InvalidSelectorArgument"), "{err}");
+    }
+}
+
+#[test]
+fn rejects_non_numeric() {
+    let err = run_err("select median(handType(hero, river)) from game='holdem', hero='AK'");
+
+    assert!(err.contains("TypeError"), "{err}");
+}
@@ -14,7 +14,7 @@ fn test_runs_query_end_to_end() {
     let mut out = Vec::new();
     let mut err = Vec::new();
 
-    opql::PQLRunner::run(src, Some(100), None, &mut out, &mut err).unwrap();
+    opql::PQLRunner::run(src, Some(100), None, None, &mut out, &mut err).unwrap();
 
     let out = String::from_utf8(out).unwrap();
     let err = String::from_utf8(err).unwrap();